@@ -0,0 +1,63 @@
+//! Compares the ways of writing a date with a `DateFormat`: building a new
+//! `String` each time with `format`, writing into a reused `String` with
+//! `write_to`, and writing bytes into a reused buffer with `write_io`.
+//!
+//! This runs on stable Rust without the `test` crate, so it does its own
+//! timing. Run it with `cargo bench`.
+
+extern crate datetime;
+extern crate locale;
+
+use std::time::Instant;
+
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset};
+use datetime::cldr::Locale;
+use datetime::fmt::DateFormat;
+
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn bench<F: FnMut() -> usize>(name: &str, mut f: F) {
+    let start = Instant::now();
+    let mut total = 0;
+
+    for _ in 0 .. ITERATIONS {
+        total += f();
+    }
+
+    let elapsed = start.elapsed();
+    let nanos = elapsed.as_secs() * 1_000_000_000 + elapsed.subsec_nanos() as u64;
+    println!("{:<28} {:>6} ns/iter  ({} bytes)", name, nanos / ITERATIONS as u64, total / ITERATIONS as usize);
+}
+
+fn main() {
+    let local = LocalDateTime::new(LocalDate::ymd(2024, Month::February, 3).unwrap(), LocalTime::hms_ms(15, 4, 5, 250).unwrap());
+    let when = Offset::of_hours_and_minutes(1, 0).unwrap().transform_date(local);
+    let english = locale::Time::english();
+    let german = Locale::from_tag("de").unwrap();
+
+    let log_line = DateFormat::parse("{:Y}-{>02:N}-{>02:D}T{>02:h}:{>02:m}:{>02:s}.{>03:f}{_:z}").unwrap();
+    let names = DateFormat::parse("{_:E}, {:D} {_:M} {:Y}").unwrap();
+
+    let mut string = String::with_capacity(64);
+    let mut bytes = Vec::with_capacity(64);
+
+    bench("log line: format", || log_line.format(&when, &english).len());
+    bench("log line: write_to", || {
+        string.clear();
+        log_line.write_to(&mut string, &when, &english).unwrap();
+        string.len()
+    });
+    bench("log line: write_io", || {
+        bytes.clear();
+        log_line.write_io(&mut bytes, &when, &english).unwrap();
+        bytes.len()
+    });
+
+    bench("cldr names: format", || names.format(&when, german).len());
+    bench("cldr names: write_to", || {
+        string.clear();
+        names.write_to(&mut string, &when, german).unwrap();
+        string.len()
+    });
+}
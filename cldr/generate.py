@@ -0,0 +1,176 @@
+#!/usr/bin/env python3
+
+# Generates src/cal/fmt/cldr/data.rs from the CLDR JSON in this directory,
+# which is taken from the cldr-dates-full and cldr-core packages, trimmed
+# down to the Gregorian calendar data that the formatter uses.
+#
+# Run it from the top of the repository:
+#
+#     python3 cldr/generate.py > src/cal/fmt/cldr/data.rs
+
+import json
+import os
+import unicodedata
+
+ROOT = os.path.dirname(os.path.abspath(__file__))
+
+MONTHS = [str(n) for n in range(1, 13)]
+UNITS = ["year", "month", "week", "day", "hour", "minute", "second"]
+PLURALS = ["zero", "one", "two", "few", "many", "other"]
+DAYS = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"]
+LENGTHS = ["full", "long", "medium", "short"]
+WEEKDAYS = {"sun": "Sunday", "mon": "Monday", "tue": "Tuesday", "wed": "Wednesday",
+            "thu": "Thursday", "fri": "Friday", "sat": "Saturday"}
+
+
+def load(*path):
+    with open(os.path.join(ROOT, *path), encoding="utf-8") as f:
+        return json.load(f)
+
+
+def string(s):
+    """Writes a Rust string literal, escaping any spaces that aren't ASCII
+    spaces so they can be told apart in the source."""
+    out = ""
+    for c in s:
+        if c in "\\\"":
+            out += "\\" + c
+        elif c != " " and unicodedata.category(c) in ("Zs", "Cc", "Cf"):
+            out += "\\u{%x}" % ord(c)
+        else:
+            out += c
+    return '"' + out + '"'
+
+
+def array(names, keys):
+    return "[" + ", ".join(string(names[key]) for key in keys) + "]"
+
+
+def names(field, width, keys):
+    return ("Names {\n"
+            "            abbreviated: %s,\n"
+            "            wide:        %s,\n"
+            "        }" % (array(field[width]["abbreviated"], keys), array(field[width]["wide"], keys)))
+
+
+def patterns(formats):
+    return ("Patterns {\n" +
+            "".join("            %-7s %s,\n" % (length + ":", string(formats[length])) for length in LENGTHS) +
+            "        }")
+
+
+def plurals(counts):
+    lines = "".join("                %-6s %s,\n" % (plural + ":", "Some(%s)" % string(counts[plural]) if plural in counts else "None")
+                    for plural in PLURALS if plural != "other")
+    return ("Plurals {\n%s"
+            "                other: %s,\n"
+            "            }" % (lines, string(counts["other"])))
+
+
+def relative_times(fields):
+    out = "RelativeTimes {\n"
+    for unit in UNITS:
+        field = fields[unit]
+        future = {key.split("-")[-1]: value for key, value in field["relativeTime-type-future"].items()}
+        past = {key.split("-")[-1]: value for key, value in field["relativeTime-type-past"].items()}
+        idioms = sorted((int(key[len("relative-type-"):]), value) for key, value in field.items() if key.startswith("relative-type-"))
+
+        out += "            %s: RelativeNames {\n" % unit
+        out += "                future: %s,\n" % plurals(future).replace("\n", "\n    ")
+        out += "                past: %s,\n" % plurals(past).replace("\n", "\n    ")
+        out += "                idioms: &[%s],\n" % ", ".join("(%d, %s)" % (offset, string(idiom)) for offset, idiom in idioms)
+        out += "            },\n"
+    return out + "        }"
+
+
+def relation(text):
+    """Translates one relation of a plural rule, such as `i % 10 = 2..4`,
+    into a Relation, or into True or False if it doesn’t depend on the
+    number. Only whole numbers are formatted, so every operand other than
+    the number itself is 0."""
+    negated = "!=" in text
+    left, right = [part.strip() for part in text.split("!=" if negated else "=")]
+    operand, _, modulo = [part.strip() for part in left.partition("%")]
+    ranges = []
+    for item in right.split(","):
+        low, _, high = item.strip().partition("..")
+        ranges.append((int(low), int(high or low)))
+
+    if operand not in "ni":
+        value = 0 % int(modulo) if modulo else 0
+        return any(low <= value <= high for low, high in ranges) != negated
+
+    return "Relation { modulo: %s, negated: %s, ranges: &[%s] }" % (
+        "Some(%s)" % modulo if modulo else "None", "true" if negated else "false",
+        ", ".join("(%d, %d)" % r for r in ranges))
+
+
+def plural_rules(rules):
+    out = []
+    for plural in PLURALS:
+        rule = rules.get("pluralRule-count-" + plural, "").split("@")[0].strip()
+        if plural == "other" or not rule:
+            continue
+
+        conditions = []
+        for alternative in rule.split(" or "):
+            relations = [relation(text) for text in alternative.split(" and ")]
+            if False not in relations:
+                conditions.append("&[%s]" % ", ".join(r for r in relations if r is not True))
+
+        if conditions:
+            out.append("            PluralRule { category: Plural::%s, conditions: &[%s] },\n" % (plural.capitalize(), ", ".join(conditions)))
+
+    return "&[\n%s        ]" % "".join(out) if out else "&[]"
+
+
+def territory(tag, likely):
+    parts = tag.split("-")
+    if len(parts) > 1:
+        return parts[-1]
+    return likely[tag].split("-")[-1]
+
+
+def main():
+    likely = load("supplemental", "likelySubtags.json")["supplemental"]["likelySubtags"]
+    first_days = load("supplemental", "weekData.json")["supplemental"]["weekData"]["firstDay"]
+    all_plural_rules = load("supplemental", "plurals.json")["supplemental"]["plurals-type-cardinal"]
+    tags = sorted(os.listdir(os.path.join(ROOT, "main")))
+
+    print("// This file is generated by cldr/generate.py from the CLDR data in the")
+    print("// cldr directory. Don’t edit it by hand!")
+    print()
+    print("use cal::datetime::Weekday;")
+    print("use super::{Locale, Names, Patterns, Plural, PluralRule, Relation, RelativeTimes, RelativeNames, Plurals};")
+    print()
+    print()
+    print("pub static LOCALES: [Locale; %d] = [" % len(tags))
+
+    for tag in tags:
+        gregorian = load("main", tag, "ca-gregorian.json")["main"][tag]["dates"]["calendars"]["gregorian"]
+        first_day = first_days.get(territory(tag, likely), first_days["001"])
+        eras = {"abbreviated": gregorian["eras"]["eraAbbr"], "wide": gregorian["eras"]["eraNames"]}
+        periods = gregorian["dayPeriods"]["format"]["abbreviated"]
+        fields = load("main", tag, "dateFields.json")["main"][tag]["dates"]["fields"]
+
+        print("    Locale {")
+        print("        tag: %s," % string(tag))
+        print("        months: %s," % names(gregorian["months"], "format", MONTHS))
+        print("        standalone_months: %s," % names(gregorian["months"], "stand-alone", MONTHS))
+        print("        weekdays: %s," % names(gregorian["days"], "format", DAYS))
+        print("        standalone_weekdays: %s," % names(gregorian["days"], "stand-alone", DAYS))
+        print("        eras: %s," % names({"eras": eras}, "eras", ["0", "1"]))
+        print("        am_pm: [%s, %s]," % (string(periods["am"]), string(periods["pm"])))
+        print("        date_patterns: %s," % patterns(gregorian["dateFormats"]))
+        print("        time_patterns: %s," % patterns(gregorian["timeFormats"]))
+        print("        date_time_patterns: %s," % patterns(gregorian["dateTimeFormats"]))
+        print("        first_weekday: Weekday::%s," % WEEKDAYS[first_day])
+        print("        plural_rules: %s," % plural_rules(all_plural_rules[tag.split("-")[0]]))
+        print("        relative_times: %s," % relative_times(fields))
+        print("    },")
+
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
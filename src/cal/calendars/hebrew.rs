@@ -0,0 +1,238 @@
+//! The Hebrew calendar, a lunisolar calendar with a leap *month* in seven
+//! years out of every nineteen.
+
+use std::fmt;
+use std::ops::Range;
+
+use cal::datetime::{LocalDate, Error, split_cycles};
+use super::CalendarDate;
+
+use self::HebrewMonth::*;
+
+
+/// The fixed day number of the 1st of Tishri in the year 1 *Anno Mundi*,
+/// which is the 7th of October, 3761 BC, in the Julian calendar.
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+
+/// A month of the Hebrew calendar.
+///
+/// Months are numbered from Nisan, following the Torah, even though the
+/// year number changes at Tishri, the *seventh* month. In leap years, an
+/// extra month is added: `Adar` becomes Adar I, and `AdarII` follows it.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum HebrewMonth {
+    Nisan   =  1, Iyyar   =  2, Sivan   =  3,
+    Tammuz  =  4, Av      =  5, Elul    =  6,
+    Tishri  =  7, Heshvan =  8, Kislev  =  9,
+    Tevet   = 10, Shevat  = 11, Adar    = 12,
+    AdarII  = 13,
+}
+
+impl HebrewMonth {
+
+    /// Returns the month based on a number, with Nisan as **Month 1**,
+    /// and Adar II as **Month 13**.
+    pub fn from_one(month: i8) -> Result<HebrewMonth, Error> {
+        Ok(match month {
+             1 => Nisan,    2 => Iyyar,     3 => Sivan,
+             4 => Tammuz,   5 => Av,        6 => Elul,
+             7 => Tishri,   8 => Heshvan,   9 => Kislev,
+            10 => Tevet,   11 => Shevat,   12 => Adar,
+            13 => AdarII,
+             _ => return Err(Error::OutOfRange),
+        })
+    }
+
+    /// Returns the transliterated name of this month. Whether it’s a leap
+    /// year needs to be known in order to tell Adar from Adar I.
+    pub fn name(&self, leap_year: bool) -> &'static str {
+        match *self {
+            Nisan   => "Nisan",    Iyyar   => "Iyyar",    Sivan  => "Sivan",
+            Tammuz  => "Tammuz",   Av      => "Av",       Elul   => "Elul",
+            Tishri  => "Tishri",   Heshvan => "Heshvan",  Kislev => "Kislev",
+            Tevet   => "Tevet",    Shevat  => "Shevat",
+            Adar    => if leap_year { "Adar I" } else { "Adar" },
+            AdarII  => "Adar II",
+        }
+    }
+
+    /// Returns the name of this month in Hebrew script. As with `name`,
+    /// whether it’s a leap year needs to be known.
+    pub fn native_name(&self, leap_year: bool) -> &'static str {
+        match *self {
+            Nisan   => "ניסן",     Iyyar   => "אייר",     Sivan  => "סיון",
+            Tammuz  => "תמוז",     Av      => "אב",       Elul   => "אלול",
+            Tishri  => "תשרי",     Heshvan => "חשון",     Kislev => "כסלו",
+            Tevet   => "טבת",      Shevat  => "שבט",
+            Adar    => if leap_year { "אדר א׳" } else { "אדר" },
+            AdarII  => "אדר ב׳",
+        }
+    }
+}
+
+
+/// A date in the **Hebrew calendar**.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct HebrewDate {
+    year:  i64,
+    month: HebrewMonth,
+    day:   i8,
+}
+
+impl HebrewDate {
+
+    /// Creates a new Hebrew date from the given year, month, and day
+    /// fields, returning an error if the day doesn’t exist: either the day
+    /// is past the end of the month, or the month is Adar II in a year
+    /// that isn’t a leap year.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::calendars::{CalendarDate, HebrewDate, HebrewMonth};
+    ///
+    /// let rosh_hashanah = HebrewDate::ymd(5785, HebrewMonth::Tishri, 1).unwrap();
+    /// assert_eq!(rosh_hashanah.to_local_date(), LocalDate::ymd(2024, Month::October, 3).unwrap());
+    ///
+    /// assert!(HebrewDate::ymd(5784, HebrewMonth::AdarII, 14).is_ok());
+    /// assert!(HebrewDate::ymd(5785, HebrewMonth::AdarII, 14).is_err());
+    /// ```
+    pub fn ymd(year: i64, month: HebrewMonth, day: i8) -> Result<HebrewDate, Error> {
+        if month == AdarII && !HebrewDate::is_leap_year(year) {
+            Err(Error::OutOfRange)
+        }
+        else if day >= 1 && day <= days_in_month(year, month) {
+            Ok(HebrewDate { year: year, month: month, day: day })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns whether the given year is a leap year, which has thirteen
+    /// months instead of twelve.
+    pub fn is_leap_year(year: i64) -> bool {
+        split_cycles(7 * year + 1, 19).1 < 7
+    }
+
+    /// Returns the number of days in the given year, which can be anywhere
+    /// from 353 to 355 in a common year, or 383 to 385 in a leap year.
+    pub fn days_in_year(year: i64) -> i16 {
+        (new_year(year + 1) - new_year(year)) as i16
+    }
+
+    /// Returns the name of this date’s month, transliterated.
+    pub fn month_name(&self) -> &'static str {
+        self.month.name(HebrewDate::is_leap_year(self.year))
+    }
+
+    fn fixed_day(&self) -> i64 {
+        let last_month = if HebrewDate::is_leap_year(self.year) { AdarII } else { Adar };
+        let month = self.month as i8;
+
+        // The year starts at Tishri, so the months before it in the
+        // numbering (Nisan to Elul) come *after* all the months from
+        // Tishri onwards.
+        let days_before = if self.month < Tishri {
+            days_in_months(self.year, Tishri as i8 .. last_month as i8 + 1)
+                + days_in_months(self.year, Nisan as i8 .. month)
+        }
+        else {
+            days_in_months(self.year, Tishri as i8 .. month)
+        };
+
+        new_year(self.year) + days_before + self.day as i64 - 1
+    }
+
+    fn from_fixed_day(fixed: i64) -> HebrewDate {
+        // Start from a year that’s definitely not too high, using the
+        // average length of a year, then move forwards until the next
+        // Rosh Hashanah would be after the day.
+        let mut year = split_cycles(98_496 * (fixed - HEBREW_EPOCH), 35_975_351).0;
+        while new_year(year + 1) <= fixed {
+            year += 1;
+        }
+
+        let nisan_1 = HebrewDate { year: year, month: Nisan, day: 1 }.fixed_day();
+        let start = if fixed < nisan_1 { Tishri } else { Nisan };
+
+        // Scan forwards from the start of the right half of the year until
+        // we find the month this day falls in.
+        let month = (start as i8 ..)
+            .map(|m| HebrewMonth::from_one(m).unwrap())
+            .find(|&m| fixed < HebrewDate { year: year, month: m, day: 1 }.fixed_day() + days_in_month(year, m) as i64)
+            .unwrap();
+
+        let first = HebrewDate { year: year, month: month, day: 1 }.fixed_day();
+        HebrewDate { year: year, month: month, day: (fixed - first + 1) as i8 }
+    }
+}
+
+impl CalendarDate for HebrewDate {
+    type Month = HebrewMonth;
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> HebrewMonth { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn from_local_date(date: LocalDate) -> Result<HebrewDate, Error> {
+        Ok(HebrewDate::from_fixed_day(date.to_rata_die()))
+    }
+
+    fn to_local_date(&self) -> LocalDate {
+        LocalDate::from_rata_die(self.fixed_day())
+    }
+}
+
+impl fmt::Debug for HebrewDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HebrewDate({} {} {})", self.day, self.month_name(), self.year)
+    }
+}
+
+
+/// Returns the total number of days in the given range of months.
+fn days_in_months(year: i64, months: Range<i8>) -> i64 {
+    months.map(|m| days_in_month(year, HebrewMonth::from_one(m).unwrap()) as i64).sum()
+}
+
+/// Returns the number of days in the given month of the given year.
+fn days_in_month(year: i64, month: HebrewMonth) -> i8 {
+    let year_length = HebrewDate::days_in_year(year);
+
+    match month {
+        Iyyar | Tammuz | Elul | Tevet | AdarII    => 29,
+        Adar    if !HebrewDate::is_leap_year(year)  => 29,
+        Heshvan if year_length % 10 != 5            => 29,  // long Heshvan only in 355- or 385-day years
+        Kislev  if year_length % 10 == 3            => 29,  // short Kislev only in 353- or 383-day years
+        _                                           => 30,
+    }
+}
+
+/// Returns the number of days from the epoch to the *molad* (the mean new
+/// moon) of Tishri in the given year, with the first of the postponement
+/// rules applied: Rosh Hashanah can’t fall on a Sunday, Wednesday, or
+/// Friday.
+fn elapsed_days(year: i64) -> i64 {
+    let months_elapsed = split_cycles(235 * year - 234, 19).0;
+    let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+    let days = 29 * months_elapsed + split_cycles(parts_elapsed, 25_920).0;
+
+    if split_cycles(3 * (days + 1), 7).1 < 3 { days + 1 } else { days }
+}
+
+/// Returns the fixed day of Rosh Hashanah in the given year, applying the
+/// remaining postponement rules that keep the lengths of years in range.
+fn new_year(year: i64) -> i64 {
+    let previous = elapsed_days(year - 1);
+    let current  = elapsed_days(year);
+    let next     = elapsed_days(year + 1);
+
+    let delay = if next - current == 356     { 2 }
+           else if current - previous == 382 { 1 }
+           else                              { 0 };
+
+    HEBREW_EPOCH + current + delay
+}
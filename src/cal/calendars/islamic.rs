@@ -0,0 +1,160 @@
+//! The tabular Islamic (Hijri) calendar, a purely lunar calendar.
+
+use std::fmt;
+
+use cal::datetime::{LocalDate, Error, split_cycles};
+use super::CalendarDate;
+
+use self::IslamicMonth::*;
+
+
+/// The fixed day number of the 1st of Muharram in the year 1 AH, which is
+/// the 16th of July, 622, in the Julian calendar.
+const ISLAMIC_EPOCH: i64 = 227_015;
+
+
+/// A month of the Islamic calendar.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum IslamicMonth {
+    Muharram     =  1, Safar        =  2, RabiAlAwwal  =  3,
+    RabiAlThani  =  4, JumadaAlUla  =  5, JumadaAlAkhirah =  6,
+    Rajab        =  7, Shaban       =  8, Ramadan      =  9,
+    Shawwal      = 10, DhuAlQadah   = 11, DhuAlHijjah  = 12,
+}
+
+impl IslamicMonth {
+
+    /// Returns the month based on a number, with Muharram as **Month 1**,
+    /// Safar as **Month 2**, and so on.
+    pub fn from_one(month: i8) -> Result<IslamicMonth, Error> {
+        Ok(match month {
+             1 => Muharram,      2 => Safar,         3 => RabiAlAwwal,
+             4 => RabiAlThani,   5 => JumadaAlUla,   6 => JumadaAlAkhirah,
+             7 => Rajab,         8 => Shaban,        9 => Ramadan,
+            10 => Shawwal,      11 => DhuAlQadah,   12 => DhuAlHijjah,
+             _ => return Err(Error::OutOfRange),
+        })
+    }
+
+    /// Returns the number of days in this month, depending on whether it’s
+    /// a leap year or not: months alternate between 30 and 29 days, with
+    /// the last month gaining a day in leap years.
+    pub fn days_in_month(&self, leap_year: bool) -> i8 {
+        match *self {
+            DhuAlHijjah if leap_year  => 30,
+            m if m as i8 % 2 == 1     => 30,
+            _                         => 29,
+        }
+    }
+
+    /// Returns the transliterated name of this month.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Muharram    => "Muharram",       Safar        => "Safar",
+            RabiAlAwwal => "Rabi al-Awwal",  RabiAlThani  => "Rabi al-Thani",
+            JumadaAlUla => "Jumada al-Ula",  JumadaAlAkhirah => "Jumada al-Akhirah",
+            Rajab       => "Rajab",          Shaban       => "Sha’ban",
+            Ramadan     => "Ramadan",        Shawwal      => "Shawwal",
+            DhuAlQadah  => "Dhu al-Qa’dah",  DhuAlHijjah  => "Dhu al-Hijjah",
+        }
+    }
+
+    /// Returns the name of this month in Arabic script.
+    pub fn native_name(&self) -> &'static str {
+        match *self {
+            Muharram    => "محرم",           Safar        => "صفر",
+            RabiAlAwwal => "ربيع الأول",     RabiAlThani  => "ربيع الآخر",
+            JumadaAlUla => "جمادى الأولى",   JumadaAlAkhirah => "جمادى الآخرة",
+            Rajab       => "رجب",            Shaban       => "شعبان",
+            Ramadan     => "رمضان",          Shawwal      => "شوال",
+            DhuAlQadah  => "ذو القعدة",      DhuAlHijjah  => "ذو الحجة",
+        }
+    }
+}
+
+
+/// A date in the **tabular Islamic calendar**.
+///
+/// The religious calendar depends on sightings of the new moon, so it can’t
+/// be calculated in advance; instead, this is the arithmetical calendar
+/// used for civil purposes, with eleven leap years in every thirty. Dates
+/// may differ from the observed calendar by a day or two.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct IslamicDate {
+    year:  i64,
+    month: IslamicMonth,
+    day:   i8,
+}
+
+impl IslamicDate {
+
+    /// Creates a new Islamic date from the given year, month, and day
+    /// fields, returning an error if the day doesn’t exist.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::calendars::{CalendarDate, IslamicDate, IslamicMonth};
+    ///
+    /// let date = IslamicDate::ymd(1, IslamicMonth::Muharram, 1).unwrap();
+    /// assert_eq!(date.to_local_date(), LocalDate::ymd(622, Month::July, 19).unwrap());
+    /// ```
+    pub fn ymd(year: i64, month: IslamicMonth, day: i8) -> Result<IslamicDate, Error> {
+        if day >= 1 && day <= month.days_in_month(IslamicDate::is_leap_year(year)) {
+            Ok(IslamicDate { year: year, month: month, day: day })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns whether the given year is a leap year, in which the last
+    /// month has 30 days instead of 29.
+    pub fn is_leap_year(year: i64) -> bool {
+        split_cycles(14 + 11 * year, 30).1 < 11
+    }
+
+    fn fixed_day(&self) -> i64 {
+        let month = self.month as i64;
+
+        ISLAMIC_EPOCH - 1
+            + (self.year - 1) * 354
+            + split_cycles(3 + 11 * self.year, 30).0
+            + 29 * (month - 1)
+            + month / 2
+            + self.day as i64
+    }
+
+    fn from_fixed_day(fixed: i64) -> IslamicDate {
+        let year = split_cycles(30 * (fixed - ISLAMIC_EPOCH) + 10_646, 10_631).0;
+
+        let new_year = IslamicDate { year: year, month: Muharram, day: 1 }.fixed_day();
+        let month = IslamicMonth::from_one(split_cycles(11 * (fixed - new_year) + 330, 325).0 as i8).unwrap();
+
+        let first = IslamicDate { year: year, month: month, day: 1 }.fixed_day();
+        IslamicDate { year: year, month: month, day: (fixed - first + 1) as i8 }
+    }
+}
+
+impl CalendarDate for IslamicDate {
+    type Month = IslamicMonth;
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> IslamicMonth { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn from_local_date(date: LocalDate) -> Result<IslamicDate, Error> {
+        Ok(IslamicDate::from_fixed_day(date.to_rata_die()))
+    }
+
+    fn to_local_date(&self) -> LocalDate {
+        LocalDate::from_rata_die(self.fixed_day())
+    }
+}
+
+impl fmt::Debug for IslamicDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IslamicDate({:04}-{:02}-{:02})", self.year, self.month as usize, self.day)
+    }
+}
@@ -0,0 +1,163 @@
+//! Japanese imperial era dates, which use the months and days of the
+//! Gregorian calendar but number their years from the start of each
+//! emperor’s reign.
+
+use std::fmt;
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Error};
+use super::CalendarDate;
+
+use self::Era::*;
+
+
+/// A Japanese imperial era.
+///
+/// Only the modern eras are included, as Japan switched to the Gregorian
+/// calendar in 1873, during the Meiji era. Dates earlier in that era are
+/// given in the proleptic Gregorian calendar, rather than the lunisolar
+/// calendar that was actually in use.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Era {
+    Meiji, Taisho, Showa, Heisei, Reiwa,
+}
+
+static ERAS: &'static [Era] = &[ Meiji, Taisho, Showa, Heisei, Reiwa ];
+
+static MONTH_NAMES: &'static [&'static str] = &[
+    "1月", "2月", "3月", "4月", "5月", "6月",
+    "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+impl Era {
+
+    /// Returns the Gregorian date on which this era began.
+    pub fn start(&self) -> LocalDate {
+        let (year, month, day) = match *self {
+            Meiji   => (1868, Month::October,   23),
+            Taisho  => (1912, Month::July,      30),
+            Showa   => (1926, Month::December,  25),
+            Heisei  => (1989, Month::January,    8),
+            Reiwa   => (2019, Month::May,        1),
+        };
+
+        LocalDate::ymd(year, month, day).unwrap()
+    }
+
+    /// Returns the era that follows this one, if there is one yet.
+    pub fn next(&self) -> Option<Era> {
+        ERAS.get(*self as usize + 1).cloned()
+    }
+
+    /// Returns the romanised name of this era.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Meiji   => "Meiji",
+            Taisho  => "Taishō",
+            Showa   => "Shōwa",
+            Heisei  => "Heisei",
+            Reiwa   => "Reiwa",
+        }
+    }
+
+    /// Returns the name of this era in Japanese script.
+    pub fn native_name(&self) -> &'static str {
+        match *self {
+            Meiji   => "明治",
+            Taisho  => "大正",
+            Showa   => "昭和",
+            Heisei  => "平成",
+            Reiwa   => "令和",
+        }
+    }
+
+    /// Returns the era that contains the given date, or an error if the
+    /// date is before the start of the Meiji era.
+    pub fn of(date: LocalDate) -> Result<Era, Error> {
+        ERAS.iter().rev()
+            .find(|era| era.start() <= date)
+            .cloned()
+            .ok_or(Error::OutOfRange)
+    }
+}
+
+
+/// A date in the **Japanese imperial calendar**.
+///
+/// The first year of each era runs from the day the era begins until the
+/// end of that Gregorian year, so both Heisei 31 and Reiwa 1 are in 2019.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct JapaneseDate {
+    era:   Era,
+    year:  i64,
+    month: Month,
+    day:   i8,
+}
+
+impl JapaneseDate {
+
+    /// Creates a new Japanese date from the given era, year of the era,
+    /// month, and day fields, returning an error if the day doesn’t exist
+    /// or doesn’t fall within the era.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::calendars::{CalendarDate, JapaneseDate, Era};
+    ///
+    /// let date = JapaneseDate::new(Era::Reiwa, 6, Month::January, 31).unwrap();
+    /// assert_eq!(date.to_local_date(), LocalDate::ymd(2024, Month::January, 31).unwrap());
+    ///
+    /// assert!(JapaneseDate::new(Era::Reiwa, 1, Month::April, 30).is_err());
+    /// ```
+    pub fn new(era: Era, year: i64, month: Month, day: i8) -> Result<JapaneseDate, Error> {
+        let date = try!(LocalDate::ymd(era.start().year() + year - 1, month, day));
+
+        if date < era.start() || era.next().map_or(false, |next| date >= next.start()) {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(JapaneseDate { era: era, year: year, month: month, day: day })
+    }
+
+    /// The era that this date falls within.
+    pub fn era(&self) -> Era {
+        self.era
+    }
+
+    /// Returns the name of this date’s month in Japanese script. Months
+    /// are simply numbered, so this is “1月” for January, and so on.
+    pub fn native_month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month.months_from_january()]
+    }
+}
+
+impl CalendarDate for JapaneseDate {
+    type Month = Month;
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> Month { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn from_local_date(date: LocalDate) -> Result<JapaneseDate, Error> {
+        let era = try!(Era::of(date));
+
+        Ok(JapaneseDate {
+            era:   era,
+            year:  date.year() - era.start().year() + 1,
+            month: date.month(),
+            day:   date.day(),
+        })
+    }
+
+    fn to_local_date(&self) -> LocalDate {
+        LocalDate::ymd(self.era.start().year() + self.year - 1, self.month, self.day).unwrap()
+    }
+}
+
+impl fmt::Debug for JapaneseDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JapaneseDate({} {}-{:02}-{:02})", self.era.name(), self.year, self.month as usize, self.day)
+    }
+}
@@ -0,0 +1,112 @@
+//! The Julian calendar, which the Gregorian calendar replaced.
+
+use std::fmt;
+
+use cal::datetime::{LocalDate, Month, Error, split_cycles};
+use super::CalendarDate;
+
+
+/// The fixed day number of the 1st of January in the year 1 of the Julian
+/// calendar, which is the 30th of December in the year 0 of the Gregorian
+/// calendar.
+const JULIAN_EPOCH: i64 = -1;
+
+
+/// A date in the **Julian calendar**.
+///
+/// Years are numbered astronomically, just like they are for `LocalDate`,
+/// so the year before 1 AD is the year 0, rather than 1 BC.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct JulianDate {
+    year:  i64,
+    month: Month,
+    day:   i8,
+}
+
+impl JulianDate {
+
+    /// Creates a new Julian date from the given year, month, and day
+    /// fields, returning an error if the day doesn’t exist.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::calendars::{CalendarDate, JulianDate};
+    ///
+    /// let date = JulianDate::ymd(1582, Month::October, 5).unwrap();
+    /// assert_eq!(date.to_local_date(), LocalDate::ymd(1582, Month::October, 15).unwrap());
+    ///
+    /// assert!(JulianDate::ymd(1900, Month::February, 29).is_ok());
+    /// assert!(JulianDate::ymd(1901, Month::February, 29).is_err());
+    /// ```
+    pub fn ymd(year: i64, month: Month, day: i8) -> Result<JulianDate, Error> {
+        if day >= 1 && day <= month.days_in_month(JulianDate::is_leap_year(year)) {
+            Ok(JulianDate { year: year, month: month, day: day })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns whether the given year is a leap year in the Julian
+    /// calendar, which is simply whether it’s a multiple of four.
+    pub fn is_leap_year(year: i64) -> bool {
+        year % 4 == 0
+    }
+
+    fn fixed_day(&self) -> i64 {
+        let year = self.year;
+        let month = self.month as i64;
+
+        let correction = if month <= 2                         { 0 }
+                    else if JulianDate::is_leap_year(year)     { -1 }
+                    else                                       { -2 };
+
+        JULIAN_EPOCH - 1
+            + 365 * (year - 1)
+            + split_cycles(year - 1, 4).0
+            + (367 * month - 362) / 12
+            + correction
+            + self.day as i64
+    }
+
+    fn from_fixed_day(fixed: i64) -> JulianDate {
+        let year = split_cycles(4 * (fixed - JULIAN_EPOCH) + 1464, 1461).0;
+
+        let jan_1 = JulianDate { year: year, month: Month::January, day: 1 }.fixed_day();
+        let mar_1 = JulianDate { year: year, month: Month::March,   day: 1 }.fixed_day();
+
+        let correction = if fixed < mar_1                       { 0 }
+                    else if JulianDate::is_leap_year(year)      { 1 }
+                    else                                        { 2 };
+
+        let month = (12 * (fixed - jan_1 + correction) + 373) / 367;
+        let month = Month::from_one(month as i8).unwrap();
+
+        let first = JulianDate { year: year, month: month, day: 1 }.fixed_day();
+        JulianDate { year: year, month: month, day: (fixed - first + 1) as i8 }
+    }
+}
+
+impl CalendarDate for JulianDate {
+    type Month = Month;
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> Month { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn from_local_date(date: LocalDate) -> Result<JulianDate, Error> {
+        Ok(JulianDate::from_fixed_day(date.to_rata_die()))
+    }
+
+    fn to_local_date(&self) -> LocalDate {
+        LocalDate::from_rata_die(self.fixed_day())
+    }
+}
+
+impl fmt::Debug for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JulianDate({:04}-{:02}-{:02})", self.year, self.month as usize, self.day)
+    }
+}
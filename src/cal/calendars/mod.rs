@@ -0,0 +1,49 @@
+//! Calendar systems other than the proleptic Gregorian calendar used by
+//! the rest of this library.
+//!
+//! Each calendar has its own date type, with its own year, month, and day
+//! fields, that can be converted to and from a `LocalDate`. All the
+//! conversions go through the *fixed* day number of a date (its Rata Die),
+//! which counts the 1st of January in the (Gregorian) year 1 as day 1, in
+//! the style of Reingold and Dershowitz’s *Calendrical Calculations*.
+
+use cal::datetime::{LocalDate, Error};
+
+pub mod hebrew;
+pub mod islamic;
+pub mod japanese;
+pub mod julian;
+pub mod persian;
+
+pub use self::hebrew::{HebrewDate, HebrewMonth};
+pub use self::islamic::{IslamicDate, IslamicMonth};
+pub use self::japanese::{JapaneseDate, Era};
+pub use self::julian::JulianDate;
+pub use self::persian::{PersianDate, PersianMonth};
+
+
+/// A **calendar date** is a day in some calendar system, which can be
+/// converted to and from the Gregorian `LocalDate` that falls on the same
+/// day.
+pub trait CalendarDate: Sized {
+
+    /// The type of this calendar’s months.
+    type Month;
+
+    /// The year, as numbered by this calendar.
+    fn year(&self) -> i64;
+
+    /// The month of the year.
+    fn month(&self) -> Self::Month;
+
+    /// The day of the month, starting from 1.
+    fn day(&self) -> i8;
+
+    /// Returns the date in this calendar that falls on the same day as the
+    /// given Gregorian date. This returns an error if the date lies outside
+    /// the range that this calendar can represent.
+    fn from_local_date(date: LocalDate) -> Result<Self, Error>;
+
+    /// Returns the Gregorian date that falls on the same day as this date.
+    fn to_local_date(&self) -> LocalDate;
+}
@@ -0,0 +1,254 @@
+//! The Solar Hijri calendar, which is the official calendar of Iran and
+//! Afghanistan.
+
+use std::fmt;
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Error};
+use super::CalendarDate;
+
+use self::PersianMonth::*;
+
+
+/// The years at which the pattern of leap years changes. Between each
+/// pair of breaks, leap years follow a 33-year cycle.
+///
+/// The Solar Hijri year begins at the vernal equinox, as observed in
+/// Tehran, so the real calendar is astronomical. These breaks come from
+/// Kazimierz Borkowski’s analysis of the equinox times, which reproduces
+/// the astronomical calendar for the years that it covers.
+const BREAKS: &'static [i64] = &[
+    -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210,
+    1635, 2060, 2097, 2192, 2262, 2324, 2394, 2456, 3178,
+];
+
+/// The difference between the Solar Hijri year and the Gregorian year in
+/// which it begins.
+const YEAR_DIFFERENCE: i64 = 621;
+
+
+/// A month of the Solar Hijri calendar.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum PersianMonth {
+    Farvardin =  1, Ordibehesht =  2, Khordad =  3,
+    Tir       =  4, Mordad      =  5, Shahrivar =  6,
+    Mehr      =  7, Aban        =  8, Azar    =  9,
+    Dey       = 10, Bahman      = 11, Esfand  = 12,
+}
+
+impl PersianMonth {
+
+    /// Returns the month based on a number, with Farvardin as **Month 1**,
+    /// Ordibehesht as **Month 2**, and so on.
+    pub fn from_one(month: i8) -> Result<PersianMonth, Error> {
+        Ok(match month {
+             1 => Farvardin,   2 => Ordibehesht,   3 => Khordad,
+             4 => Tir,         5 => Mordad,        6 => Shahrivar,
+             7 => Mehr,        8 => Aban,          9 => Azar,
+            10 => Dey,        11 => Bahman,       12 => Esfand,
+             _ => return Err(Error::OutOfRange),
+        })
+    }
+
+    /// Returns the number of days in this month, depending on whether it’s
+    /// a leap year or not: the first six months have 31 days, the next
+    /// five have 30, and Esfand has 29, or 30 in a leap year.
+    pub fn days_in_month(&self, leap_year: bool) -> i8 {
+        match *self {
+            Esfand                    => if leap_year { 30 } else { 29 },
+            m if m as i8 <= 6         => 31,
+            _                         => 30,
+        }
+    }
+
+    /// Returns the number of days that have elapsed in a year *before* this
+    /// month begins.
+    fn days_before_start(&self) -> i64 {
+        let month = *self as i64;
+        if month <= 7 { (month - 1) * 31 } else { 186 + (month - 7) * 30 }
+    }
+
+    /// Returns the transliterated name of this month.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Farvardin => "Farvardin",  Ordibehesht => "Ordibehesht",  Khordad   => "Khordad",
+            Tir       => "Tir",        Mordad      => "Mordad",       Shahrivar => "Shahrivar",
+            Mehr      => "Mehr",       Aban        => "Aban",         Azar      => "Azar",
+            Dey       => "Dey",        Bahman      => "Bahman",       Esfand    => "Esfand",
+        }
+    }
+
+    /// Returns the name of this month in Persian script.
+    pub fn native_name(&self) -> &'static str {
+        match *self {
+            Farvardin => "فروردین",    Ordibehesht => "اردیبهشت",     Khordad   => "خرداد",
+            Tir       => "تیر",        Mordad      => "مرداد",        Shahrivar => "شهریور",
+            Mehr      => "مهر",        Aban        => "آبان",         Azar      => "آذر",
+            Dey       => "دی",         Bahman      => "بهمن",         Esfand    => "اسفند",
+        }
+    }
+}
+
+
+/// A date in the **Solar Hijri calendar**, also known as the Persian or
+/// Jalali calendar.
+///
+/// Only the years from -61 to 3177 can be represented, as that’s the range
+/// over which the leap year rules are known to match the equinoxes.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct PersianDate {
+    year:  i64,
+    month: PersianMonth,
+    day:   i8,
+}
+
+impl PersianDate {
+
+    /// Creates a new Solar Hijri date from the given year, month, and day
+    /// fields, returning an error if the day doesn’t exist or the year is
+    /// outside the supported range.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    /// use datetime::calendars::{CalendarDate, PersianDate, PersianMonth};
+    ///
+    /// let nowruz = PersianDate::ymd(1403, PersianMonth::Farvardin, 1).unwrap();
+    /// assert_eq!(nowruz.to_local_date(), LocalDate::ymd(2024, Month::March, 20).unwrap());
+    /// ```
+    pub fn ymd(year: i64, month: PersianMonth, day: i8) -> Result<PersianDate, Error> {
+        let leap_year = try!(PersianDate::is_leap_year(year));
+
+        if day >= 1 && day <= month.days_in_month(leap_year) {
+            Ok(PersianDate { year: year, month: month, day: day })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns whether the given year is a leap year, in which Esfand has
+    /// 30 days instead of 29. This returns an error if the year is outside
+    /// the supported range.
+    pub fn is_leap_year(year: i64) -> Result<bool, Error> {
+        year_info(year).map(|info| info.leap_year)
+    }
+
+    fn fixed_day(&self) -> i64 {
+        // The year has already been checked by the constructor.
+        let info = year_info(self.year).unwrap();
+        info.nowruz + self.month.days_before_start() + self.day as i64 - 1
+    }
+
+    fn from_fixed_day(fixed: i64) -> Result<PersianDate, Error> {
+        let gregorian_year = LocalDate::from_rata_die(fixed).year();
+
+        // The day either falls in the year that began in this Gregorian
+        // year, or the one before it, depending on whether it’s past
+        // Nowruz yet. Use the previous year to find out, so that the last
+        // days of the last supported year can still be converted.
+        let previous = try!(year_info(gregorian_year - YEAR_DIFFERENCE - 1));
+        let next_nowruz = previous.nowruz + if previous.leap_year { 366 } else { 365 };
+
+        let (year, nowruz) = if fixed < next_nowruz {
+            (gregorian_year - YEAR_DIFFERENCE - 1, previous.nowruz)
+        }
+        else {
+            try!(year_info(gregorian_year - YEAR_DIFFERENCE));
+            (gregorian_year - YEAR_DIFFERENCE, next_nowruz)
+        };
+
+        let days = fixed - nowruz;
+        let (month, day) = if days < 186 { (1 + days / 31, days % 31) }
+                                    else { (7 + (days - 186) / 30, (days - 186) % 30) };
+
+        Ok(PersianDate {
+            year:  year,
+            month: PersianMonth::from_one(month as i8).unwrap(),
+            day:   (day + 1) as i8,
+        })
+    }
+}
+
+impl CalendarDate for PersianDate {
+    type Month = PersianMonth;
+
+    fn year(&self) -> i64 { self.year }
+    fn month(&self) -> PersianMonth { self.month }
+    fn day(&self) -> i8 { self.day }
+
+    fn from_local_date(date: LocalDate) -> Result<PersianDate, Error> {
+        PersianDate::from_fixed_day(date.to_rata_die())
+    }
+
+    fn to_local_date(&self) -> LocalDate {
+        LocalDate::from_rata_die(self.fixed_day())
+    }
+}
+
+impl fmt::Debug for PersianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PersianDate({:04}-{:02}-{:02})", self.year, self.month as usize, self.day)
+    }
+}
+
+
+/// The leap-year status and start date of a Solar Hijri year.
+struct YearInfo {
+    leap_year: bool,
+    nowruz: i64,
+}
+
+/// Works out whether the given year is a leap year, and the fixed day
+/// that it begins on, using the 33-year cycles between each break.
+fn year_info(year: i64) -> Result<YearInfo, Error> {
+    if year < BREAKS[0] || year >= BREAKS[BREAKS.len() - 1] {
+        return Err(Error::OutOfRange);
+    }
+
+    let gregorian_year = year + YEAR_DIFFERENCE;
+
+    // Count the leap years that have elapsed up to the start of the
+    // period that contains this year.
+    let mut leap_years = -14;
+    let mut period_start = BREAKS[0];
+    let mut jump = 0;
+
+    for &period_end in &BREAKS[1..] {
+        jump = period_end - period_start;
+        if year < period_end {
+            break;
+        }
+
+        leap_years += jump / 33 * 8 + (jump % 33) / 4;
+        period_start = period_end;
+    }
+
+    // Then count the ones since the start of the period.
+    let mut years_into_period = year - period_start;
+    leap_years += years_into_period / 33 * 8 + (years_into_period % 33 + 3) / 4;
+
+    if jump % 33 == 4 && jump - years_into_period == 4 {
+        leap_years += 1;
+    }
+
+    // The Gregorian leap years that have elapsed, counted on the same
+    // basis, determine which day in March the year begins.
+    let gregorian_leap_years = gregorian_year / 4 - (gregorian_year / 100 + 1) * 3 / 4 - 150;
+    let march = 20 + leap_years - gregorian_leap_years;
+
+    if jump - years_into_period < 6 {
+        years_into_period = years_into_period - jump + (jump + 4) / 33 * 33;
+    }
+
+    // This is the number of years since the last leap year, so zero means
+    // this year is one. It can come out as -1 for the last year of a cycle,
+    // which isn’t.
+    let since_leap = ((years_into_period + 1) % 33 - 1) % 4;
+
+    Ok(YearInfo {
+        leap_year: since_leap == 0,
+        nowruz:    LocalDate::ymd(gregorian_year, Month::March, march as i8).unwrap().to_rata_die(),
+    })
+}
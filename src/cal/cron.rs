@@ -0,0 +1,536 @@
+//! Schedules written as cron expressions.
+//!
+//! A cron expression lists the minutes, hours, days of the month, months,
+//! and days of the week that a job should run at, such as `30 9 * * MON-FRI`
+//! for half past nine every weekday. An optional sixth field at the start
+//! gives the seconds. This module parses these expressions and finds the
+//! times that they fire, either on a wall clock or in a time zone.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year};
+use cal::zone::{TimeZone, ZonedDateTime};
+use instant::Instant;
+
+
+/// The number of days to search through for a fire time before giving up.
+/// The calendar repeats every 400 years, so an expression that doesn’t
+/// fire within that long never will, such as one for the 30th of February.
+const MAX_SEARCH_DAYS: i64 = 146_097 + 366;
+
+static MONTH_NAMES: &'static [&'static str] = &[
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+static WEEKDAY_NAMES: &'static [&'static str] = &[
+    "SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT",
+];
+
+
+/// A day of the month that can’t be written as a plain number.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum MonthDay {
+
+    /// The last day of the month, or this many days before it (`L`, `L-3`).
+    Last(i8),
+
+    /// The weekday nearest to the given day, without leaving the month
+    /// (`15W`).
+    NearestWeekday(i8),
+
+    /// The last weekday of the month (`LW`).
+    LastWeekday,
+}
+
+/// A day of the week that only happens once in a month.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum WeekdayOfMonth {
+
+    /// The given occurrence of the weekday in the month (`5#3`).
+    Nth(Weekday, i8),
+
+    /// The last occurrence of the weekday in the month (`5L`).
+    Last(Weekday),
+}
+
+
+/// A **cron schedule**, parsed from a cron expression.
+///
+/// Expressions have five fields, for the minute, hour, day of the month,
+/// month, and day of the week, or six with the second at the start. Each
+/// field can be `*`, a number, a range such as `1-5`, a step such as `*/15`
+/// or `10-50/20`, or a list of these separated by commas. Months and days
+/// of the week can also be written as three-letter names, and Sunday can
+/// be either 0 or 7. Ranges of days of the week can wrap around the end of
+/// the week, so `FRI-SUN` means Friday, Saturday, and Sunday. The macros
+/// `@yearly` (or `@annually`), `@monthly`, `@weekly`, `@daily` (or
+/// `@midnight`), and `@hourly` are also accepted.
+///
+/// The day-of-the-month field also allows `L` for the last day of the
+/// month, `L-3` for three days before it, `15W` for the weekday nearest the
+/// 15th, and `LW` for the last weekday. The day-of-the-week field allows
+/// `5L` for the last Friday of the month, and `5#3` for the third Friday.
+/// Either day field can be `?`, which means the same as `*`.
+///
+/// As in other crons, when *both* day fields are restricted, the schedule
+/// fires on days that match *either* of them, so `0 0 1 * MON` fires on the
+/// 1st of each month and on every Monday. A field that starts with `*` or
+/// `?` doesn’t count as restricted.
+///
+/// ### Examples
+///
+/// ```rust
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, CronSchedule};
+///
+/// let schedule: CronSchedule = "0 9 * * MON-FRI".parse().unwrap();
+/// let friday = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 5).unwrap(), LocalTime::hm(10, 0).unwrap());
+///
+/// assert_eq!(schedule.next_local(friday),
+///            Some(LocalDateTime::new(LocalDate::ymd(2024, Month::January, 8).unwrap(), LocalTime::hm(9, 0).unwrap())));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CronSchedule {
+    seconds:            u64,
+    minutes:            u64,
+    hours:              u32,
+    days_of_month:      u32,
+    special_month_days: Vec<MonthDay>,
+    months:             u16,
+    days_of_week:       u8,
+    special_weekdays:   Vec<WeekdayOfMonth>,
+    any_day_of_month:   bool,
+    any_day_of_week:    bool,
+}
+
+impl CronSchedule {
+
+    /// Returns the first time after the given wall-clock time that this
+    /// schedule fires, or `None` if it never fires again.
+    pub fn next_local(&self, after: LocalDateTime) -> Option<LocalDateTime> {
+        let mut date = after.date();
+        let mut from = after.time().to_seconds() + 1;
+
+        for _ in 0 .. MAX_SEARCH_DAYS {
+            if from < 86_400 && self.matches_day(date) {
+                if let Some(time) = self.first_time_from(from) {
+                    return Some(LocalDateTime::new(date, time));
+                }
+            }
+
+            date = if self.matches_month(date.month()) { date.add_days(1) }
+                                                   else { first_of_next_month(date) };
+            from = 0;
+        }
+
+        None
+    }
+
+    /// Returns the last time before the given wall-clock time that this
+    /// schedule fired, or `None` if it never fired.
+    pub fn previous_local(&self, before: LocalDateTime) -> Option<LocalDateTime> {
+        let mut date = before.date();
+        let time = before.time();
+        let mut until = if time.millisecond() > 0 { time.to_seconds() } else { time.to_seconds() - 1 };
+
+        for _ in 0 .. MAX_SEARCH_DAYS {
+            if until >= 0 && self.matches_day(date) {
+                if let Some(time) = self.last_time_until(until) {
+                    return Some(LocalDateTime::new(date, time));
+                }
+            }
+
+            date = if self.matches_month(date.month()) { date.add_days(-1) }
+                                                   else { LocalDate::ymd(date.year(), date.month(), 1).unwrap().add_days(-1) };
+            until = 86_399;
+        }
+
+        None
+    }
+
+    /// Returns the first time after the given instant that this schedule
+    /// fires in the time zone, or `None` if it never fires again.
+    ///
+    /// The schedule follows the wall clock, so a job at 09:00 runs at 09:00
+    /// whether or not daylight saving time is in effect. When the clocks go
+    /// forward, the fire times in the hour that gets skipped are replaced
+    /// by a single one at the moment the clocks change, so a job every 20
+    /// minutes fires at 03:00 and then 03:20, rather than three times at
+    /// 03:00. When the clocks go back, the fire times in the hour that gets
+    /// repeated only happen the first time around.
+    ///
+    /// The search starts from an instant rather than a wall-clock time,
+    /// as a wall-clock time in the repeated hour doesn’t say which time
+    /// around it means. To find the fire time after this one, pass in its
+    /// `to_instant()`.
+    pub fn next_in<'a>(&self, after: Instant, zone: &'a TimeZone) -> Option<ZonedDateTime<'a>> {
+        let mut local = zone.to_zoned(LocalDateTime::at_ms(after.seconds(), after.milliseconds()));
+
+        // Fire times in a repeated hour, or in a skipped one, can come out
+        // at or before the instant, so those get passed over.
+        while let Some(next) = self.next_local(local) {
+            let fire = zone.earliest_local(next);
+            if fire.to_instant() > after {
+                return Some(fire);
+            }
+
+            local = next;
+        }
+
+        None
+    }
+
+    /// Returns the last time before the given instant that this schedule
+    /// fired in the time zone, or `None` if it never fired, treating
+    /// skipped and repeated times in the same way as `next_in`.
+    pub fn previous_in<'a>(&self, before: Instant, zone: &'a TimeZone) -> Option<ZonedDateTime<'a>> {
+        let mut local = zone.to_zoned(LocalDateTime::at_ms(before.seconds(), before.milliseconds()));
+
+        while let Some(previous) = self.previous_local(local) {
+            let fire = zone.earliest_local(previous);
+            if fire.to_instant() < before {
+                return Some(fire);
+            }
+
+            local = previous;
+        }
+
+        None
+    }
+
+    /// Returns whether this schedule fires at any time in the given month.
+    fn matches_month(&self, month: Month) -> bool {
+        self.months & (1 << (month.months_from_january() + 1)) != 0
+    }
+
+    /// Returns whether this schedule fires at any time on the given date.
+    fn matches_day(&self, date: LocalDate) -> bool {
+        if !self.matches_month(date.month()) {
+            return false;
+        }
+
+        let day_of_month = self.matches_day_of_month(date);
+        let day_of_week = self.matches_day_of_week(date);
+
+        if self.any_day_of_month || self.any_day_of_week {
+            day_of_month && day_of_week
+        }
+        else {
+            day_of_month || day_of_week
+        }
+    }
+
+    fn matches_day_of_month(&self, date: LocalDate) -> bool {
+        let day = date.day();
+        if self.days_of_month & (1 << day) != 0 {
+            return true;
+        }
+
+        let last = date.month().days_in_month(Year(date.year()).is_leap_year());
+        let weekday_of = |day: i8| LocalDate::ymd(date.year(), date.month(), day).unwrap().weekday();
+
+        self.special_month_days.iter().any(|special| match *special {
+            MonthDay::Last(before)  => day == last - before,
+            MonthDay::LastWeekday   => match weekday_of(last) {
+                Weekday::Saturday  => day == last - 1,
+                Weekday::Sunday    => day == last - 2,
+                _                  => day == last,
+            },
+            MonthDay::NearestWeekday(target) => target <= last && match weekday_of(target) {
+                Weekday::Saturday  => day == if target == 1 { 3 } else { target - 1 },
+                Weekday::Sunday    => day == if target == last { target - 2 } else { target + 1 },
+                _                  => day == target,
+            },
+        })
+    }
+
+    fn matches_day_of_week(&self, date: LocalDate) -> bool {
+        let weekday = date.weekday();
+        if self.days_of_week & (1 << weekday as u8) != 0 {
+            return true;
+        }
+
+        let (day, last) = (date.day(), date.month().days_in_month(Year(date.year()).is_leap_year()));
+
+        self.special_weekdays.iter().any(|special| match *special {
+            WeekdayOfMonth::Nth(w, n)  => w == weekday && (day - 1) / 7 + 1 == n,
+            WeekdayOfMonth::Last(w)    => w == weekday && day + 7 > last,
+        })
+    }
+
+    /// Returns the first time of day at or after the given number of
+    /// seconds since midnight that this schedule fires at.
+    fn first_time_from(&self, from: i64) -> Option<LocalTime> {
+        let (from_hour, from_minute, from_second) = ((from / 3600) as u32, (from / 60 % 60) as u32, (from % 60) as u32);
+
+        for hour in from_hour .. 24 {
+            if self.hours & (1 << hour) == 0 { continue }
+            let first_minute = if hour == from_hour { from_minute } else { 0 };
+
+            for minute in first_minute .. 60 {
+                if self.minutes & (1 << minute) == 0 { continue }
+                let first_second = if hour == from_hour && minute == from_minute { from_second } else { 0 };
+
+                if let Some(second) = (first_second .. 60).find(|s| self.seconds & (1 << s) != 0) {
+                    return Some(LocalTime::hms(hour as i8, minute as i8, second as i8).unwrap());
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the last time of day at or before the given number of
+    /// seconds since midnight that this schedule fires at.
+    fn last_time_until(&self, until: i64) -> Option<LocalTime> {
+        let (until_hour, until_minute, until_second) = ((until / 3600) as u32, (until / 60 % 60) as u32, (until % 60) as u32);
+
+        for hour in (0 .. until_hour + 1).rev() {
+            if self.hours & (1 << hour) == 0 { continue }
+            let last_minute = if hour == until_hour { until_minute } else { 59 };
+
+            for minute in (0 .. last_minute + 1).rev() {
+                if self.minutes & (1 << minute) == 0 { continue }
+                let last_second = if hour == until_hour && minute == until_minute { until_second } else { 59 };
+
+                if let Some(second) = (0 .. last_second + 1).rev().find(|s| self.seconds & (1 << s) != 0) {
+                    return Some(LocalTime::hms(hour as i8, minute as i8, second as i8).unwrap());
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns the first day of the month after the given date’s month.
+fn first_of_next_month(date: LocalDate) -> LocalDate {
+    match date.month() {
+        Month::December  => LocalDate::ymd(date.year() + 1, Month::January, 1).unwrap(),
+        month            => LocalDate::ymd(date.year(), Month::from_zero(month.months_from_january() as i8 + 1).unwrap(), 1).unwrap(),
+    }
+}
+
+
+impl FromStr for CronSchedule {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<CronSchedule, Self::Err> {
+        let expression = match input.trim() {
+            "@yearly" | "@annually"  => "0 0 1 1 *",
+            "@monthly"               => "0 0 1 * *",
+            "@weekly"                => "0 0 * * 0",
+            "@daily" | "@midnight"   => "0 0 * * *",
+            "@hourly"                => "0 * * * *",
+            other if other.starts_with('@') => return Err(Error::UnknownMacro(other.to_owned())),
+            other                    => other,
+        };
+
+        let mut fields: Vec<&str> = expression.split_whitespace().collect();
+        match fields.len() {
+            5 => fields.insert(0, "0"),
+            6 => {},
+            n => return Err(Error::FieldCount(n)),
+        }
+
+        let mut schedule = CronSchedule {
+            seconds:            try!(parse_field(fields[0], 0, 59, &[], None)),
+            minutes:            try!(parse_field(fields[1], 0, 59, &[], None)),
+            hours:              try!(parse_field(fields[2], 0, 23, &[], None)) as u32,
+            days_of_month:      0,
+            special_month_days: Vec::new(),
+            months:             try!(parse_field(fields[4], 1, 12, MONTH_NAMES, None)) as u16,
+            days_of_week:       0,
+            special_weekdays:   Vec::new(),
+            any_day_of_month:   fields[3].starts_with('*') || fields[3].starts_with('?'),
+            any_day_of_week:    fields[5].starts_with('*') || fields[5].starts_with('?'),
+        };
+
+        let mut days_of_month = Vec::new();
+        for item in fields[3].split(',') {
+            match try!(parse_month_day(item)) {
+                Some(special)  => schedule.special_month_days.push(special),
+                None           => days_of_month.push(item),
+            }
+        }
+
+        if !days_of_month.is_empty() {
+            schedule.days_of_month = try!(parse_field(&days_of_month.join(","), 1, 31, &[], None)) as u32;
+        }
+
+        let mut days_of_week = Vec::new();
+        for item in fields[5].split(',') {
+            match try!(parse_weekday_of_month(item)) {
+                Some(special)  => schedule.special_weekdays.push(special),
+                None           => days_of_week.push(item),
+            }
+        }
+
+        if !days_of_week.is_empty() {
+            // Sunday can be written as either 0 or 7, and ranges such as
+            // FRI-SUN can run past the end of the week.
+            let mask = try!(parse_field(&days_of_week.join(","), 0, 7, WEEKDAY_NAMES, Some(7)));
+            schedule.days_of_week = ((mask | mask >> 7) & 0x7F) as u8;
+        }
+
+        Ok(schedule)
+    }
+}
+
+/// Parses one field of a cron expression into a bit mask of the values in
+/// it, where each value must be between the minimum and maximum. Values
+/// can also be written as names, where the first name is the minimum.
+///
+/// Fields whose values go round in a cycle of the given length, starting
+/// from the minimum, can have ranges that wrap around past its end, which
+/// cover the values from the first one to the end of the cycle, and from
+/// the start of the cycle to the last one.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str], cycle: Option<u32>) -> Result<u64, Error> {
+    let invalid = || Error::InvalidField(field.to_owned());
+    let value = |text: &str| -> Result<u32, Error> {
+        let number = match names.iter().position(|n| n.eq_ignore_ascii_case(text)) {
+            Some(index)  => index as u32 + min,
+            None         => try!(text.parse().map_err(|_| invalid())),
+        };
+
+        if number < min || number > max { Err(invalid()) } else { Ok(number) }
+    };
+
+    let mut mask = 0;
+
+    for item in field.split(',') {
+        let (range, step) = match item.find('/') {
+            Some(index)  => (&item[.. index], try!(item[index + 1 ..].parse().map_err(|_| invalid()))),
+            None         => (item, 0),
+        };
+
+        if step == 0 && item.contains('/') {
+            return Err(invalid());
+        }
+
+        let (first, last) = if range == "*" || range == "?" {
+            (min, max)
+        }
+        else if let Some(index) = range.find('-') {
+            (try!(value(&range[.. index])), try!(value(&range[index + 1 ..])))
+        }
+        else {
+            let first = try!(value(range));
+            (first, if step > 0 { max } else { first })
+        };
+
+        let last = match cycle {
+            _ if first <= last  => last,
+            Some(cycle)         => last + cycle,
+            None                => return Err(invalid()),
+        };
+
+        let mut number = first;
+        while number <= last {
+            mask |= 1 << match cycle {
+                Some(cycle) if number >= min + cycle  => number - cycle,
+                _                                     => number,
+            };
+            number += if step > 0 { step } else { 1 };
+        }
+    }
+
+    Ok(mask)
+}
+
+/// Parses the forms of a day of the month that aren’t plain numbers,
+/// returning `None` if it’s a plain number or range.
+fn parse_month_day(item: &str) -> Result<Option<MonthDay>, Error> {
+    let invalid = || Error::InvalidField(item.to_owned());
+    let upper = item.to_uppercase();
+
+    if upper == "L" {
+        Ok(Some(MonthDay::Last(0)))
+    }
+    else if upper == "LW" {
+        Ok(Some(MonthDay::LastWeekday))
+    }
+    else if upper.starts_with("L-") {
+        match upper[2..].parse() {
+            Ok(before) if before >= 0 && before <= 30  => Ok(Some(MonthDay::Last(before))),
+            _                                          => Err(invalid()),
+        }
+    }
+    else if upper.ends_with('W') {
+        match upper[.. upper.len() - 1].parse() {
+            Ok(day) if day >= 1 && day <= 31  => Ok(Some(MonthDay::NearestWeekday(day))),
+            _                                 => Err(invalid()),
+        }
+    }
+    else {
+        Ok(None)
+    }
+}
+
+/// Parses the forms of a day of the week that only happen once a month,
+/// returning `None` if it’s a plain weekday or range.
+fn parse_weekday_of_month(item: &str) -> Result<Option<WeekdayOfMonth>, Error> {
+    let invalid = || Error::InvalidField(item.to_owned());
+    let weekday = |text: &str| parse_field(text, 0, 7, WEEKDAY_NAMES, Some(7)).ok()
+                                 .and_then(|mask| if mask.count_ones() == 1 { Some(mask.trailing_zeros() % 7) } else { None })
+                                 .map(|number| Weekday::from_zero(number as i8).unwrap());
+
+    if let Some(index) = item.find('#') {
+        let nth = match item[index + 1 ..].parse() {
+            Ok(nth) if nth >= 1 && nth <= 5  => nth,
+            _                                => return Err(invalid()),
+        };
+
+        match weekday(&item[.. index]) {
+            Some(weekday)  => Ok(Some(WeekdayOfMonth::Nth(weekday, nth))),
+            None           => Err(invalid()),
+        }
+    }
+    else if item.len() > 1 && (item.ends_with('L') || item.ends_with('l')) {
+        match weekday(&item[.. item.len() - 1]) {
+            Some(weekday)  => Ok(Some(WeekdayOfMonth::Last(weekday))),
+            None           => Err(invalid()),
+        }
+    }
+    else {
+        Ok(None)
+    }
+}
+
+
+/// An error that occurs when parsing a cron expression.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Error {
+
+    /// The expression has the wrong number of fields; it should have five
+    /// or six.
+    FieldCount(usize),
+
+    /// A field that couldn’t be parsed, or that has a value out of range.
+    InvalidField(String),
+
+    /// A macro starting with `@` that isn’t recognised.
+    UnknownMacro(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::FieldCount(count)        => write!(f, "{}: expected 5 or 6, got {}", self.description(), count),
+            Error::InvalidField(ref field)  => write!(f, "{}: {}", self.description(), field),
+            Error::UnknownMacro(ref name)   => write!(f, "{}: {}", self.description(), name),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::FieldCount(_)    => "wrong number of fields in cron expression",
+            Error::InvalidField(_)  => "invalid cron field",
+            Error::UnknownMacro(_)  => "unknown cron macro",
+        }
+    }
+}
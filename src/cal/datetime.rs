@@ -1,6 +1,6 @@
 //! Dates, times, datetimes, months, and weekdays.
 
-use std::cmp::{Ordering, PartialOrd};
+use std::cmp::{min, Ordering, PartialOrd};
 use std::error::Error as ErrorTrait;
 use std::fmt;
 use std::ops::{Add, Sub};
@@ -8,7 +8,7 @@ use std::ops::Deref;
 use std::ops::{Range, RangeFrom, RangeTo, RangeFull};
 use std::slice::Iter as SliceIter;
 
-use cal::{DatePiece, TimePiece};
+use cal::{DatePiece, TimePiece, ZonePiece};
 use cal::fmt::ISO;
 use duration::Duration;
 use instant::Instant;
@@ -360,6 +360,21 @@ const EPOCH_DIFFERENCE: i64 = (30 * 365      // 30 years between 2000 and 1970..
                                + 31 + 29);   // plus all the days in January and February in 2000.
 
 
+/// The Rata Die of **1st January, 1970**, counting the 1st of January in
+/// the year 1 as day 1.
+const RATA_DIE_OF_UNIX_EPOCH: i64 = 719_163;
+
+/// The Julian Day Number of the day *before* the 1st of January in the
+/// year 1, which is day 0 in the Rata Die count.
+const JULIAN_DAY_NUMBER_OF_RATA_DIE_EPOCH: i64 = 1_721_425;
+
+/// The difference between the Julian Day Number of a date and its Modified
+/// Julian Day. (The Modified Julian Date is the Julian Date minus
+/// 2,400,000.5, but as it also starts its days at midnight instead of
+/// noon, the two day numbers end up one further apart.)
+const MODIFIED_JULIAN_DAY_DIFFERENCE: i64 = 2_400_001;
+
+
 /// This rather strange triangle is an array of the number of days elapsed
 /// at the end of each month, starting at the beginning of March (the first
 /// month after the EPOCH above), going backwards, ignoring February.
@@ -566,13 +581,18 @@ impl LocalDate {
         // Calculate the numbers of 100-year cycles, 4-year cycles, and
         // leftover years, continually reducing the number of days left to
         // think about.
-        let num_100y_cycles = remainder / DAYS_IN_100Y;
+        //
+        // The cycles start in March, so the leap day comes at the very end
+        // of each one. That day would otherwise be counted as the start of
+        // a fifth century or year that doesn’t exist, so the counts need
+        // to be capped.
+        let num_100y_cycles = min(remainder / DAYS_IN_100Y, 3);
         remainder -= num_100y_cycles * DAYS_IN_100Y;  // remainder is now days left in this 100-year cycle
 
         let num_4y_cycles = remainder / DAYS_IN_4Y;
         remainder -= num_4y_cycles * DAYS_IN_4Y;  // remainder is now days left in this 4-year cycle
 
-        let mut years = remainder / 365;
+        let mut years = min(remainder / 365, 3);
         remainder -= years * 365;  // remainder is now days left in this year
 
         // Leap year calculation goes thusly:
@@ -640,6 +660,23 @@ impl LocalDate {
         }
     }
 
+    /// Returns the date that is the given number of days after this one,
+    /// or before it if the number is negative.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::February, 28).unwrap();
+    /// assert_eq!(date.add_days(1), LocalDate::ymd(2016, Month::February, 29).unwrap());
+    /// assert_eq!(date.add_days(-59), LocalDate::ymd(2015, Month::December, 31).unwrap());
+    /// ```
+    pub fn add_days(&self, days: i64) -> LocalDate {
+        let days_since_epoch = self.ymd.to_days_since_epoch().unwrap();
+        LocalDate::from_days_since_epoch(days_since_epoch + days - EPOCH_DIFFERENCE)
+    }
+
     /// Creates a new datestamp instance with the given year, month, day,
     /// weekday, and yearday fields.
     ///
@@ -661,6 +698,103 @@ impl LocalDate {
 
     // I’m not 100% convinced on using `unsafe` for something that doesn’t
     // technically *need* to be unsafe, but I’ll stick with it for now.
+
+    /// Returns the **ISO week date** year and week number of this date,
+    /// the inverse of `ywd`.
+    ///
+    /// Weeks start on Monday, and week 1 is the one with the year’s first
+    /// Thursday in it, so the days at either end of the year can belong to
+    /// a week in the year before or after.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::ymd(2021, Month::January, 3).unwrap();
+    /// assert_eq!(date.iso_week(), (2020, 53));
+    /// ```
+    pub fn iso_week(&self) -> (i64, i8) {
+        let year = self.ymd.year;
+        let week = (self.yearday as i64 - self.weekday.days_from_monday_as_one() as i64 + 10) / 7;
+
+        if week < 1 {
+            (year - 1, weeks_in_year(year - 1))
+        }
+        else if week > weeks_in_year(year) as i64 {
+            (year + 1, 1)
+        }
+        else {
+            (year, week as i8)
+        }
+    }
+
+    /// Computes a LocalDate given its **Rata Die**: the number of days
+    /// since the start of the proleptic Gregorian calendar, with the 1st
+    /// of January in the year 1 as day 1.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::from_rata_die(710_347);
+    /// assert_eq!(date, LocalDate::ymd(1945, Month::November, 12).unwrap());
+    /// ```
+    pub fn from_rata_die(rata_die: i64) -> LocalDate {
+        LocalDate::from_days_since_epoch(rata_die - RATA_DIE_OF_UNIX_EPOCH - EPOCH_DIFFERENCE)
+    }
+
+    /// Returns the **Rata Die** of this date. See `from_rata_die`.
+    pub fn to_rata_die(&self) -> i64 {
+        self.ymd.to_days_since_epoch().unwrap() + RATA_DIE_OF_UNIX_EPOCH
+    }
+
+    /// Computes a LocalDate given its **Julian Day Number**: the number of
+    /// days since the 1st of January, 4713 BC, in the Julian calendar.
+    ///
+    /// Julian days begin at noon, so the Julian Day Number returned is the
+    /// one for the day that *begins* at noon on this date.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::from_julian_day_number(2_451_545);
+    /// assert_eq!(date, LocalDate::ymd(2000, Month::January, 1).unwrap());
+    /// ```
+    pub fn from_julian_day_number(julian_day_number: i64) -> LocalDate {
+        LocalDate::from_rata_die(julian_day_number - JULIAN_DAY_NUMBER_OF_RATA_DIE_EPOCH)
+    }
+
+    /// Returns the **Julian Day Number** of this date. See
+    /// `from_julian_day_number`.
+    pub fn to_julian_day_number(&self) -> i64 {
+        self.to_rata_die() + JULIAN_DAY_NUMBER_OF_RATA_DIE_EPOCH
+    }
+
+    /// Computes a LocalDate given its **Modified Julian Day**: the number
+    /// of days since the 17th of November, 1858. Unlike Julian days,
+    /// modified Julian days begin at midnight.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::from_modified_julian_day(51_544);
+    /// assert_eq!(date, LocalDate::ymd(2000, Month::January, 1).unwrap());
+    /// ```
+    pub fn from_modified_julian_day(modified_julian_day: i64) -> LocalDate {
+        LocalDate::from_julian_day_number(modified_julian_day + MODIFIED_JULIAN_DAY_DIFFERENCE)
+    }
+
+    /// Returns the **Modified Julian Day** of this date. See
+    /// `from_modified_julian_day`.
+    pub fn to_modified_julian_day(&self) -> i64 {
+        self.to_julian_day_number() - MODIFIED_JULIAN_DAY_DIFFERENCE
+    }
 }
 
 impl DatePiece for LocalDate {
@@ -772,6 +906,86 @@ impl LocalTime {
             + self.minute as i64 * 60
             + self.second as i64
     }
+
+    /// Adds the given duration to this time, wrapping around at midnight,
+    /// and returns the resulting time along with the number of days that
+    /// were crossed. The number of days is negative if the duration is.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalTime, Duration};
+    ///
+    /// let late = LocalTime::hm(22, 0).unwrap();
+    /// let (time, days) = late.overflowing_add(Duration::of(5 * 60 * 60));
+    /// assert_eq!(time, LocalTime::hm(3, 0).unwrap());
+    /// assert_eq!(days, 1);
+    /// ```
+    pub fn overflowing_add(&self, duration: Duration) -> (LocalTime, i64) {
+        let (seconds, milliseconds) = duration.lengths();
+
+        // Split off whole days before multiplying, so that long durations
+        // can’t overflow.
+        let (days, seconds) = split_cycles(seconds, SECONDS_IN_DAY);
+        let total = self.to_seconds() * 1000 + self.millisecond as i64 + seconds * 1000 + milliseconds as i64;
+        let (extra_days, total) = split_cycles(total, SECONDS_IN_DAY * 1000);
+
+        let time = LocalTime::from_seconds_and_milliseconds_since_midnight(total / 1000, (total % 1000) as i16);
+        (time, days + extra_days)
+    }
+
+    /// Returns the duration from this time until the next occurrence of the
+    /// given time, wrapping around at midnight if the other time is
+    /// earlier in the day. The result is always less than a day long.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalTime, Duration};
+    ///
+    /// let evening = LocalTime::hm(22, 0).unwrap();
+    /// let morning = LocalTime::hm(6, 0).unwrap();
+    /// assert_eq!(evening.until(morning), Duration::of(8 * 60 * 60));
+    /// assert_eq!(morning.until(evening), Duration::of(16 * 60 * 60));
+    /// ```
+    pub fn until(&self, other: LocalTime) -> Duration {
+        let difference = other - *self;
+        let (seconds, milliseconds) = difference.lengths();
+        let (_, seconds) = split_cycles(seconds, SECONDS_IN_DAY);
+        Duration::of_ms(seconds, milliseconds)
+    }
+}
+
+impl Add<Duration> for LocalTime {
+    type Output = LocalTime;
+
+    /// Adds the duration to this time, wrapping around at midnight. Use
+    /// `overflowing_add` to find out how many days were crossed.
+    fn add(self, duration: Duration) -> LocalTime {
+        self.overflowing_add(duration).0
+    }
+}
+
+impl Sub<Duration> for LocalTime {
+    type Output = LocalTime;
+
+    fn sub(self, duration: Duration) -> LocalTime {
+        self.overflowing_add(Duration::zero() - duration).0
+    }
+}
+
+impl Sub<LocalTime> for LocalTime {
+    type Output = Duration;
+
+    /// Returns the duration between the two times on the same day, which is
+    /// negative if the other time is later.
+    fn sub(self, other: LocalTime) -> Duration {
+        let milliseconds = (self.to_seconds() - other.to_seconds()) * 1000
+                         + (self.millisecond - other.millisecond) as i64;
+
+        let (seconds, milliseconds) = split_cycles(milliseconds, 1000);
+        Duration::of_ms(seconds, milliseconds as i16)
+    }
 }
 
 impl TimePiece for LocalTime {
@@ -781,6 +995,8 @@ impl TimePiece for LocalTime {
     fn millisecond(&self) -> i16 { self.millisecond }
 }
 
+impl ZonePiece for LocalTime {}
+
 impl fmt::Debug for LocalTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "LocalTime({})", self.iso())
@@ -851,6 +1067,39 @@ impl LocalDateTime {
     pub fn add_seconds(&self, seconds: i64) -> LocalDateTime {
         Self::from_instant(self.to_instant() + Duration::of(seconds))
     }
+
+    /// Computes a complete date-time from a **Julian Date**, rounded to the
+    /// nearest millisecond. See `Instant::from_julian_date`.
+    pub fn from_julian_date(julian_date: f64) -> LocalDateTime {
+        LocalDateTime::from_instant(Instant::from_julian_date(julian_date))
+    }
+
+    /// Returns the **Julian Date** of this date-time.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+    ///
+    /// let j2000 = LocalDateTime::new(LocalDate::ymd(2000, Month::January, 1).unwrap(),
+    ///                                LocalTime::hms(12, 0, 0).unwrap());
+    /// assert_eq!(j2000.to_julian_date(), 2_451_545.0);
+    /// ```
+    pub fn to_julian_date(&self) -> f64 {
+        self.to_instant().to_julian_date()
+    }
+
+    /// Computes a complete date-time from a **Modified Julian Date**,
+    /// rounded to the nearest millisecond. See
+    /// `Instant::from_modified_julian_date`.
+    pub fn from_modified_julian_date(modified_julian_date: f64) -> LocalDateTime {
+        LocalDateTime::from_instant(Instant::from_modified_julian_date(modified_julian_date))
+    }
+
+    /// Returns the **Modified Julian Date** of this date-time.
+    pub fn to_modified_julian_date(&self) -> f64 {
+        self.to_instant().to_modified_julian_date()
+    }
 }
 
 impl DatePiece for LocalDateTime {
@@ -868,6 +1117,8 @@ impl TimePiece for LocalDateTime {
     fn millisecond(&self) -> i16 { self.time.millisecond }
 }
 
+impl ZonePiece for LocalDateTime {}
+
 impl fmt::Debug for LocalDateTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "LocalDateTime({})", self.iso())
@@ -972,6 +1223,19 @@ fn days_to_weekday(days: i64) -> Weekday {
     Weekday::from_zero(if weekday < 0 { weekday + 7 } else { weekday } as i8).unwrap()
 }
 
+/// Returns the number of ISO weeks in the given year: 53 if it starts on a
+/// Thursday, or if it’s a leap year that starts on a Wednesday, and 52
+/// otherwise.
+fn weeks_in_year(year: i64) -> i8 {
+    let jan_1 = YMD { year: year, month: January, day: 1 };
+
+    match days_to_weekday(jan_1.to_days_since_epoch().unwrap() - EPOCH_DIFFERENCE) {
+        Thursday                                       => 53,
+        Wednesday if Year(year).is_leap_year()         => 53,
+        _                                              => 52,
+    }
+}
+
 /// Split a number of years into a number of year-cycles, and the number
 /// of years left over that don’t fit into a cycle. This is also used
 /// for day-cycles.
@@ -980,7 +1244,7 @@ fn days_to_weekday(days: i64) -> Weekday {
 /// remainder, with the difference that a negative value gets ‘wrapped
 /// around’ to be a positive value, owing to the way the modulo operator
 /// works for negative values.
-fn split_cycles(number_of_periods: i64, cycle_length: i64) -> (i64, i64) {
+pub(crate) fn split_cycles(number_of_periods: i64, cycle_length: i64) -> (i64, i64) {
     let mut cycles    = number_of_periods / cycle_length;
     let mut remainder = number_of_periods % cycle_length;
 
@@ -0,0 +1,188 @@
+//! Computus: working out the date of Easter, and the feasts that move
+//! along with it.
+
+use cal::datetime::{LocalDate, Month, Year, split_cycles};
+
+
+impl Year {
+
+    /// Returns the date of Easter Sunday in this year, as celebrated by
+    /// the Western churches and computed with the Gregorian calendar.
+    ///
+    /// The calculation is the “anonymous Gregorian algorithm” published in
+    /// *Nature* in 1876, and is carried out on the proleptic Gregorian
+    /// calendar, so it gives an answer for years before 1583 too, even
+    /// though nobody was using it back then.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, LocalDate, Month};
+    ///
+    /// assert_eq!(Year(2024).easter_gregorian(), LocalDate::ymd(2024, Month::March, 31).unwrap());
+    /// assert_eq!(Year(2025).easter_gregorian(), LocalDate::ymd(2025, Month::April, 20).unwrap());
+    /// ```
+    pub fn easter_gregorian(&self) -> LocalDate {
+        let year = self.0;
+
+        let a = split_cycles(year, 19).1;          // position in the Metonic cycle
+        let (b, c) = split_cycles(year, 100);      // century, and year of the century
+        let (d, e) = split_cycles(b, 4);
+        let f = split_cycles(b + 8, 25).0;         // lunar correction
+        let g = split_cycles(b - f + 1, 3).0;
+        let h = split_cycles(19 * a + b - d - g + 15, 30).1;   // epact, more or less
+        let i = c / 4;
+        let k = c % 4;
+        let l = split_cycles(32 + 2 * e + 2 * i - h - k, 7).1;  // days to the next Sunday
+        let m = (a + 11 * h + 22 * l) / 451;
+
+        let n = h + l - 7 * m + 114;
+        let month = Month::from_one((n / 31) as i8).unwrap();
+        let day = (n % 31 + 1) as i8;
+
+        LocalDate::ymd(year, month, day).unwrap()
+    }
+
+    /// Returns the month and day of Easter Sunday in this year as computed
+    /// with the Julian calendar, which is what the Eastern Orthodox
+    /// churches still use for their computus.
+    ///
+    /// The month and day returned here are *Julian* calendar fields, which
+    /// is why they’re not returned as a `LocalDate`. For the same day
+    /// expressed in the Gregorian calendar, use `easter_orthodox`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    /// use datetime::Month::April;
+    ///
+    /// assert_eq!(Year(2024).easter_julian(), (April, 22));
+    /// ```
+    pub fn easter_julian(&self) -> (Month, i8) {
+        let year = self.0;
+
+        let a = split_cycles(year, 4).1;
+        let b = split_cycles(year, 7).1;
+        let c = split_cycles(year, 19).1;
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b - d + 34) % 7;
+
+        let n = d + e + 114;
+        let month = Month::from_one((n / 31) as i8).unwrap();
+        let day = (n % 31 + 1) as i8;
+
+        (month, day)
+    }
+
+    /// Returns the date of Orthodox Easter Sunday in this year, which is
+    /// computed with the Julian calendar and then converted to the
+    /// Gregorian one.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, LocalDate, Month};
+    ///
+    /// assert_eq!(Year(2024).easter_orthodox(), LocalDate::ymd(2024, Month::May, 5).unwrap());
+    /// assert_eq!(Year(2025).easter_orthodox(), LocalDate::ymd(2025, Month::April, 20).unwrap());
+    /// ```
+    pub fn easter_orthodox(&self) -> LocalDate {
+        let (month, day) = self.easter_julian();
+
+        // Easter always falls after the end of February, so the difference
+        // between the two calendars is the one that applies for the rest
+        // of this year: one day for every century year that’s a leap year
+        // in the Julian calendar but not in the Gregorian one.
+        let difference = split_cycles(self.0, 100).0 - split_cycles(self.0, 400).0 - 2;
+
+        // The Julian month and day are always a valid Gregorian date too,
+        // as Easter can only fall between late March and early May.
+        LocalDate::ymd(self.0, month, day).unwrap().add_days(difference)
+    }
+}
+
+
+/// A **movable feast** is a holiday whose date depends on the date of
+/// Easter in that year, rather than being fixed to a particular day of a
+/// particular month.
+///
+/// The same offsets are used in both the Western and Orthodox calendars,
+/// so pass in whichever date of Easter applies.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{Year, LocalDate, Month, MovableFeast};
+///
+/// let easter = Year(2024).easter_gregorian();
+/// assert_eq!(MovableFeast::GoodFriday.relative_to(easter), LocalDate::ymd(2024, Month::March, 29).unwrap());
+/// assert_eq!(MovableFeast::Pentecost.relative_to(easter),  LocalDate::ymd(2024, Month::May, 19).unwrap());
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum MovableFeast {
+
+    /// The first day of Lent, six and a half weeks before Easter.
+    AshWednesday,
+
+    /// The Sunday before Easter.
+    PalmSunday,
+
+    /// The Thursday before Easter.
+    MaundyThursday,
+
+    /// The Friday before Easter.
+    GoodFriday,
+
+    /// The Saturday before Easter.
+    HolySaturday,
+
+    /// Easter itself.
+    EasterSunday,
+
+    /// The Monday after Easter.
+    EasterMonday,
+
+    /// The fortieth day of Easter, which is always a Thursday.
+    Ascension,
+
+    /// The seventh Sunday after Easter, also known as Whit Sunday.
+    Pentecost,
+
+    /// The Monday after Pentecost.
+    WhitMonday,
+
+    /// The Sunday after Pentecost.
+    TrinitySunday,
+
+    /// The Thursday after Trinity Sunday.
+    CorpusChristi,
+}
+
+impl MovableFeast {
+
+    /// Returns the number of days between Easter Sunday and this feast,
+    /// which is negative for feasts that come before Easter.
+    pub fn days_from_easter(&self) -> i64 {
+        match *self {
+            MovableFeast::AshWednesday    => -46,
+            MovableFeast::PalmSunday      =>  -7,
+            MovableFeast::MaundyThursday  =>  -3,
+            MovableFeast::GoodFriday      =>  -2,
+            MovableFeast::HolySaturday    =>  -1,
+            MovableFeast::EasterSunday    =>   0,
+            MovableFeast::EasterMonday    =>   1,
+            MovableFeast::Ascension       =>  39,
+            MovableFeast::Pentecost       =>  49,
+            MovableFeast::WhitMonday      =>  50,
+            MovableFeast::TrinitySunday   =>  56,
+            MovableFeast::CorpusChristi   =>  60,
+        }
+    }
+
+    /// Returns the date of this feast, given the date of Easter Sunday in
+    /// the same year.
+    pub fn relative_to(&self, easter: LocalDate) -> LocalDate {
+        easter.add_days(self.days_from_easter())
+    }
+}
@@ -0,0 +1,1095 @@
+// This file is generated by cldr/generate.py from the CLDR data in the
+// cldr directory. Don’t edit it by hand!
+
+use cal::datetime::Weekday;
+use super::{Locale, Names, Patterns, Plural, PluralRule, Relation, RelativeTimes, RelativeNames, Plurals};
+
+
+pub static LOCALES: [Locale; 6] = [
+    Locale {
+        tag: "de",
+        months: Names {
+            abbreviated: ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."],
+            wide:        ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"],
+        },
+        standalone_months: Names {
+            abbreviated: ["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"],
+            wide:        ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"],
+        },
+        weekdays: Names {
+            abbreviated: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+            wide:        ["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+        },
+        standalone_weekdays: Names {
+            abbreviated: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+            wide:        ["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+        },
+        eras: Names {
+            abbreviated: ["v. Chr.", "n. Chr."],
+            wide:        ["v. Chr.", "n. Chr."],
+        },
+        am_pm: ["AM", "PM"],
+        date_patterns: Patterns {
+            full:   "EEEE, d. MMMM y",
+            long:   "d. MMMM y",
+            medium: "dd.MM.y",
+            short:  "dd.MM.yy",
+        },
+        time_patterns: Patterns {
+            full:   "HH:mm:ss zzzz",
+            long:   "HH:mm:ss z",
+            medium: "HH:mm:ss",
+            short:  "HH:mm",
+        },
+        date_time_patterns: Patterns {
+            full:   "{1}, {0}",
+            long:   "{1}, {0}",
+            medium: "{1}, {0}",
+            short:  "{1}, {0}",
+        },
+        first_weekday: Weekday::Monday,
+        plural_rules: &[
+            PluralRule { category: Plural::One, conditions: &[&[Relation { modulo: None, negated: false, ranges: &[(1, 1)] }]] },
+        ],
+        relative_times: RelativeTimes {
+            year: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} Jahr"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} Jahren",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("vor {0} Jahr"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "vor {0} Jahren",
+                },
+                idioms: &[(-1, "letztes Jahr"), (0, "dieses Jahr"), (1, "nächstes Jahr")],
+            },
+            month: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} Monat"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} Monaten",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("vor {0} Monat"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "vor {0} Monaten",
+                },
+                idioms: &[(-1, "letzten Monat"), (0, "diesen Monat"), (1, "nächsten Monat")],
+            },
+            week: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} Woche"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} Wochen",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("vor {0} Woche"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "vor {0} Wochen",
+                },
+                idioms: &[(-1, "letzte Woche"), (0, "diese Woche"), (1, "nächste Woche")],
+            },
+            day: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} Tag"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} Tagen",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("vor {0} Tag"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "vor {0} Tagen",
+                },
+                idioms: &[(-2, "vorgestern"), (-1, "gestern"), (0, "heute"), (1, "morgen"), (2, "übermorgen")],
+            },
+            hour: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} Stunde"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} Stunden",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("vor {0} Stunde"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "vor {0} Stunden",
+                },
+                idioms: &[(0, "in dieser Stunde")],
+            },
+            minute: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} Minute"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} Minuten",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("vor {0} Minute"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "vor {0} Minuten",
+                },
+                idioms: &[(0, "in dieser Minute")],
+            },
+            second: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} Sekunde"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} Sekunden",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("vor {0} Sekunde"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "vor {0} Sekunden",
+                },
+                idioms: &[(0, "jetzt")],
+            },
+        },
+    },
+    Locale {
+        tag: "en",
+        months: Names {
+            abbreviated: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+            wide:        ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+        },
+        standalone_months: Names {
+            abbreviated: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+            wide:        ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+        },
+        weekdays: Names {
+            abbreviated: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            wide:        ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+        },
+        standalone_weekdays: Names {
+            abbreviated: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            wide:        ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+        },
+        eras: Names {
+            abbreviated: ["BC", "AD"],
+            wide:        ["Before Christ", "Anno Domini"],
+        },
+        am_pm: ["AM", "PM"],
+        date_patterns: Patterns {
+            full:   "EEEE, MMMM d, y",
+            long:   "MMMM d, y",
+            medium: "MMM d, y",
+            short:  "M/d/yy",
+        },
+        time_patterns: Patterns {
+            full:   "h:mm:ss\u{202f}a zzzz",
+            long:   "h:mm:ss\u{202f}a z",
+            medium: "h:mm:ss\u{202f}a",
+            short:  "h:mm\u{202f}a",
+        },
+        date_time_patterns: Patterns {
+            full:   "{1}, {0}",
+            long:   "{1}, {0}",
+            medium: "{1}, {0}",
+            short:  "{1}, {0}",
+        },
+        first_weekday: Weekday::Sunday,
+        plural_rules: &[
+            PluralRule { category: Plural::One, conditions: &[&[Relation { modulo: None, negated: false, ranges: &[(1, 1)] }]] },
+        ],
+        relative_times: RelativeTimes {
+            year: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} year"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} years",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} year ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} years ago",
+                },
+                idioms: &[(-1, "last year"), (0, "this year"), (1, "next year")],
+            },
+            month: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} month"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} months",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} month ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} months ago",
+                },
+                idioms: &[(-1, "last month"), (0, "this month"), (1, "next month")],
+            },
+            week: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} week"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} weeks",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} week ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} weeks ago",
+                },
+                idioms: &[(-1, "last week"), (0, "this week"), (1, "next week")],
+            },
+            day: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} day"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} days",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} day ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} days ago",
+                },
+                idioms: &[(-1, "yesterday"), (0, "today"), (1, "tomorrow")],
+            },
+            hour: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} hour"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} hours",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} hour ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} hours ago",
+                },
+                idioms: &[(0, "this hour")],
+            },
+            minute: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} minute"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} minutes",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} minute ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} minutes ago",
+                },
+                idioms: &[(0, "this minute")],
+            },
+            second: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} second"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} seconds",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} second ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} seconds ago",
+                },
+                idioms: &[(0, "now")],
+            },
+        },
+    },
+    Locale {
+        tag: "en-GB",
+        months: Names {
+            abbreviated: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"],
+            wide:        ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+        },
+        standalone_months: Names {
+            abbreviated: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sept", "Oct", "Nov", "Dec"],
+            wide:        ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+        },
+        weekdays: Names {
+            abbreviated: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            wide:        ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+        },
+        standalone_weekdays: Names {
+            abbreviated: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            wide:        ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+        },
+        eras: Names {
+            abbreviated: ["BC", "AD"],
+            wide:        ["Before Christ", "Anno Domini"],
+        },
+        am_pm: ["am", "pm"],
+        date_patterns: Patterns {
+            full:   "EEEE d MMMM y",
+            long:   "d MMMM y",
+            medium: "d MMM y",
+            short:  "dd/MM/y",
+        },
+        time_patterns: Patterns {
+            full:   "HH:mm:ss zzzz",
+            long:   "HH:mm:ss z",
+            medium: "HH:mm:ss",
+            short:  "HH:mm",
+        },
+        date_time_patterns: Patterns {
+            full:   "{1}, {0}",
+            long:   "{1}, {0}",
+            medium: "{1}, {0}",
+            short:  "{1}, {0}",
+        },
+        first_weekday: Weekday::Monday,
+        plural_rules: &[
+            PluralRule { category: Plural::One, conditions: &[&[Relation { modulo: None, negated: false, ranges: &[(1, 1)] }]] },
+        ],
+        relative_times: RelativeTimes {
+            year: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} year"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} years",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} year ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} years ago",
+                },
+                idioms: &[(-1, "last year"), (0, "this year"), (1, "next year")],
+            },
+            month: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} month"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} months",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} month ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} months ago",
+                },
+                idioms: &[(-1, "last month"), (0, "this month"), (1, "next month")],
+            },
+            week: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} week"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} weeks",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} week ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} weeks ago",
+                },
+                idioms: &[(-1, "last week"), (0, "this week"), (1, "next week")],
+            },
+            day: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} day"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} days",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} day ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} days ago",
+                },
+                idioms: &[(-1, "yesterday"), (0, "today"), (1, "tomorrow")],
+            },
+            hour: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} hour"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} hours",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} hour ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} hours ago",
+                },
+                idioms: &[(0, "this hour")],
+            },
+            minute: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} minute"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} minutes",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} minute ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} minutes ago",
+                },
+                idioms: &[(0, "this minute")],
+            },
+            second: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("in {0} second"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "in {0} seconds",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("{0} second ago"),
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} seconds ago",
+                },
+                idioms: &[(0, "now")],
+            },
+        },
+    },
+    Locale {
+        tag: "es",
+        months: Names {
+            abbreviated: ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+            wide:        ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+        },
+        standalone_months: Names {
+            abbreviated: ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+            wide:        ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+        },
+        weekdays: Names {
+            abbreviated: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+            wide:        ["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+        },
+        standalone_weekdays: Names {
+            abbreviated: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+            wide:        ["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+        },
+        eras: Names {
+            abbreviated: ["a. C.", "d. C."],
+            wide:        ["antes de Cristo", "después de Cristo"],
+        },
+        am_pm: ["a.\u{a0}m.", "p.\u{a0}m."],
+        date_patterns: Patterns {
+            full:   "EEEE, d 'de' MMMM 'de' y",
+            long:   "d 'de' MMMM 'de' y",
+            medium: "d MMM y",
+            short:  "d/M/yy",
+        },
+        time_patterns: Patterns {
+            full:   "H:mm:ss (zzzz)",
+            long:   "H:mm:ss z",
+            medium: "H:mm:ss",
+            short:  "H:mm",
+        },
+        date_time_patterns: Patterns {
+            full:   "{1}, {0}",
+            long:   "{1}, {0}",
+            medium: "{1}, {0}",
+            short:  "{1}, {0}",
+        },
+        first_weekday: Weekday::Monday,
+        plural_rules: &[
+            PluralRule { category: Plural::One, conditions: &[&[Relation { modulo: None, negated: false, ranges: &[(1, 1)] }]] },
+            PluralRule { category: Plural::Many, conditions: &[&[Relation { modulo: None, negated: true, ranges: &[(0, 0)] }, Relation { modulo: Some(1000000), negated: false, ranges: &[(0, 0)] }]] },
+        ],
+        relative_times: RelativeTimes {
+            year: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dentro de {0} año"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dentro de {0} años"),
+                    other: "dentro de {0} años",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("hace {0} año"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("hace {0} años"),
+                    other: "hace {0} años",
+                },
+                idioms: &[(-1, "el año pasado"), (0, "este año"), (1, "el próximo año")],
+            },
+            month: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dentro de {0} mes"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dentro de {0} meses"),
+                    other: "dentro de {0} meses",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("hace {0} mes"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("hace {0} meses"),
+                    other: "hace {0} meses",
+                },
+                idioms: &[(-1, "el mes pasado"), (0, "este mes"), (1, "el próximo mes")],
+            },
+            week: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dentro de {0} semana"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dentro de {0} semanas"),
+                    other: "dentro de {0} semanas",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("hace {0} semana"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("hace {0} semanas"),
+                    other: "hace {0} semanas",
+                },
+                idioms: &[(-1, "la semana pasada"), (0, "esta semana"), (1, "la próxima semana")],
+            },
+            day: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dentro de {0} día"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dentro de {0} días"),
+                    other: "dentro de {0} días",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("hace {0} día"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("hace {0} días"),
+                    other: "hace {0} días",
+                },
+                idioms: &[(-2, "anteayer"), (-1, "ayer"), (0, "hoy"), (1, "mañana"), (2, "pasado mañana")],
+            },
+            hour: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dentro de {0} hora"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dentro de {0} horas"),
+                    other: "dentro de {0} horas",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("hace {0} hora"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("hace {0} horas"),
+                    other: "hace {0} horas",
+                },
+                idioms: &[(0, "esta hora")],
+            },
+            minute: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dentro de {0} minuto"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dentro de {0} minutos"),
+                    other: "dentro de {0} minutos",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("hace {0} minuto"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("hace {0} minutos"),
+                    other: "hace {0} minutos",
+                },
+                idioms: &[(0, "este minuto")],
+            },
+            second: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dentro de {0} segundo"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dentro de {0} segundos"),
+                    other: "dentro de {0} segundos",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("hace {0} segundo"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("hace {0} segundos"),
+                    other: "hace {0} segundos",
+                },
+                idioms: &[(0, "ahora")],
+            },
+        },
+    },
+    Locale {
+        tag: "fr",
+        months: Names {
+            abbreviated: ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."],
+            wide:        ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+        },
+        standalone_months: Names {
+            abbreviated: ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."],
+            wide:        ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+        },
+        weekdays: Names {
+            abbreviated: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+            wide:        ["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+        },
+        standalone_weekdays: Names {
+            abbreviated: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+            wide:        ["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+        },
+        eras: Names {
+            abbreviated: ["av. J.-C.", "ap. J.-C."],
+            wide:        ["avant Jésus-Christ", "après Jésus-Christ"],
+        },
+        am_pm: ["AM", "PM"],
+        date_patterns: Patterns {
+            full:   "EEEE d MMMM y",
+            long:   "d MMMM y",
+            medium: "d MMM y",
+            short:  "dd/MM/y",
+        },
+        time_patterns: Patterns {
+            full:   "HH:mm:ss zzzz",
+            long:   "HH:mm:ss z",
+            medium: "HH:mm:ss",
+            short:  "HH:mm",
+        },
+        date_time_patterns: Patterns {
+            full:   "{1}, {0}",
+            long:   "{1}, {0}",
+            medium: "{1} {0}",
+            short:  "{1} {0}",
+        },
+        first_weekday: Weekday::Monday,
+        plural_rules: &[
+            PluralRule { category: Plural::One, conditions: &[&[Relation { modulo: None, negated: false, ranges: &[(0, 0), (1, 1)] }]] },
+            PluralRule { category: Plural::Many, conditions: &[&[Relation { modulo: None, negated: true, ranges: &[(0, 0)] }, Relation { modulo: Some(1000000), negated: false, ranges: &[(0, 0)] }]] },
+        ],
+        relative_times: RelativeTimes {
+            year: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dans {0} an"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dans {0} ans"),
+                    other: "dans {0} ans",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("il y a {0} an"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("il y a {0} ans"),
+                    other: "il y a {0} ans",
+                },
+                idioms: &[(-1, "l’année dernière"), (0, "cette année"), (1, "l’année prochaine")],
+            },
+            month: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dans {0} mois"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dans {0} mois"),
+                    other: "dans {0} mois",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("il y a {0} mois"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("il y a {0} mois"),
+                    other: "il y a {0} mois",
+                },
+                idioms: &[(-1, "le mois dernier"), (0, "ce mois-ci"), (1, "le mois prochain")],
+            },
+            week: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dans {0} semaine"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dans {0} semaines"),
+                    other: "dans {0} semaines",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("il y a {0} semaine"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("il y a {0} semaines"),
+                    other: "il y a {0} semaines",
+                },
+                idioms: &[(-1, "la semaine dernière"), (0, "cette semaine"), (1, "la semaine prochaine")],
+            },
+            day: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dans {0} jour"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dans {0} jours"),
+                    other: "dans {0} jours",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("il y a {0} jour"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("il y a {0} jours"),
+                    other: "il y a {0} jours",
+                },
+                idioms: &[(-2, "avant-hier"), (-1, "hier"), (0, "aujourd’hui"), (1, "demain"), (2, "après-demain")],
+            },
+            hour: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dans {0} heure"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dans {0} heures"),
+                    other: "dans {0} heures",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("il y a {0} heure"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("il y a {0} heures"),
+                    other: "il y a {0} heures",
+                },
+                idioms: &[(0, "cette heure-ci")],
+            },
+            minute: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dans {0} minute"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dans {0} minutes"),
+                    other: "dans {0} minutes",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("il y a {0} minute"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("il y a {0} minutes"),
+                    other: "il y a {0} minutes",
+                },
+                idioms: &[(0, "cette minute-ci")],
+            },
+            second: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   Some("dans {0} seconde"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("dans {0} secondes"),
+                    other: "dans {0} secondes",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   Some("il y a {0} seconde"),
+                    two:   None,
+                    few:   None,
+                    many:  Some("il y a {0} secondes"),
+                    other: "il y a {0} secondes",
+                },
+                idioms: &[(0, "maintenant")],
+            },
+        },
+    },
+    Locale {
+        tag: "ja",
+        months: Names {
+            abbreviated: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+            wide:        ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        },
+        standalone_months: Names {
+            abbreviated: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+            wide:        ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        },
+        weekdays: Names {
+            abbreviated: ["日", "月", "火", "水", "木", "金", "土"],
+            wide:        ["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"],
+        },
+        standalone_weekdays: Names {
+            abbreviated: ["日", "月", "火", "水", "木", "金", "土"],
+            wide:        ["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"],
+        },
+        eras: Names {
+            abbreviated: ["紀元前", "西暦"],
+            wide:        ["紀元前", "西暦"],
+        },
+        am_pm: ["午前", "午後"],
+        date_patterns: Patterns {
+            full:   "y年M月d日EEEE",
+            long:   "y年M月d日",
+            medium: "y/MM/dd",
+            short:  "y/MM/dd",
+        },
+        time_patterns: Patterns {
+            full:   "H時mm分ss秒 zzzz",
+            long:   "H:mm:ss z",
+            medium: "H:mm:ss",
+            short:  "H:mm",
+        },
+        date_time_patterns: Patterns {
+            full:   "{1} {0}",
+            long:   "{1} {0}",
+            medium: "{1} {0}",
+            short:  "{1} {0}",
+        },
+        first_weekday: Weekday::Sunday,
+        plural_rules: &[],
+        relative_times: RelativeTimes {
+            year: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 年後",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 年前",
+                },
+                idioms: &[(-1, "昨年"), (0, "今年"), (1, "来年")],
+            },
+            month: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} か月後",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} か月前",
+                },
+                idioms: &[(-1, "先月"), (0, "今月"), (1, "来月")],
+            },
+            week: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 週間後",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 週間前",
+                },
+                idioms: &[(-1, "先週"), (0, "今週"), (1, "来週")],
+            },
+            day: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 日後",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 日前",
+                },
+                idioms: &[(-2, "一昨日"), (-1, "昨日"), (0, "今日"), (1, "明日"), (2, "明後日")],
+            },
+            hour: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 時間後",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 時間前",
+                },
+                idioms: &[(0, "1 時間以内")],
+            },
+            minute: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 分後",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 分前",
+                },
+                idioms: &[(0, "1 分以内")],
+            },
+            second: RelativeNames {
+                future: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 秒後",
+                },
+                past: Plurals {
+                    zero:  None,
+                    one:   None,
+                    two:   None,
+                    few:   None,
+                    many:  None,
+                    other: "{0} 秒前",
+                },
+                idioms: &[(0, "今")],
+            },
+        },
+    },
+];
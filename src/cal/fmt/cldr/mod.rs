@@ -0,0 +1,275 @@
+//! Locale data from the Unicode Common Locale Data Repository (CLDR): the
+//! names of months, weekdays, and eras, the date and time patterns each
+//! locale prefers, the weekday its weeks start on, and the words it uses
+//! for relative times, along with its plural rules.
+//!
+//! Unlike `locale::Time`, which asks the platform, this data is built into
+//! the library, so it’s the same everywhere. It’s generated from the CLDR
+//! JSON in the `cldr` directory of the repository by `cldr/generate.py`,
+//! and only covers the Gregorian calendar.
+
+use std::borrow::Cow;
+
+use cal::datetime::Weekday;
+use cal::fmt::custom::LocaleNames;
+use cal::fmt::relative::RelativeUnit;
+
+mod data;
+
+
+/// The data for one locale.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Locale {
+    tag: &'static str,
+    months: Names<[&'static str; 12]>,
+    standalone_months: Names<[&'static str; 12]>,
+    weekdays: Names<[&'static str; 7]>,
+    standalone_weekdays: Names<[&'static str; 7]>,
+    eras: Names<[&'static str; 2]>,
+    am_pm: [&'static str; 2],
+    date_patterns: Patterns,
+    time_patterns: Patterns,
+    date_time_patterns: Patterns,
+    first_weekday: Weekday,
+    plural_rules: &'static [PluralRule],
+    relative_times: RelativeTimes,
+}
+
+/// The short and long forms of a set of names.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct Names<T> {
+    abbreviated: T,
+    wide: T,
+}
+
+/// A locale’s LDML patterns for each length of date, time, or both, from
+/// the longest to the shortest.
+///
+/// The patterns for dates with times have `{1}` where the date goes and
+/// `{0}` where the time goes.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Patterns {
+    pub full: &'static str,
+    pub long: &'static str,
+    pub medium: &'static str,
+    pub short: &'static str,
+}
+
+/// The plural categories that CLDR sorts numbers into, which pick the
+/// form of the words that they count. Which ones a language uses, and
+/// which numbers go in them, depends on the language; English only uses
+/// `One` and `Other`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Plural {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// The numbers that are in one plural category, which are those matching
+/// every relation in any of the conditions.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct PluralRule {
+    category: Plural,
+    conditions: &'static [&'static [Relation]],
+}
+
+/// Whether a number, or its remainder after dividing by the modulo, is in
+/// one of the ranges, or isn’t in any of them if the relation is negated.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct Relation {
+    modulo: Option<u64>,
+    negated: bool,
+    ranges: &'static [(u64, u64)],
+}
+
+/// The words for relative times in each unit.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct RelativeTimes {
+    year: RelativeNames,
+    month: RelativeNames,
+    week: RelativeNames,
+    day: RelativeNames,
+    hour: RelativeNames,
+    minute: RelativeNames,
+    second: RelativeNames,
+}
+
+/// The patterns for times some number of a unit ahead or behind, such as
+/// `in {0} days`, and the words for particular numbers of them, such as
+/// `yesterday` for -1 days.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct RelativeNames {
+    future: Plurals,
+    past: Plurals,
+    idioms: &'static [(i64, &'static str)],
+}
+
+/// A pattern for each plural category, with `other` used for any that
+/// the language doesn’t have.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct Plurals {
+    zero: Option<&'static str>,
+    one: Option<&'static str>,
+    two: Option<&'static str>,
+    few: Option<&'static str>,
+    many: Option<&'static str>,
+    other: &'static str,
+}
+
+impl Locale {
+
+    /// Returns the data for the locale with the given BCP 47 language tag,
+    /// such as `en-GB`, ignoring case. When there’s no data for the exact
+    /// tag, subtags are taken off the end until there is, so `de-AT` uses
+    /// the data for `de`. Underscores are treated as hyphens, so POSIX
+    /// locale names such as `fr_FR` work too.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::cldr::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("en-GB").unwrap().tag(), "en-GB");
+    /// assert_eq!(Locale::from_tag("de-DE").unwrap().tag(), "de");
+    /// assert_eq!(Locale::from_tag("tlh"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<&'static Locale> {
+        let mut tag = tag.replace('_', "-");
+
+        loop {
+            if let Some(locale) = data::LOCALES.iter().find(|locale| locale.tag.eq_ignore_ascii_case(&tag)) {
+                return Some(locale);
+            }
+
+            match tag.rfind('-') {
+                Some(index)  => tag.truncate(index),
+                None         => return None,
+            }
+        }
+    }
+
+    /// Returns the data for every locale there is.
+    pub fn all() -> &'static [Locale] {
+        &data::LOCALES
+    }
+
+    /// Returns this locale’s language tag, such as `en` or `en-GB`.
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+
+    /// Returns this locale’s patterns for dates, such as `M/d/yy`.
+    pub fn date_patterns(&self) -> &Patterns {
+        &self.date_patterns
+    }
+
+    /// Returns this locale’s patterns for times, such as `HH:mm`.
+    pub fn time_patterns(&self) -> &Patterns {
+        &self.time_patterns
+    }
+
+    /// Returns this locale’s patterns for putting a date and a time
+    /// together, such as `{1}, {0}`.
+    pub fn date_time_patterns(&self) -> &Patterns {
+        &self.date_time_patterns
+    }
+
+    /// Returns the weekday that weeks start on in this locale’s country.
+    pub fn first_weekday(&self) -> Weekday {
+        self.first_weekday
+    }
+
+    /// Returns the plural category that a whole number is in, using this
+    /// locale’s plural rules.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::cldr::{Locale, Plural};
+    ///
+    /// assert_eq!(Locale::from_tag("en").unwrap().plural(0), Plural::Other);
+    /// assert_eq!(Locale::from_tag("fr").unwrap().plural(0), Plural::One);
+    /// ```
+    pub fn plural(&self, number: u64) -> Plural {
+        self.plural_rules.iter()
+            .find(|rule| rule.conditions.iter().any(|relations| relations.iter().all(|r| r.matches(number))))
+            .map_or(Plural::Other, |rule| rule.category)
+    }
+
+    /// Returns this locale’s pattern for a time some number of the given
+    /// unit ahead or behind, where the number is in the given plural
+    /// category, such as `in {0} days` or `{0} day ago`.
+    pub fn relative_time_pattern(&self, unit: RelativeUnit, future: bool, plural: Plural) -> &'static str {
+        let names = self.relative_names(unit);
+        let plurals = if future { &names.future } else { &names.past };
+
+        let pattern = match plural {
+            Plural::Zero   => plurals.zero,
+            Plural::One    => plurals.one,
+            Plural::Two    => plurals.two,
+            Plural::Few    => plurals.few,
+            Plural::Many   => plurals.many,
+            Plural::Other  => None,
+        };
+
+        pattern.unwrap_or(plurals.other)
+    }
+
+    /// Returns this locale’s word for a time the given number of a unit
+    /// ahead or behind, such as `yesterday` for -1 days, if it has one.
+    pub fn relative_idiom(&self, unit: RelativeUnit, offset: i64) -> Option<&'static str> {
+        self.relative_names(unit).idioms.iter()
+            .find(|&&(idiom_offset, _)| idiom_offset == offset)
+            .map(|&(_, idiom)| idiom)
+    }
+
+    fn relative_names(&self, unit: RelativeUnit) -> &RelativeNames {
+        let times = &self.relative_times;
+        match unit {
+            RelativeUnit::Second  => &times.second,
+            RelativeUnit::Minute  => &times.minute,
+            RelativeUnit::Hour    => &times.hour,
+            RelativeUnit::Day     => &times.day,
+            RelativeUnit::Week    => &times.week,
+            RelativeUnit::Month   => &times.month,
+            RelativeUnit::Year    => &times.year,
+        }
+    }
+}
+
+impl Relation {
+    fn matches(&self, number: u64) -> bool {
+        let value = self.modulo.map_or(number, |modulo| number % modulo);
+        self.ranges.iter().any(|&(low, high)| low <= value && value <= high) != self.negated
+    }
+}
+
+impl<T> Names<T> {
+    fn get(&self, long: bool) -> &T {
+        if long { &self.wide } else { &self.abbreviated }
+    }
+}
+
+impl LocaleNames for Locale {
+    fn month_name(&self, months_from_january: usize, long: bool, standalone: bool) -> Cow<'_, str> {
+        let names = if standalone { &self.standalone_months } else { &self.months };
+        Cow::Borrowed(names.get(long)[months_from_january])
+    }
+
+    fn weekday_name(&self, days_from_sunday: usize, long: bool, standalone: bool) -> Cow<'_, str> {
+        let names = if standalone { &self.standalone_weekdays } else { &self.weekdays };
+        Cow::Borrowed(names.get(long)[days_from_sunday])
+    }
+
+    fn am_pm(&self, pm: bool) -> Cow<'_, str> {
+        Cow::Borrowed(self.am_pm[pm as usize])
+    }
+
+    fn era_name(&self, ad: bool, long: bool) -> Cow<'_, str> {
+        Cow::Borrowed(self.eras.get(long)[ad as usize])
+    }
+}
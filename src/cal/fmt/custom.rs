@@ -1,59 +1,503 @@
-//! Datetime-to-string routines.
+//! Datetime-to-string and string-to-datetime routines.
 
+use std::borrow::Cow;
+use std::error::Error as ErrorTrait;
+use std::fmt;
 use std::fmt::Display;
 use std::io;
-use std::io::Write;
 use std::str::CharIndices;
 
-use cal::{DatePiece, TimePiece};
+use cal::{DatePiece, TimePiece, ZonePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Error as DateTimeError};
+use cal::offset::{Offset, OffsetDateTime};
+use duration::Duration;
 
 use locale;
 use num_traits::PrimInt;
-use pad::{PadStr, Alignment};
+use pad::Alignment;
+use unicode_width::UnicodeWidthStr;
 
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Field<'a> {
     Literal(&'a str),
+    Whitespace(&'a str),
 
     Year(NumArguments),
     YearOfCentury(NumArguments),
+    WeekYear(NumArguments),
+
+    Era(bool, TextArguments),
 
     MonthName(bool, TextArguments),
+    StandaloneMonthName(bool, TextArguments),
+    MonthNumber(NumArguments),
+    Quarter(NumArguments),
 
     Day(NumArguments),
+    YearDay(NumArguments),
+    Week(NumArguments),
+    WeekOfYear(Weekday, NumArguments),
     WeekdayName(bool, TextArguments),
+    StandaloneWeekdayName(bool, TextArguments),
+    WeekdayNumber(bool, NumArguments),
 
     Hour(NumArguments),
+    Hour12(NumArguments),
+    AmPm(TextArguments),
     Minute(NumArguments),
     Second(NumArguments),
+    Millisecond(NumArguments),
+    Fraction(NumArguments),
+
+    UtcOffset(bool, TextArguments),
+    ZoneAbbreviation(TextArguments),
+    Timestamp(NumArguments),
 }
 
 impl<'a> Field<'a> {
-    fn format<T>(&self, when: &T, w: &mut Vec<u8>, locale: &locale::Time) -> io::Result<()> where T: DatePiece+TimePiece {
+    fn format<W, T, L>(&self, when: &T, w: &mut W, locale: &L) -> fmt::Result where W: fmt::Write, T: DatePiece+TimePiece+ZonePiece, L: LocaleNames+?Sized {
+        let mut buf = Buffer::new();
+
         match *self {
-            Field::Literal(s)             => w.write_all(s.as_bytes()),
+            Field::Literal(s)             => w.write_str(s),
+            Field::Whitespace(s)          => w.write_str(s),
             Field::Year(a)                => a.format(w, when.year()),
             Field::YearOfCentury(a)       => a.format(w, when.year_of_century()),
-            Field::MonthName(true, a)     => a.format(w, &locale.long_month_name(when.month() as usize - 1)[..]),
-            Field::MonthName(false, a)    => a.format(w, &locale.short_month_name(when.month() as usize - 1)[..]),
+            Field::WeekYear(a)            => a.format(w, iso_week(when).0),
+            Field::Era(long, a)           => a.format(w, &locale.era_name(when.year() > 0, long)),
+            Field::MonthName(long, a)     => a.format(w, &locale.month_name(when.month().months_from_january(), long, false)),
+            Field::StandaloneMonthName(long, a) => a.format(w, &locale.month_name(when.month().months_from_january(), long, true)),
+            Field::MonthNumber(a)         => a.format(w, when.month() as usize),
+            Field::Quarter(a)             => a.format(w, when.month().months_from_january() / 3 + 1),
             Field::Day(a)                 => a.format(w, when.day()),
-            Field::WeekdayName(true, a)   => a.format(w, &locale.long_day_name(when.weekday() as usize)[..]),
-            Field::WeekdayName(false, a)  => a.format(w, &locale.short_day_name(when.weekday() as usize)[..]),
+            Field::YearDay(a)             => a.format(w, when.yearday()),
+            Field::Week(a)                => a.format(w, iso_week(when).1),
+            Field::WeekOfYear(first, a)   => a.format(w, week_of_year(when, first)),
+            Field::WeekdayName(long, a)   => a.format(w, &locale.weekday_name(when.weekday() as usize, long, false)),
+            Field::StandaloneWeekdayName(long, a) => a.format(w, &locale.weekday_name(when.weekday() as usize, long, true)),
+            Field::WeekdayNumber(true, a) => a.format(w, match when.weekday() as usize { 0 => 7, n => n }),
+            Field::WeekdayNumber(false, a) => a.format(w, when.weekday() as usize),
             Field::Hour(a)                => a.format(w, when.hour()),
+            Field::Hour12(a)              => a.format(w, match when.hour() % 12 { 0 => 12, hour => hour }),
+            Field::AmPm(a)                => a.format(w, &locale.am_pm(when.hour() >= 12)),
             Field::Minute(a)              => a.format(w, when.minute()),
             Field::Second(a)              => a.format(w, when.second()),
+            Field::Millisecond(a)         => a.format(w, when.millisecond()),
+            Field::Fraction(a)            => {
+                try!(write_fraction(&mut buf, when.millisecond()));
+                a.0.format(w, buf.as_str())
+            },
+            Field::UtcOffset(long, a)     => {
+                if let Some(offset) = when.utc_offset() {
+                    try!(write_offset(&mut buf, offset, long));
+                }
+                a.format(w, buf.as_str())
+            },
+            Field::ZoneAbbreviation(a)    => a.format(w, &when.zone_abbreviation().unwrap_or_default()),
+            Field::Timestamp(a)           => a.format(w, timestamp(when)),
+        }
+    }
+
+    /// Reads this field from the input at the given position, storing its
+    /// value in the builder, and returns the position after it.
+    fn parse<L: LocaleNames+?Sized>(&self, index: usize, input: &str, pos: Pos, locale: &L, parsed: &mut Parsed) -> Result<Pos, ParseError> {
+        let invalid = ParseError::InvalidField { field: index, pos: pos };
+        let conflict = ParseError::ConflictingField { field: index, pos: pos };
+
+        let (stored, end) = match *self {
+            Field::Literal(s) => {
+                if input[pos..].starts_with(s) { return Ok(pos + s.len()) }
+                else { return Err(invalid) }
+            },
+
+            Field::Whitespace(_) => {
+                let rest = &input[pos..];
+                return Ok(pos + rest.len() - rest.trim_left().len());
+            },
+
+            Field::Year(a) => {
+                let (year, end) = try!(a.parse(input, pos, true).ok_or(invalid));
+                (set(&mut parsed.year, year), end)
+            },
+
+            Field::YearOfCentury(a) => {
+                let (year, end) = try!(a.parse(input, pos, false).ok_or(invalid));
+                (set(&mut parsed.year_of_century, year), end)
+            },
+
+            Field::WeekYear(a) => {
+                let (year, end) = try!(a.parse(input, pos, true).ok_or(invalid));
+                (set(&mut parsed.week_year, year), end)
+            },
+
+            Field::Era(long, a) => {
+                let names: Vec<String> = [false, true].iter().map(|&ad| locale.era_name(ad, long).into_owned()).collect();
+                let (index, end) = try!(a.parse(input, pos, &names).ok_or(invalid));
+                (set(&mut parsed.era, if index == 0 { Era::BC } else { Era::AD }), end)
+            },
+
+            Field::MonthName(long, a) | Field::StandaloneMonthName(long, a) => {
+                let standalone = if let Field::StandaloneMonthName(..) = *self { true } else { false };
+                let names: Vec<String> = (0 .. 12).map(|i| locale.month_name(i, long, standalone).into_owned()).collect();
+                let (index, end) = try!(a.parse(input, pos, &names).ok_or(invalid));
+                (set(&mut parsed.month, Month::from_zero(index as i8).unwrap()), end)
+            },
+
+            Field::MonthNumber(a) => {
+                let (month, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                let month = try!(Month::from_one(month).map_err(|_| invalid));
+                (set(&mut parsed.month, month), end)
+            },
+
+            Field::Quarter(a) => {
+                let (quarter, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                (set(&mut parsed.quarter, quarter), end)
+            },
+
+            Field::Day(a) => {
+                let (day, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                (set(&mut parsed.day, day), end)
+            },
+
+            Field::YearDay(a) => {
+                let (yearday, end) = try!(a.parse(input, pos, false).ok_or(invalid));
+                if yearday > 366 { return Err(invalid) }
+                (set(&mut parsed.yearday, yearday as i16), end)
+            },
+
+            Field::Week(a) => {
+                let (week, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                (set(&mut parsed.week, week), end)
+            },
+
+            Field::WeekOfYear(first, a) => {
+                let (week, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                if first == Weekday::Sunday { (set(&mut parsed.sunday_week, week), end) }
+                                       else { (set(&mut parsed.monday_week, week), end) }
+            },
+
+            Field::WeekdayNumber(iso, a) => {
+                let (number, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                let weekday = if iso { Weekday::from_one(number) } else { Weekday::from_zero(number) };
+                (set(&mut parsed.weekday, try!(weekday.map_err(|_| invalid))), end)
+            },
+
+            Field::WeekdayName(long, a) | Field::StandaloneWeekdayName(long, a) => {
+                let standalone = if let Field::StandaloneWeekdayName(..) = *self { true } else { false };
+                let names: Vec<String> = (0 .. 7).map(|i| locale.weekday_name(i, long, standalone).into_owned()).collect();
+                let (index, end) = try!(a.parse(input, pos, &names).ok_or(invalid));
+                (set(&mut parsed.weekday, Weekday::from_zero(index as i8).unwrap()), end)
+            },
+
+            Field::Hour(a) => {
+                let (hour, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                (set(&mut parsed.hour, hour), end)
+            },
+
+            Field::Hour12(a) => {
+                let (hour, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                (set(&mut parsed.hour_12, hour), end)
+            },
+
+            Field::AmPm(a) => {
+                let names: Vec<String> = [false, true].iter().map(|&pm| locale.am_pm(pm).into_owned()).collect();
+                let (index, end) = try!(a.parse(input, pos, &names).ok_or(invalid));
+                (set(&mut parsed.is_pm, index == 1), end)
+            },
+
+            Field::Minute(a) => {
+                let (minute, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                (set(&mut parsed.minute, minute), end)
+            },
+
+            Field::Second(a) => {
+                let (second, end) = try!(a.parse(input, pos, false).and_then(narrow).ok_or(invalid));
+                (set(&mut parsed.second, second), end)
+            },
+
+            Field::Millisecond(a) => {
+                let (millisecond, end) = try!(a.parse(input, pos, false).ok_or(invalid));
+                if millisecond > 999 { return Err(invalid) }
+                (set(&mut parsed.millisecond, millisecond as i16), end)
+            },
+
+            Field::Fraction(a) => {
+                let (digits, end) = try!(a.parse_digits(input, pos, false).ok_or(invalid));
+                let millisecond = digits.bytes().chain(b"00".iter().cloned()).take(3).fold(0, |n, b| n * 10 + (b - b'0') as i16);
+                (set(&mut parsed.millisecond, millisecond), end)
+            },
+
+            Field::UtcOffset(_, a) => {
+                let (offset, end) = try!(a.parse_offset(input, pos).ok_or(invalid));
+                (set(&mut parsed.offset, offset), end)
+            },
+
+            Field::ZoneAbbreviation(a) => {
+                let (name, end) = try!(a.parse_zone_abbreviation(input, pos).ok_or(invalid));
+                match name {
+                    "UTC" | "GMT" | "Z"  => (set(&mut parsed.offset, Offset::utc()), end),
+                    _                    => (true, end),
+                }
+            },
+
+            Field::Timestamp(a) => {
+                let (timestamp, end) = try!(a.parse(input, pos, true).ok_or(invalid));
+                (set(&mut parsed.timestamp, timestamp), end)
+            },
+        };
+
+        if stored { Ok(end) } else { Err(conflict) }
+    }
+}
+
+/// The short and long names of the eras, before and after the year 1.
+static ERA_NAMES: [[&'static str; 2]; 2] = [
+    [ "BC", "AD" ],
+    [ "Before Christ", "Anno Domini" ],
+];
+
+static AM_PM_NAMES: [&'static str; 2] = [ "AM", "PM" ];
+
+/// The short and long English names of the weekdays, from Sunday.
+static WEEKDAY_NAMES: [[&'static str; 7]; 2] = [
+    [ "Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat" ],
+    [ "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday" ],
+];
+
+
+/// The names that a locale gives to months, weekdays, and other parts of
+/// a date, which are used to write and read the fields that have names.
+///
+/// This is implemented by the locale data in the `cldr` module, and by
+/// `locale::Time`, which only knows the names of months and weekdays, and
+/// uses the same names for both forms of them.
+pub trait LocaleNames {
+
+    /// Returns the name of the month with the given number, from January
+    /// as 0, in its short or long form, and either on its own or as part
+    /// of a date.
+    fn month_name(&self, months_from_january: usize, long: bool, standalone: bool) -> Cow<'_, str>;
+
+    /// Returns the name of the weekday with the given number, from Sunday
+    /// as 0, in its short or long form, and either on its own or as part
+    /// of a date.
+    fn weekday_name(&self, days_from_sunday: usize, long: bool, standalone: bool) -> Cow<'_, str>;
+
+    /// Returns the name for times before noon, or after it. This is `AM`
+    /// or `PM` unless the locale says otherwise.
+    fn am_pm(&self, pm: bool) -> Cow<'_, str> {
+        Cow::Borrowed(AM_PM_NAMES[pm as usize])
+    }
+
+    /// Returns the name of the era before the year 1, or from it onwards,
+    /// in its short or long form. This is `BC` or `AD` unless the locale
+    /// says otherwise.
+    fn era_name(&self, ad: bool, long: bool) -> Cow<'_, str> {
+        Cow::Borrowed(ERA_NAMES[long as usize][ad as usize])
+    }
+}
+
+impl LocaleNames for locale::Time {
+    fn month_name(&self, months_from_january: usize, long: bool, _standalone: bool) -> Cow<'_, str> {
+        if long { Cow::Owned(self.long_month_name(months_from_january)) }
+           else { Cow::Owned(self.short_month_name(months_from_january)) }
+    }
+
+    fn weekday_name(&self, days_from_sunday: usize, long: bool, _standalone: bool) -> Cow<'_, str> {
+        let short = self.short_day_name(days_from_sunday);
+        if !long {
+            return Cow::Owned(short);
+        }
+
+        // `long_day_name` gives back the short name in locale 0.2, so the
+        // long English names are looked up from the short ones instead.
+        // Other languages get whatever the locale has.
+        let long = self.long_day_name(days_from_sunday);
+        if long == short && short == WEEKDAY_NAMES[0][days_from_sunday] {
+            Cow::Borrowed(WEEKDAY_NAMES[1][days_from_sunday])
+        }
+        else {
+            Cow::Owned(long)
+        }
+    }
+}
+
+/// Returns the ISO week-numbering year and week number of a date.
+fn iso_week<T: DatePiece>(when: &T) -> (i64, i8) {
+    LocalDate::ymd(when.year(), when.month(), when.day()).unwrap().iso_week()
+}
+
+/// Returns the week of the year that a date is in, with weeks starting on
+/// the given weekday, which is either Sunday or Monday. Days before the
+/// first of those in the year are in week 0.
+fn week_of_year<T: DatePiece>(when: &T, first: Weekday) -> i64 {
+    let days_into_week = days_into_week(when.weekday(), first);
+    (when.yearday() as i64 - 1 + 7 - days_into_week) / 7
+}
+
+/// Returns how many days after the start of the week a weekday is, with
+/// weeks starting on the given weekday, which is either Sunday or Monday.
+fn days_into_week(weekday: Weekday, first: Weekday) -> i64 {
+    if first == Weekday::Sunday { weekday as i64 } else { (weekday as i64 + 6) % 7 }
+}
+
+/// Returns the number of seconds since the Unix epoch of a value. Values
+/// that don’t know their offset are taken to be in UTC.
+fn timestamp<T: DatePiece+TimePiece+ZonePiece>(when: &T) -> i64 {
+    let date = LocalDate::ymd(when.year(), when.month(), when.day()).unwrap();
+    let local = LocalDateTime::new(date, LocalTime::hms(when.hour(), when.minute(), when.second()).unwrap());
+    local.to_instant().seconds() - when.utc_offset().map_or(0, offset_seconds)
+}
+
+/// Returns the total number of seconds in an offset.
+fn offset_seconds(offset: Offset) -> i64 {
+    offset.hours() as i64 * 3600 + offset.minutes() as i64 * 60 + offset.seconds() as i64
+}
+
+/// Writes the digits of a millisecond as a decimal fraction of a second,
+/// without any trailing zeros, such as `25` for 250.
+fn write_fraction<W: fmt::Write>(w: &mut W, millisecond: i16) -> fmt::Result {
+    match millisecond {
+        0                     => w.write_str("0"),
+        ms if ms % 100 == 0   => write!(w, "{}", ms / 100),
+        ms if ms % 10 == 0    => write!(w, "{:02}", ms / 10),
+        ms                    => write!(w, "{:03}", ms),
+    }
+}
+
+/// Writes an offset, such as `+0100`, or `+01:00` with colons. Seconds
+/// are only written if there are any.
+fn write_offset<W: fmt::Write>(w: &mut W, offset: Offset, colons: bool) -> fmt::Result {
+    let separator = if colons { ":" } else { "" };
+
+    if offset.is_utc() {
+        write!(w, "+00{}00", separator)
+    }
+    else if offset.is_unknown() {
+        write!(w, "-00{}00", separator)
+    }
+    else {
+        let sign = if offset.is_negative() { '-' } else { '+' };
+        try!(write!(w, "{}{:02}{}{:02}", sign, offset.hours().abs(), separator, offset.minutes().abs()));
+        if offset.seconds() != 0 {
+            try!(write!(w, "{}{:02}", separator, offset.seconds().abs()));
+        }
+        Ok(())
+    }
+}
+
+/// A fixed-size buffer on the stack that numbers and offsets are written
+/// into before they’re padded, so formatting a field doesn’t need to
+/// allocate. It’s big enough for any `i64` or offset.
+struct Buffer {
+    bytes: [u8; 24],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Buffer {
+        Buffer { bytes: [0; 24], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only whole strings are ever written in, so this is always UTF-8.
+        ::std::str::from_utf8(&self.bytes[.. self.len]).unwrap()
+    }
+}
+
+impl fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
         }
+
+        self.bytes[self.len .. end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Stores a value in a builder slot, returning whether it was stored:
+/// a value that's already been read can only be read again if it’s the
+/// same.
+fn set<T: PartialEq>(slot: &mut Option<T>, value: T) -> bool {
+    match *slot {
+        Some(ref existing) if *existing != value => return false,
+        _ => {},
     }
+
+    *slot = Some(value);
+    true
 }
 
+/// Narrows a number that was read to fit in an `i8`, failing if it can’t.
+fn narrow((number, end): (i64, Pos)) -> Option<(i8, Pos)> {
+    if number >= 0 && number <= i8::max_value() as i64 { Some((number as i8, end)) }
+    else { None }
+}
+
+/// Returns whether the input starts with the given prefix, ignoring case.
+fn starts_with_ignoring_case(input: &str, prefix: &str) -> bool {
+    input.len() >= prefix.len()
+        && input.is_char_boundary(prefix.len())
+        && input[.. prefix.len()].to_lowercase() == prefix.to_lowercase()
+}
 
+
+/// A format for writing and reading dates and times, made of literal text
+/// and fields such as `{:Y}`.
+///
+/// Each field is a letter after a colon:
+///
+/// - `Y`: the year, `y`: the year of the century, and `g`: the ISO
+///   week-numbering year
+/// - `G`: the era, `BC` or `AD`
+/// - `M`: the month’s name, `N`: its number, and `q`: its quarter
+/// - `D`: the day of the month, and `j`: the day of the year
+/// - `W`: the ISO week number, and `U` or `K`: the week of the year, with
+///   weeks starting on Sunday or Monday, and days before the first one in
+///   week 0
+/// - `E`: the weekday’s name, and `u` or `w`: its number, from Monday as 1
+///   or from Sunday as 0
+/// - `L` and `c`: the month’s and weekday’s names on their own, rather
+///   than as part of a date, for languages that use different forms
+/// - `h`: the hour, `l`: the hour on a 12-hour clock, and `p`: `AM` or `PM`
+/// - `m`: the minute, and `s`: the second
+/// - `f`: the millisecond, and `F`: the decimal fraction of the second,
+///   without trailing zeros
+/// - `z`: the offset from UTC, such as `+0100`
+/// - `Z`: the time zone abbreviation, such as `BST`
+/// - `T`: the number of seconds since the Unix epoch
+///
+/// Before the colon, a `_` asks for the long form of a name, or for an
+/// offset with colons, such as `+01:00`; `<`, `^`, or `>` aligns the field;
+/// a number gives its width; and a leading `0` pads it with zeros. Values
+/// that don’t know their offset or time zone leave those fields empty.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct DateFormat<'a> {
     pub fields: Vec<Field<'a>>,
 }
 
 
+/// A value along with a format to display it in, returned from
+/// `DateFormat::display`.
+#[derive(Debug)]
+pub struct DateFormatString<'f, 'a: 'f, T: 'f, L: 'f+?Sized> {
+    format: &'f DateFormat<'a>,
+    when: &'f T,
+    locale: &'f L,
+}
+
+impl<'f, 'a, T, L> Display for DateFormatString<'f, 'a, T, L>
+where T: DatePiece+TimePiece+ZonePiece, L: LocaleNames+?Sized {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format.write_to(f, self.when, self.locale)
+    }
+}
+
+
 #[derive(PartialEq, Eq, Clone, Debug, Copy)]
 pub enum FormatError {
     InvalidChar { c: char, colon: bool, pos: Pos },
@@ -62,6 +506,10 @@ pub enum FormatError {
     MissingField { open_pos: Pos, close_pos: Pos },
     DoubleAlignment { open_pos: Pos, current_alignment: Alignment },
     DoubleWidth { open_pos: Pos, current_width: Width },
+    UnsupportedDirective { c: char, pos: Pos },
+    UnfinishedDirective { pos: Pos },
+    UnsupportedLength { c: char, length: usize, pos: Pos },
+    UnclosedQuote { open_pos: Pos },
 }
 
 pub type Width = usize;
@@ -107,23 +555,68 @@ impl Arguments {
         }
     }
 
-    fn format(self, w: &mut Vec<u8>, string: &str) -> io::Result<()> {
+    /// Writes a string padded to this width, with the padding written
+    /// straight into the output. The width is measured in columns, so wide
+    /// characters such as those in Japanese count twice.
+    fn format<W: fmt::Write>(self, w: &mut W, string: &str) -> fmt::Result {
         let width     = self.width.unwrap_or(0);
         let pad_char  = self.pad_char.unwrap_or(' ');
         let alignment = self.alignment.unwrap_or(Alignment::Left);
-        let s         = string.pad(width, pad_char, alignment, false);
+        let diff      = width.saturating_sub(UnicodeWidthStr::width(string));
+
+        let (left_pad, right_pad) = match alignment {
+            Alignment::Left         => (0, diff),
+            Alignment::Right        => (diff, 0),
+            Alignment::Middle       => (diff / 2, diff - diff / 2),
+            Alignment::MiddleRight  => (diff - diff / 2, diff / 2),
+        };
+
+        for _ in 0 .. left_pad {
+            try!(w.write_char(pad_char));
+        }
+
+        try!(w.write_str(string));
 
-        w.write_all(s.as_bytes())
+        for _ in 0 .. right_pad {
+            try!(w.write_char(pad_char));
+        }
+
+        Ok(())
     }
 
     pub fn is_empty(&self) -> bool {
         self.alignment.is_none() && self.width.is_none() && self.pad_char.is_none()
     }
+
+    /// Returns the part of the input that a field with a width takes up,
+    /// with its padding removed, and the position after it. Fields without
+    /// a width don’t take up a fixed part of the input.
+    fn padded_span(self, input: &str, pos: Pos) -> Option<(&str, Pos)> {
+        let width = match self.width {
+            Some(width)  => width,
+            None         => return None,
+        };
+
+        let rest = &input[pos..];
+        let length = rest.char_indices().nth(width).map(|(i, _)| i).unwrap_or(rest.len());
+        let span = &rest[.. length];
+
+        let pad_char = self.pad_char.unwrap_or(' ');
+        let trimmed = match self.alignment.unwrap_or(Alignment::Left) {
+            _ if pad_char.is_digit(10)  => span,
+            Alignment::Left             => span.trim_right_matches(pad_char),
+            Alignment::Right            => span.trim_left_matches(pad_char),
+            Alignment::Middle |
+            Alignment::MiddleRight      => span.trim_matches(pad_char),
+        };
+
+        Some((trimmed, pos + length))
+    }
 }
 
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct TextArguments(Arguments);
+pub struct TextArguments(pub Arguments);
 
 impl TextArguments {
     #[cfg(test)]
@@ -131,14 +624,95 @@ impl TextArguments {
         TextArguments(Arguments::empty())
     }
 
-    fn format(self, w: &mut Vec<u8>, string: &str) -> io::Result<()> {
+    fn format<W: fmt::Write>(self, w: &mut W, string: &str) -> fmt::Result {
         self.0.format(w, string)
     }
+
+    /// Reads one of the given names from the input, ignoring case, and
+    /// returns its index and the position after it. When more than one
+    /// name matches, the longest one wins.
+    fn parse(self, input: &str, pos: Pos, names: &[String]) -> Option<(usize, Pos)> {
+        if let Some((span, end)) = self.0.padded_span(input, pos) {
+            if let Some(index) = names.iter().position(|name| span.to_lowercase() == name.to_lowercase()) {
+                return Some((index, end));
+            }
+        }
+
+        // Names that are longer than the width aren’t cut short when
+        // they’re formatted, so they can still be found without it.
+        names.iter().enumerate()
+             .filter(|&(_, name)| !name.is_empty() && starts_with_ignoring_case(&input[pos..], name))
+             .max_by_key(|&(_, name)| name.len())
+             .map(|(index, name)| (index, pos + name.len()))
+    }
+
+    /// Reads an offset, such as `+0100`, `+01:00`, or `Z`, and returns it
+    /// and the position after it. `-0000` is read as an unknown offset.
+    fn parse_offset(self, input: &str, pos: Pos) -> Option<(Offset, Pos)> {
+        let (span, end) = match self.0.padded_span(input, pos) {
+            Some(span_and_end)  => span_and_end,
+            None => {
+                let rest = &input[pos..];
+                let length = rest.find(|c: char| !(c.is_ascii_digit() || c == '+' || c == '-' || c == ':' || c == 'Z')).unwrap_or(rest.len());
+                (&rest[.. length], pos + length)
+            },
+        };
+
+        if span == "Z" {
+            return Some((Offset::utc(), end));
+        }
+
+        let sign = match span.chars().next() {
+            Some('+')  => 1,
+            Some('-')  => -1,
+            _          => return None,
+        };
+
+        let digits: String = span[1..].chars().filter(|&c| c != ':').collect();
+        if !(digits.len() == 4 || digits.len() == 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let fields: Vec<i32> = digits.as_bytes().chunks(2)
+                                     .map(|pair| (pair[0] - b'0') as i32 * 10 + (pair[1] - b'0') as i32)
+                                     .collect();
+
+        // The minutes and seconds don’t carry over into the next unit up,
+        // so `+0199` isn’t an offset at all.
+        if fields[1..].iter().any(|&field| field >= 60) {
+            return None;
+        }
+
+        let seconds = fields.iter().zip([3600, 60, 1].iter())
+                            .fold(0, |total, (field, unit)| total + field * unit);
+
+        match (sign, seconds) {
+            (-1, 0)  => Some((Offset::unknown(), end)),
+            (_, 0)   => Some((Offset::utc(), end)),
+            _        => Offset::of_seconds(sign * seconds).ok().map(|offset| (offset, end)),
+        }
+    }
+
+    /// Reads a time zone abbreviation, made of letters, and returns it and
+    /// the position after it.
+    fn parse_zone_abbreviation<'i>(self, input: &'i str, pos: Pos) -> Option<(&'i str, Pos)> {
+        let (span, end) = match self.0.padded_span(input, pos) {
+            Some(span_and_end)  => span_and_end,
+            None => {
+                let rest = &input[pos..];
+                let length = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+                (&rest[.. length], pos + length)
+            },
+        };
+
+        if span.is_empty() || !span.chars().all(char::is_alphabetic) { None }
+        else { Some((span, end)) }
+    }
 }
 
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct NumArguments(Arguments);
+pub struct NumArguments(pub Arguments);
 
 impl NumArguments {
     #[cfg(test)]
@@ -146,22 +720,152 @@ impl NumArguments {
         NumArguments(Arguments::empty())
     }
 
-    fn format<N: PrimInt + Display>(self, w: &mut Vec<u8>, number: N) -> io::Result<()> {
-        self.0.format(w, &number.to_string())
+    fn format<W: fmt::Write, N: PrimInt + Display>(self, w: &mut W, number: N) -> fmt::Result {
+        use std::fmt::Write;
+
+        let mut buf = Buffer::new();
+        try!(write!(buf, "{}", number));
+        let string = buf.as_str();
+
+        // Zeros go after the sign of a negative number, not before it.
+        match (self.0.pad_char, self.0.alignment, self.0.width) {
+            (Some('0'), Some(Alignment::Right), Some(width)) if string.starts_with('-') && width > 1 => {
+                try!(w.write_char('-'));
+                Arguments { width: Some(width - 1), .. self.0 }.format(w, &string[1..])
+            },
+            _ => self.0.format(w, string),
+        }
+    }
+
+    /// Reads a number from the input, which can be negative if it’s
+    /// signed, and returns it and the position after it.
+    fn parse(self, input: &str, pos: Pos, signed: bool) -> Option<(i64, Pos)> {
+        self.parse_digits(input, pos, signed)
+            .and_then(|(digits, end)| digits.parse().ok().map(|number| (number, end)))
+    }
+
+    /// Reads the digits of a number from the input, keeping any leading
+    /// zeros, and returns them and the position after them, including any
+    /// padding.
+    ///
+    /// With a width, the number and its padding take up at most that many
+    /// characters, so `{>02:D}` reads both `07` and `7`, and `{>2:D}` reads
+    /// both ` 7` and `7`. Without one, it takes up as many digits as there
+    /// are.
+    fn parse_digits(self, input: &str, pos: Pos, signed: bool) -> Option<(&str, Pos)> {
+        let rest = &input[pos..];
+        let limit = self.0.width.unwrap_or(usize::max_value());
+        let pad_char = self.0.pad_char.unwrap_or(' ');
+        let alignment = self.0.alignment.unwrap_or(Alignment::Left);
+        let padded = self.0.width.is_some() && !pad_char.is_digit(10);
+
+        let mut count = 0;
+        let mut length = 0;
+
+        if padded && alignment != Alignment::Left {
+            for c in rest.chars() {
+                if c != pad_char || count + 1 >= limit { break }
+                count += 1;
+                length += c.len_utf8();
+            }
+        }
+
+        let start = length;
+        if signed && rest[length..].starts_with('-') {
+            count += 1;
+            length += 1;
+        }
+
+        for b in rest[length..].bytes() {
+            if !b.is_ascii_digit() || count >= limit { break }
+            count += 1;
+            length += 1;
+        }
+
+        let digits = &rest[start .. length];
+        if digits.is_empty() || digits == "-" {
+            return None;
+        }
+
+        if padded && alignment != Alignment::Right {
+            for c in rest[length..].chars() {
+                if c != pad_char || count >= limit { break }
+                count += 1;
+                length += c.len_utf8();
+            }
+        }
+
+        Some((digits, pos + length))
     }
 }
 
 impl<'a> DateFormat<'a> {
-    pub fn format<T>(&self, when: &T, locale: &locale::Time) -> String where T: DatePiece+TimePiece{
-        let mut buf = Vec::<u8>::new();
+    pub fn format<T, L>(&self, when: &T, locale: &L) -> String where T: DatePiece+TimePiece+ZonePiece, L: LocaleNames+?Sized {
+        let mut buf = String::new();
+
+        // It's safe to just ignore the error when writing to an in-memory
+        // String buffer. If it fails then you have bigger problems
+        match self.write_to(&mut buf, when, locale) { _ => {} }
 
+        buf
+    }
+
+    /// Writes a value in this format to anything that accepts text, such
+    /// as a `String` or a `fmt::Formatter`, without allocating anything
+    /// along the way.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// extern crate datetime;
+    /// extern crate locale;
+    /// # fn main() {
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+    /// use datetime::fmt::DateFormat;
+    ///
+    /// let date = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 5).unwrap(), LocalTime::midnight());
+    /// let format = DateFormat::parse("{:Y}-{>02:N}-{>02:D}").unwrap();
+    ///
+    /// let mut line = String::from("date=");
+    /// format.write_to(&mut line, &date, &locale::Time::english()).unwrap();
+    /// assert_eq!(line, "date=2024-01-05");
+    /// # }
+    /// ```
+    pub fn write_to<W, T, L>(&self, w: &mut W, when: &T, locale: &L) -> fmt::Result where W: fmt::Write, T: DatePiece+TimePiece+ZonePiece, L: LocaleNames+?Sized {
         for field in &self.fields {
-            // It's safe to just ignore the error when writing to an in-memory
-            // Vec<u8> buffer. If it fails then you have bigger problems
-            match field.format(when, &mut buf, locale) { _ => {} }
+            try!(field.format(when, w, locale));
         }
 
-        String::from_utf8(buf).unwrap()  // Assume UTF-8
+        Ok(())
+    }
+
+    /// Writes a value in this format to a byte stream, such as a file or a
+    /// locked `stdout`, without allocating anything along the way.
+    pub fn write_io<W, T, L>(&self, w: &mut W, when: &T, locale: &L) -> io::Result<()> where W: io::Write, T: DatePiece+TimePiece+ZonePiece, L: LocaleNames+?Sized {
+        write!(w, "{}", self.display(when, locale))
+    }
+
+    /// Returns a value that displays the given one in this format, for
+    /// use with `write!` or `format!`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// extern crate datetime;
+    /// extern crate locale;
+    /// # fn main() {
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+    /// use datetime::fmt::DateFormat;
+    ///
+    /// let date = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 5).unwrap(), LocalTime::midnight());
+    /// let format = DateFormat::parse("{:D} {_:M}").unwrap();
+    /// let english = locale::Time::english();
+    ///
+    /// assert_eq!(format!("[{}]", format.display(&date, &english)), "[5 January]");
+    /// # }
+    /// ```
+    pub fn display<'f, T, L>(&'f self, when: &'f T, locale: &'f L) -> DateFormatString<'f, 'a, T, L> where T: DatePiece+TimePiece+ZonePiece, L: LocaleNames+?Sized {
+        DateFormatString { format: self, when: when, locale: locale }
     }
 
     pub fn parse(input: &'a str) -> Result<DateFormat<'a>, FormatError> {
@@ -170,6 +874,335 @@ impl<'a> DateFormat<'a> {
 
         Ok(DateFormat { fields: parser.fields })
     }
+
+    /// Reads a value written in this format, returning the fields that
+    /// were read in a builder that can be turned into a date, a time, or
+    /// both. All of the input has to be read.
+    ///
+    /// Numbers read with a width take up exactly that many characters,
+    /// with their padding removed; without a width, they take up as many
+    /// digits as there are. Month and weekday names are read using the
+    /// locale, ignoring case.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// extern crate datetime;
+    /// extern crate locale;
+    /// use datetime::fmt::DateFormat;
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+    ///
+    /// # fn main() {
+    /// let format = DateFormat::parse("{:D}/{:M}/{:Y} {:h}:{:m}:{:s}").unwrap();
+    /// let parsed = format.parse_value("31/Jan/2024 10:30:15", &locale::Time::english()).unwrap();
+    ///
+    /// let expected = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 31).unwrap(), LocalTime::hms(10, 30, 15).unwrap());
+    /// assert_eq!(parsed.to_local_date_time(), Ok(expected));
+    /// # }
+    /// ```
+    pub fn parse_value<L: LocaleNames+?Sized>(&self, input: &str, locale: &L) -> Result<Parsed, ParseError> {
+        let mut parsed = Parsed::new();
+        let end = try!(self.parse_into(input, locale, &mut parsed));
+
+        if end < input.len() {
+            return Err(ParseError::TrailingInput { pos: end });
+        }
+
+        Ok(parsed)
+    }
+
+    /// Reads a value written in this format from the start of the input,
+    /// adding the fields that were read to the given builder, and returns
+    /// the position after the last field. Any input after that is left
+    /// alone, so a value can be read in parts, or from the start of a
+    /// longer string.
+    pub fn parse_into<L: LocaleNames+?Sized>(&self, input: &str, locale: &L, parsed: &mut Parsed) -> Result<Pos, ParseError> {
+        let mut pos = 0;
+
+        for (index, field) in self.fields.iter().enumerate() {
+            pos = try!(field.parse(index, input, pos, locale, parsed));
+        }
+
+        Ok(pos)
+    }
+}
+
+
+/// The fields of a date and time that have been read by
+/// `DateFormat::parse_value`, any of which can be missing.
+///
+/// Fields can also be filled in by hand, before turning the builder into
+/// a date, a time, or both.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Parsed {
+    pub year: Option<i64>,
+    pub year_of_century: Option<i64>,
+    pub week_year: Option<i64>,
+    pub era: Option<Era>,
+    pub month: Option<Month>,
+    pub quarter: Option<i8>,
+    pub day: Option<i8>,
+    pub yearday: Option<i16>,
+    pub week: Option<i8>,
+    pub sunday_week: Option<i8>,
+    pub monday_week: Option<i8>,
+    pub weekday: Option<Weekday>,
+    pub hour: Option<i8>,
+    pub hour_12: Option<i8>,
+    pub is_pm: Option<bool>,
+    pub minute: Option<i8>,
+    pub second: Option<i8>,
+    pub millisecond: Option<i16>,
+    pub offset: Option<Offset>,
+    pub timestamp: Option<i64>,
+}
+
+/// Which side of the year 1 a year is on.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Era {
+
+    /// Years up to and including the year 0, which is 1 BC.
+    BC,
+
+    /// Years from the year 1 onwards.
+    AD,
+}
+
+impl Parsed {
+
+    /// Returns a builder with no fields filled in.
+    pub fn new() -> Parsed {
+        Parsed::default()
+    }
+
+    /// Returns the date that the fields refer to, from its month and day,
+    /// its day of the year, its ISO week, or its week of the year starting
+    /// on Sunday or Monday, in that order. Any other fields that were
+    /// read, such as the weekday or quarter, have to match it. A timestamp
+    /// takes the place of all of these.
+    ///
+    /// A year of the century on its own is taken to be in 1969 to 2068, as
+    /// POSIX `strptime` does. An ISO week is taken to be in the
+    /// week-numbering year if one was read, or the year if not. Weeks
+    /// start on their first day if there’s no weekday.
+    pub fn to_local_date(&self) -> Result<LocalDate, ParseError> {
+        if let Some(datetime) = self.timestamp_date_time() {
+            return Ok(datetime.date());
+        }
+
+        let date = match (self.month, self.day, self.yearday, self.week, self.sunday_week, self.monday_week) {
+            (Some(month), Some(day), _, _, _, _)  => LocalDate::ymd(try!(self.full_year()), month, day),
+            (Some(_), None, _, _, _, _)           => return Err(ParseError::MissingField("day")),
+            (None, _, Some(yearday), _, _, _)     => yearday_to_date(try!(self.full_year()), yearday as i64),
+            (None, _, None, Some(week), _, _)     => {
+                let year = match self.week_year { Some(year) => year, None => try!(self.full_year()) };
+                week_to_date(year, week as i64, self.weekday.unwrap_or(Weekday::Monday))
+            },
+            (None, _, None, None, Some(week), _)  => week_of_year_to_date(try!(self.full_year()), week as i64, Weekday::Sunday, self.weekday.unwrap_or(Weekday::Sunday)),
+            (None, _, None, None, None, Some(week)) => week_of_year_to_date(try!(self.full_year()), week as i64, Weekday::Monday, self.weekday.unwrap_or(Weekday::Monday)),
+            (None, _, None, None, None, None)     => {
+                let _ = try!(self.full_year());
+                return Err(ParseError::MissingField("month"));
+            },
+        };
+
+        let date = try!(date.map_err(ParseError::Date));
+        let era = if date.year() > 0 { Era::AD } else { Era::BC };
+
+        if self.year.map_or(false, |y| y != date.year()) {
+            Err(ParseError::Inconsistent("year"))
+        }
+        else if self.era.map_or(false, |e| e != era) {
+            Err(ParseError::Inconsistent("era"))
+        }
+        else if self.quarter.map_or(false, |q| q as usize != date.month().months_from_january() / 3 + 1) {
+            Err(ParseError::Inconsistent("quarter"))
+        }
+        else if self.yearday.map_or(false, |d| d != date.yearday()) {
+            Err(ParseError::Inconsistent("day of the year"))
+        }
+        else if self.week_year.map_or(false, |y| y != date.iso_week().0) {
+            Err(ParseError::Inconsistent("week-numbering year"))
+        }
+        else if self.week.map_or(false, |w| w != date.iso_week().1) {
+            Err(ParseError::Inconsistent("week"))
+        }
+        else if self.sunday_week.map_or(false, |w| w as i64 != week_of_year(&date, Weekday::Sunday))
+             || self.monday_week.map_or(false, |w| w as i64 != week_of_year(&date, Weekday::Monday)) {
+            Err(ParseError::Inconsistent("week of the year"))
+        }
+        else if self.weekday.map_or(false, |w| w != date.weekday()) {
+            Err(ParseError::Inconsistent("weekday"))
+        }
+        else {
+            Ok(date)
+        }
+    }
+
+    /// Returns the time that the fields refer to. Only the hour is needed,
+    /// either on its own or on a 12-hour clock with AM or PM: the other
+    /// fields are zero if they weren’t read.
+    pub fn to_local_time(&self) -> Result<LocalTime, ParseError> {
+        if let Some(datetime) = self.timestamp_date_time() {
+            return Ok(datetime.time());
+        }
+
+        let hour = match (self.hour, self.hour_12, self.is_pm) {
+            (Some(hour), _, _) => {
+                if self.hour_12.map_or(false, |h| h % 12 != hour % 12) {
+                    return Err(ParseError::Inconsistent("12-hour hour"));
+                }
+                else if self.is_pm.map_or(false, |pm| pm != (hour >= 12)) {
+                    return Err(ParseError::Inconsistent("AM/PM"));
+                }
+
+                hour
+            },
+            (None, Some(hour), Some(pm)) => {
+                if hour < 1 || hour > 12 {
+                    return Err(ParseError::Date(DateTimeError::OutOfRange));
+                }
+
+                hour % 12 + if pm { 12 } else { 0 }
+            },
+            (None, Some(_), None)  => return Err(ParseError::MissingField("AM/PM")),
+            (None, None, _)        => return Err(ParseError::MissingField("hour")),
+        };
+
+        let (minute, second) = (self.minute.unwrap_or(0), self.second.unwrap_or(0));
+        match self.millisecond {
+            Some(millisecond)  => LocalTime::hms_ms(hour, minute, second, millisecond).map_err(ParseError::Date),
+            None               => LocalTime::hms(hour, minute, second).map_err(ParseError::Date),
+        }
+    }
+
+    /// Returns the date and time that the fields refer to.
+    pub fn to_local_date_time(&self) -> Result<LocalDateTime, ParseError> {
+        Ok(LocalDateTime::new(try!(self.to_local_date()), try!(self.to_local_time())))
+    }
+
+    /// Returns the date and time that the fields refer to, at the offset
+    /// that was read, or the given one if no offset was read.
+    pub fn to_offset_date_time(&self, default_offset: Offset) -> Result<OffsetDateTime, ParseError> {
+        let offset = self.offset.unwrap_or(default_offset);
+        Ok(offset.transform_date(try!(self.to_local_date_time())))
+    }
+
+    /// Returns the year, from the year or the year of the century.
+    fn full_year(&self) -> Result<i64, ParseError> {
+        match (self.year, self.year_of_century) {
+            (Some(year), Some(year_of_century)) => {
+                if year % 100 != year_of_century { Err(ParseError::Inconsistent("year of century")) }
+                else { Ok(year) }
+            },
+            (Some(year), None)             => Ok(year),
+            (None, Some(year_of_century))  => Ok(if year_of_century < 69 { 2000 + year_of_century } else { 1900 + year_of_century }),
+            (None, None)                   => Err(ParseError::MissingField("year")),
+        }
+    }
+
+    /// Returns the local date and time of the timestamp, if one was read,
+    /// at the offset that was read, or in UTC.
+    fn timestamp_date_time(&self) -> Option<LocalDateTime> {
+        self.timestamp.map(|timestamp| {
+            LocalDateTime::at(timestamp) + Duration::of(self.offset.map_or(0, offset_seconds))
+        })
+    }
+}
+
+/// Returns the date on the given day of the year, which has to be in it.
+fn yearday_to_date(year: i64, yearday: i64) -> Result<LocalDate, DateTimeError> {
+    let date = try!(LocalDate::yd(year, yearday));
+    if yearday < 1 || date.year() != year { Err(DateTimeError::OutOfRange) }
+    else { Ok(date) }
+}
+
+/// Returns the date in the given week of the year, with weeks starting on
+/// the given weekday, which has to be in the year.
+fn week_of_year_to_date(year: i64, week: i64, first: Weekday, weekday: Weekday) -> Result<LocalDate, DateTimeError> {
+    let january_1st = try!(LocalDate::ymd(year, Month::January, 1));
+    let first_start = (7 - days_into_week(january_1st.weekday(), first)) % 7;
+    let yearday = first_start + (week - 1) * 7 + days_into_week(weekday, first) + 1;
+
+    if week < 0 || week > 53 {
+        return Err(DateTimeError::OutOfRange);
+    }
+
+    yearday_to_date(year, yearday)
+}
+
+/// Returns the date in the given ISO week, which has to be in the year.
+fn week_to_date(year: i64, week: i64, weekday: Weekday) -> Result<LocalDate, DateTimeError> {
+    if week < 1 || week > 53 || (week == 53 && try!(LocalDate::ywd(year, 53, Weekday::Thursday)).year() != year) {
+        return Err(DateTimeError::OutOfRange);
+    }
+
+    LocalDate::ywd(year, week, weekday)
+}
+
+
+/// An error from reading a value with a `DateFormat`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ParseError {
+
+    /// The field at this index in the format didn’t match the input at
+    /// this byte position.
+    InvalidField { field: usize, pos: Pos },
+
+    /// The field at this index in the format was read before, at this byte
+    /// position, with a different value.
+    ConflictingField { field: usize, pos: Pos },
+
+    /// The whole format was read, but there was input left over, starting
+    /// at this byte position.
+    TrailingInput { pos: Pos },
+
+    /// The named field is needed, but wasn’t read.
+    MissingField(&'static str),
+
+    /// The named field doesn’t agree with the rest of the fields.
+    Inconsistent(&'static str),
+
+    /// The fields were read, but they don’t make a valid value.
+    Date(DateTimeError),
+
+    /// The format string itself was invalid.
+    Format(FormatError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::InvalidField { field, pos }      => write!(f, "{}: field {} at byte {}", self.description(), field, pos),
+            ParseError::ConflictingField { field, pos }  => write!(f, "{}: field {} at byte {}", self.description(), field, pos),
+            ParseError::TrailingInput { pos }            => write!(f, "{} at byte {}", self.description(), pos),
+            ParseError::MissingField(name)               => write!(f, "{}: {}", self.description(), name),
+            ParseError::Inconsistent(name)               => write!(f, "{}: {}", self.description(), name),
+            ParseError::Date(ref error)                  => write!(f, "{}: {}", self.description(), error),
+            ParseError::Format(ref error)                => write!(f, "{}: {:?}", self.description(), error),
+        }
+    }
+}
+
+impl ErrorTrait for ParseError {
+    fn description(&self) -> &str {
+        match *self {
+            ParseError::InvalidField { .. }      => "input does not match format",
+            ParseError::ConflictingField { .. }  => "field read twice with different values",
+            ParseError::TrailingInput { .. }     => "input left over after format",
+            ParseError::MissingField(_)          => "field missing from input",
+            ParseError::Inconsistent(_)          => "field inconsistent with the rest",
+            ParseError::Date(_)                  => "parsing resulted in an invalid date",
+            ParseError::Format(_)                => "invalid format string",
+        }
+    }
+
+    fn cause(&self) -> Option<&ErrorTrait> {
+        match *self {
+            ParseError::Date(ref error)  => Some(error),
+            _                            => None,
+        }
+    }
 }
 
 
@@ -310,12 +1343,31 @@ impl<'a> FormatParser<'a> {
                     let bitlet = match self.next() {
                         Some((_, 'Y')) => Field::Year(NumArguments(args)),
                         Some((_, 'y')) => Field::YearOfCentury(NumArguments(args)),
+                        Some((_, 'g')) => Field::WeekYear(NumArguments(args)),
+                        Some((_, 'G')) => Field::Era(long, TextArguments(args)),
                         Some((_, 'M')) => Field::MonthName(long, TextArguments(args)),
+                        Some((_, 'L')) => Field::StandaloneMonthName(long, TextArguments(args)),
+                        Some((_, 'N')) => Field::MonthNumber(NumArguments(args)),
+                        Some((_, 'q')) => Field::Quarter(NumArguments(args)),
                         Some((_, 'D')) => Field::Day(NumArguments(args)),
+                        Some((_, 'j')) => Field::YearDay(NumArguments(args)),
+                        Some((_, 'W')) => Field::Week(NumArguments(args)),
+                        Some((_, 'U')) => Field::WeekOfYear(Weekday::Sunday, NumArguments(args)),
+                        Some((_, 'K')) => Field::WeekOfYear(Weekday::Monday, NumArguments(args)),
                         Some((_, 'E')) => Field::WeekdayName(long, TextArguments(args)),
+                        Some((_, 'c')) => Field::StandaloneWeekdayName(long, TextArguments(args)),
+                        Some((_, 'u')) => Field::WeekdayNumber(true, NumArguments(args)),
+                        Some((_, 'w')) => Field::WeekdayNumber(false, NumArguments(args)),
                         Some((_, 'h')) => Field::Hour(NumArguments(args)),
+                        Some((_, 'l')) => Field::Hour12(NumArguments(args)),
+                        Some((_, 'p')) => Field::AmPm(TextArguments(args)),
                         Some((_, 'm')) => Field::Minute(NumArguments(args)),
                         Some((_, 's')) => Field::Second(NumArguments(args)),
+                        Some((_, 'f')) => Field::Millisecond(NumArguments(args)),
+                        Some((_, 'F')) => Field::Fraction(NumArguments(args)),
+                        Some((_, 'z')) => Field::UtcOffset(long, TextArguments(args)),
+                        Some((_, 'Z')) => Field::ZoneAbbreviation(TextArguments(args)),
+                        Some((_, 'T')) => Field::Timestamp(NumArguments(args)),
                         Some((pos, c)) => return Err(FormatError::InvalidChar { c: c, colon: true, pos: pos }),
                         None => return Err(FormatError::OpenCurlyBrace { open_pos: open_pos }),
                     };
@@ -340,7 +1392,7 @@ impl<'a> FormatParser<'a> {
 
 #[cfg(test)]
 mod test {
-    pub use super::{DateFormat, FormatError, Field, Arguments, NumArguments, TextArguments};
+    pub use super::{DateFormat, FormatError, Arguments, NumArguments, TextArguments, Parsed, ParseError};
     pub use super::Field::*;
 
     pub use pad::Alignment;
@@ -404,4 +1456,210 @@ mod test {
             test!(width_123456789: "{>123456789:D}" => Ok(DateFormat { fields: vec![ Day(NumArguments(Arguments::empty().set_width(123456789).set_alignment(Alignment::Right))) ] }));
         }
     }
+
+    mod parse_value {
+        pub use super::*;
+        use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday};
+        use locale;
+
+        fn parse(format: &str, input: &str) -> Result<Parsed, ParseError> {
+            DateFormat::parse(format).unwrap().parse_value(input, &locale::Time::english())
+        }
+
+        #[test]
+        fn numbers_and_names() {
+            let parsed = parse("{:D}/{:M}/{:Y} {:h}:{:m}:{:s}", "31/Jan/2024 10:30:15").unwrap();
+            let expected = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 31).unwrap(), LocalTime::hms(10, 30, 15).unwrap());
+            assert_eq!(parsed.to_local_date_time(), Ok(expected));
+        }
+
+        #[test]
+        fn long_names_ignoring_case() {
+            let parsed = parse("{_:E}, {:D} {_:M} {:Y}", "wednesday, 31 JANUARY 2024").unwrap();
+            assert_eq!(parsed.weekday, Some(Weekday::Wednesday));
+            assert_eq!(parsed.to_local_date(), Ok(LocalDate::ymd(2024, Month::January, 31).unwrap()));
+        }
+
+        #[test]
+        fn widths_and_padding() {
+            let parsed = parse("{>02:h}{>02:m}{>4:D}", "0905   7").unwrap();
+            assert_eq!(parsed.hour, Some(9));
+            assert_eq!(parsed.minute, Some(5));
+            assert_eq!(parsed.day, Some(7));
+        }
+
+        #[test]
+        fn negative_years() {
+            assert_eq!(parse("{:Y}", "-44").unwrap().year, Some(-44));
+        }
+
+        #[test]
+        fn year_of_century() {
+            let parsed = parse("{:D} {:M} {:y}", "1 Mar 68").unwrap();
+            assert_eq!(parsed.to_local_date(), Ok(LocalDate::ymd(2068, Month::March, 1).unwrap()));
+
+            let parsed = parse("{:D} {:M} {:y}", "1 Mar 69").unwrap();
+            assert_eq!(parsed.to_local_date(), Ok(LocalDate::ymd(1969, Month::March, 1).unwrap()));
+        }
+
+        #[test]
+        fn partial() {
+            let format = DateFormat::parse("{:h}:{:m}").unwrap();
+            let mut parsed = Parsed::new();
+            assert_eq!(format.parse_into("10:30 and more", &locale::Time::english(), &mut parsed), Ok(5));
+            assert_eq!(parsed.to_local_time(), Ok(LocalTime::hms(10, 30, 0).unwrap()));
+            assert_eq!(parsed.to_local_date(), Err(ParseError::MissingField("year")));
+        }
+
+        #[test]
+        fn failing_field() {
+            assert_eq!(parse("{:Y}-{:D}", "2024/31"), Err(ParseError::InvalidField { field: 1, pos: 4 }));
+            assert_eq!(parse("{:D} {:M}", "31 Foo"), Err(ParseError::InvalidField { field: 2, pos: 3 }));
+            assert_eq!(parse("{:Y}", "2024!"), Err(ParseError::TrailingInput { pos: 4 }));
+        }
+
+        #[test]
+        fn conflicting_fields() {
+            assert_eq!(parse("{:D} {:D}", "1 2"), Err(ParseError::ConflictingField { field: 2, pos: 2 }));
+            assert!(parse("{:D} {:D}", "2 2").is_ok());
+        }
+
+        #[test]
+        fn wrong_weekday() {
+            let parsed = parse("{:E} {:D} {:M} {:Y}", "Thu 31 Jan 2024").unwrap();
+            assert_eq!(parsed.to_local_date(), Err(ParseError::Inconsistent("weekday")));
+        }
+
+        #[test]
+        fn invalid_date() {
+            let parsed = parse("{:D} {:M} {:Y}", "30 Feb 2024").unwrap();
+            assert!(match parsed.to_local_date() { Err(ParseError::Date(_)) => true, _ => false });
+        }
+    }
+
+    mod more_fields {
+        pub use super::*;
+        use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+        use cal::offset::Offset;
+        use locale;
+
+        fn datetime() -> LocalDateTime {
+            LocalDateTime::new(LocalDate::ymd(2024, Month::February, 3).unwrap(), LocalTime::hms_ms(15, 4, 5, 250).unwrap())
+        }
+
+        fn format(format: &str) -> String {
+            DateFormat::parse(format).unwrap().format(&datetime(), &locale::Time::english())
+        }
+
+        fn parse(format: &str, input: &str) -> Result<Parsed, ParseError> {
+            DateFormat::parse(format).unwrap().parse_value(input, &locale::Time::english())
+        }
+
+        #[test]
+        fn dates() {
+            assert_eq!(format("{:N} {:q} {:j} {:W} {:G} {_:G}"), "2 1 34 5 AD Anno Domini");
+            assert_eq!(format("{>02:N}/{>03:j}"), "02/034");
+        }
+
+        #[test]
+        fn times() {
+            assert_eq!(format("{:l}:{>02:m} {:p}"), "3:04 PM");
+            assert_eq!(format("{:s}.{>03:f}"), "5.250");
+            assert_eq!(format("{:s}.{:F}"), "5.25");
+        }
+
+        #[test]
+        fn offsets() {
+            let datetime = Offset::of_hours_and_minutes(5, 30).unwrap().transform_date(datetime());
+            let locale = locale::Time::english();
+            assert_eq!(DateFormat::parse("{:z} {_:z}").unwrap().format(&datetime, &locale), "+0530 +05:30");
+        }
+
+        #[test]
+        fn round_trip_with_an_offset() {
+            let format = DateFormat::parse("{:Y}-{>02:N}-{>02:D} {>02:h}:{>02:m} {_:z}").unwrap();
+            let locale = locale::Time::english();
+            let parsed = format.parse_value("2024-02-03 10:00 +05:30", &locale).unwrap();
+            let datetime = parsed.to_offset_date_time(Offset::utc()).unwrap();
+
+            assert_eq!(datetime.local, LocalDateTime::new(LocalDate::ymd(2024, Month::February, 3).unwrap(), LocalTime::hms(10, 0, 0).unwrap()));
+            assert_eq!(format.format(&datetime, &locale), "2024-02-03 10:00 +05:30");
+        }
+
+        #[test]
+        fn no_offset() {
+            assert_eq!(format("[{:z}{:Z}]"), "[]");
+        }
+
+        #[test]
+        fn parse_numeric_month_and_fraction() {
+            let parsed = parse("{:Y}-{:N}-{:D} {:h}:{:m}:{:s}.{:F}", "2024-2-3 15:04:05.25").unwrap();
+            assert_eq!(parsed.to_local_date_time(), Ok(datetime()));
+        }
+
+        #[test]
+        fn parse_12_hour_clock() {
+            let parsed = parse("{:l}:{:m} {:p}", "12:30 am").unwrap();
+            assert_eq!(parsed.to_local_time(), Ok(LocalTime::hms(0, 30, 0).unwrap()));
+
+            let parsed = parse("{:l}:{:m}", "12:30").unwrap();
+            assert_eq!(parsed.to_local_time(), Err(ParseError::MissingField("AM/PM")));
+        }
+
+        #[test]
+        fn parse_yearday_and_week() {
+            let parsed = parse("{:Y}-{:j}", "2024-034").unwrap();
+            assert_eq!(parsed.to_local_date(), Ok(datetime().date()));
+
+            let parsed = parse("{:Y} week {:W} {:E}", "2024 week 5 Sat").unwrap();
+            assert_eq!(parsed.to_local_date(), Ok(datetime().date()));
+
+            let parsed = parse("{:Y}-{:j}", "2023-366").unwrap();
+            assert!(match parsed.to_local_date() { Err(ParseError::Date(_)) => true, _ => false });
+        }
+
+        #[test]
+        fn parse_offsets() {
+            let parsed = parse("{:h}:{:m} {:z}", "10:30 -08:00").unwrap();
+            assert_eq!(parsed.offset, Some(Offset::of_hours_and_minutes(-8, 0).unwrap()));
+
+            let parsed = parse("{:h}:{:m} {:Z}", "10:30 UTC").unwrap();
+            assert_eq!(parsed.offset, Some(Offset::utc()));
+
+            assert_eq!(parse("{:z}", "-0000").unwrap().offset, Some(Offset::unknown()));
+            assert_eq!(parse("{:z}", "+01"), Err(ParseError::InvalidField { field: 0, pos: 0 }));
+        }
+
+        #[test]
+        fn parse_offsets_out_of_range() {
+            assert_eq!(parse("{:z}", "+0199"), Err(ParseError::InvalidField { field: 0, pos: 0 }));
+            assert_eq!(parse("{:z}", "-01:60"), Err(ParseError::InvalidField { field: 0, pos: 0 }));
+            assert_eq!(parse("{:z}", "+01:30:60"), Err(ParseError::InvalidField { field: 0, pos: 0 }));
+            assert_eq!(parse("{:z}", "+01:59:59").unwrap().offset, Some(Offset::of_seconds(3600 + 59 * 60 + 59).unwrap()));
+        }
+
+        #[test]
+        fn parse_into_offset_date_time() {
+            let parsed = parse("{:Y}-{:N}-{:D} {:h}:{:m}:{:s}.{:f} {:z}", "2024-2-3 15:04:05.250 +0530").unwrap();
+            let offset = Offset::of_hours_and_minutes(5, 30).unwrap();
+            assert_eq!(parsed.to_offset_date_time(Offset::utc()), Ok(offset.transform_date(datetime())));
+        }
+
+        #[test]
+        fn inconsistent_fields() {
+            let parsed = parse("{:Y}-{:N}-{:D} Q{:q}", "2024-2-3 Q2").unwrap();
+            assert_eq!(parsed.to_local_date(), Err(ParseError::Inconsistent("quarter")));
+
+            let parsed = parse("{:h} {:p}", "15 AM").unwrap();
+            assert_eq!(parsed.to_local_time(), Err(ParseError::Inconsistent("AM/PM")));
+
+            let parsed = parse("{:Y}-{:N}-{:D} {:G}", "2024-2-3 BC").unwrap();
+            assert_eq!(parsed.to_local_date(), Err(ParseError::Inconsistent("era")));
+        }
+
+        #[test]
+        fn month_name_and_number_conflict() {
+            assert_eq!(parse("{:N} {:M}", "2 Mar"), Err(ParseError::ConflictingField { field: 2, pos: 2 }));
+        }
+    }
 }
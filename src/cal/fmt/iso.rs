@@ -1,6 +1,8 @@
 use std::fmt;
 use cal::{LocalDate, LocalTime, LocalDateTime, DatePiece, TimePiece};
 use cal::{Offset, OffsetDateTime};
+use cal::iso_interval::{IsoInterval, RepeatingInterval};
+use cal::period::Period;
 use util::RangeExt;
 
 
@@ -9,7 +11,19 @@ pub trait ISO: Sized {
         ISOString(self)
     }
 
+    /// Returns a value that displays this one in the ISO 8601 style given
+    /// by the format, rather than the default one.
+    fn iso_with(&self, format: IsoFormat) -> ISOFormatString<'_, Self> {
+        ISOFormatString(self, format)
+    }
+
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result;
+
+    /// Writes this value in the given ISO 8601 style. Values that have no
+    /// options to choose from are written in the default style.
+    fn fmt_with(&self, _format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        ISO::fmt(self, f)
+    }
 }
 
 #[derive(Debug)]
@@ -22,44 +36,268 @@ where T: ISO {
     }
 }
 
-impl ISO for LocalDate {
+#[derive(Debug)]
+pub struct ISOFormatString<'a, T: 'a>(&'a T, IsoFormat);
+
+impl<'a, T> fmt::Display for ISOFormatString<'a, T>
+where T: ISO {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let year = self.year();
-        if year.is_within(0 .. 9999) {
-            write!(f, "{:04}-{:02}-{:02}", year, self.month() as usize, self.day())
+        self.0.fmt_with(&self.1, f)
+    }
+}
+
+
+/// The options for writing dates and times in ISO 8601 format, for use
+/// with `ISO::iso_with`.
+///
+/// The default options give the same output as `ISO::iso`, and each
+/// method changes one of them.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, ISO};
+/// use datetime::iso::{IsoFormat, Precision, DateForm};
+///
+/// let datetime = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 31).unwrap(), LocalTime::hms(10, 30, 0).unwrap());
+///
+/// let format = IsoFormat::new().basic().precision(Precision::Seconds);
+/// assert_eq!(datetime.iso_with(format).to_string(), "20240131T103000");
+///
+/// let format = IsoFormat::new().date_form(DateForm::Week).precision(Precision::Minutes);
+/// assert_eq!(datetime.iso_with(format).to_string(), "2024-W05-3T10:30");
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct IsoFormat {
+    basic: bool,
+    precision: Precision,
+    date_form: DateForm,
+    utc_as_z: bool,
+    year_sign: bool,
+}
+
+/// How much of a time to write.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Precision {
+
+    /// Hours and minutes, such as `10:30`.
+    Minutes,
+
+    /// Hours, minutes, and seconds, such as `10:30:15`.
+    Seconds,
+
+    /// Milliseconds, such as `10:30:15.250`.
+    Milliseconds,
+
+    /// Microseconds, such as `10:30:15.250000`.
+    Microseconds,
+
+    /// Nanoseconds, such as `10:30:15.250000000`.
+    Nanoseconds,
+
+    /// Seconds, then as many digits of the fraction as are needed, such as
+    /// `10:30:15.25`, or none if it’s a whole number of seconds.
+    Trimmed,
+}
+
+/// Which of the three ISO 8601 forms to write dates in.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum DateForm {
+
+    /// The year, month, and day, such as `2024-01-31`.
+    Calendar,
+
+    /// The week-numbering year, week, and weekday, such as `2024-W05-3`.
+    Week,
+
+    /// The year and the day of the year, such as `2024-031`.
+    Ordinal,
+}
+
+impl IsoFormat {
+
+    /// Returns the default options: the extended format, to the
+    /// millisecond, in calendar form, with UTC written as `Z`, and a sign
+    /// only on years outside `0000` to `9999`.
+    pub fn new() -> IsoFormat {
+        IsoFormat {
+            basic:      false,
+            precision:  Precision::Milliseconds,
+            date_form:  DateForm::Calendar,
+            utc_as_z:   true,
+            year_sign:  false,
+        }
+    }
+
+    /// Uses the basic format, which leaves out the `-` and `:` separators,
+    /// such as `20240131T103015`.
+    pub fn basic(mut self) -> IsoFormat {
+        self.basic = true;
+        self
+    }
+
+    /// Writes times to the given precision.
+    pub fn precision(mut self, precision: Precision) -> IsoFormat {
+        self.precision = precision;
+        self
+    }
+
+    /// Writes dates in the given form.
+    pub fn date_form(mut self, date_form: DateForm) -> IsoFormat {
+        self.date_form = date_form;
+        self
+    }
+
+    /// Writes UTC as `+00:00`, or `+0000` in the basic format, instead of
+    /// `Z`.
+    pub fn utc_as_offset(mut self) -> IsoFormat {
+        self.utc_as_z = false;
+        self
+    }
+
+    /// Always writes a sign before the year, such as `+2024-01-31`.
+    pub fn year_sign(mut self) -> IsoFormat {
+        self.year_sign = true;
+        self
+    }
+
+    /// Writes a separator, unless this is the basic format.
+    fn separator(&self, f: &mut fmt::Formatter, separator: &str) -> fmt::Result {
+        if self.basic { Ok(()) } else { f.write_str(separator) }
+    }
+
+    /// Writes a year, with a sign if it needs one.
+    fn year(&self, f: &mut fmt::Formatter, year: i64) -> fmt::Result {
+        if year.is_within(0 .. 10000) && !self.year_sign {
+            write!(f, "{:04}", year)
         }
         else {
-            write!(f, "{:+05}-{:02}-{:02}", year, self.month() as usize, self.day())
+            write!(f, "{:+05}", year)
+        }
+    }
+}
+
+impl Default for IsoFormat {
+    fn default() -> IsoFormat {
+        IsoFormat::new()
+    }
+}
+
+
+impl ISO for LocalDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with(&IsoFormat::new(), f)
+    }
+
+    fn fmt_with(&self, format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        match format.date_form {
+            DateForm::Calendar => {
+                try!(format.year(f, self.year()));
+                try!(format.separator(f, "-"));
+                try!(write!(f, "{:02}", self.month() as usize));
+                try!(format.separator(f, "-"));
+                write!(f, "{:02}", self.day())
+            },
+
+            DateForm::Week => {
+                let (year, week) = self.iso_week();
+                let weekday = match self.weekday() as usize { 0 => 7, n => n };
+
+                try!(format.year(f, year));
+                try!(format.separator(f, "-"));
+                try!(write!(f, "W{:02}", week));
+                try!(format.separator(f, "-"));
+                write!(f, "{}", weekday)
+            },
+
+            DateForm::Ordinal => {
+                try!(format.year(f, self.year()));
+                try!(format.separator(f, "-"));
+                write!(f, "{:03}", self.yearday())
+            },
         }
     }
 }
 
 impl ISO for LocalTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:02}:{:02}:{:02}.{:03}", self.hour(), self.minute(), self.second(), self.millisecond())
+        self.fmt_with(&IsoFormat::new(), f)
+    }
+
+    fn fmt_with(&self, format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{:02}", self.hour()));
+        try!(format.separator(f, ":"));
+        try!(write!(f, "{:02}", self.minute()));
+
+        if format.precision == Precision::Minutes {
+            return Ok(());
+        }
+
+        try!(format.separator(f, ":"));
+        try!(write!(f, "{:02}", self.second()));
+
+        let millisecond = self.millisecond();
+        match format.precision {
+            Precision::Minutes | Precision::Seconds      => Ok(()),
+            Precision::Milliseconds                      => write!(f, ".{:03}", millisecond),
+            Precision::Microseconds                      => write!(f, ".{:03}000", millisecond),
+            Precision::Nanoseconds                       => write!(f, ".{:03}000000", millisecond),
+            Precision::Trimmed if millisecond == 0       => Ok(()),
+            Precision::Trimmed if millisecond % 100 == 0 => write!(f, ".{}", millisecond / 100),
+            Precision::Trimmed if millisecond % 10 == 0  => write!(f, ".{:02}", millisecond / 10),
+            Precision::Trimmed                           => write!(f, ".{:03}", millisecond),
+        }
     }
 }
 
 impl ISO for LocalDateTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        try!(self.date().fmt(f));
+        self.fmt_with(&IsoFormat::new(), f)
+    }
+
+    fn fmt_with(&self, format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(self.date().fmt_with(format, f));
         try!(write!(f, "T"));
-        self.time().fmt(f)
+        self.time().fmt_with(format, f)
     }
 }
 
 impl ISO for Offset {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_utc() {
+        self.fmt_with(&IsoFormat::new(), f)
+    }
+
+    fn fmt_with(&self, format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_utc() && format.utc_as_z {
             write!(f, "Z")
         }
+        else if self.is_utc() {
+            try!(write!(f, "+00"));
+            try!(format.separator(f, ":"));
+            write!(f, "00")
+        }
+        else if self.is_unknown() {
+            try!(write!(f, "-00"));
+            try!(format.separator(f, ":"));
+            write!(f, "00")
+        }
         else {
             try!(f.write_str(if self.is_negative() { "-" } else { "+" }));
 
             match (self.hours(), self.minutes(), self.seconds()) {
                 (h, 0, 0) => write!(f, "{:02}", h.abs()),
-                (h, m, 0) => write!(f, "{:02}:{:02}", h.abs(), m.abs()),
-                (h, m, s) => write!(f, "{:02}:{:02}:{:02}", h.abs(), m.abs(), s.abs()),
+                (h, m, 0) => {
+                    try!(write!(f, "{:02}", h.abs()));
+                    try!(format.separator(f, ":"));
+                    write!(f, "{:02}", m.abs())
+                },
+                (h, m, s) => {
+                    try!(write!(f, "{:02}", h.abs()));
+                    try!(format.separator(f, ":"));
+                    try!(write!(f, "{:02}", m.abs()));
+                    try!(format.separator(f, ":"));
+                    write!(f, "{:02}", s.abs())
+                },
             }
         }
     }
@@ -67,6 +305,78 @@ impl ISO for Offset {
 
 impl ISO for OffsetDateTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.local.iso(), self.offset.iso())
+        self.fmt_with(&IsoFormat::new(), f)
+    }
+
+    fn fmt_with(&self, format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(self.local.fmt_with(format, f));
+        self.offset.fmt_with(format, f)
+    }
+}
+
+impl ISO for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+
+        try!(write!(f, "P"));
+        if self.years  != 0 { try!(write!(f, "{}Y", self.years)) }
+        if self.months != 0 { try!(write!(f, "{}M", self.months)) }
+        if self.days   != 0 { try!(write!(f, "{}D", self.days)) }
+
+        let milliseconds = self.seconds * 1000 + self.milliseconds;
+        if self.hours == 0 && self.minutes == 0 && milliseconds == 0 {
+            return Ok(());
+        }
+
+        try!(write!(f, "T"));
+        if self.hours   != 0 { try!(write!(f, "{}H", self.hours)) }
+        if self.minutes != 0 { try!(write!(f, "{}M", self.minutes)) }
+
+        if milliseconds % 1000 != 0 {
+            let sign = if milliseconds < 0 { "-" } else { "" };
+            let fraction = format!("{:03}", (milliseconds % 1000).abs());
+            try!(write!(f, "{}{}.{}S", sign, (milliseconds / 1000).abs(), fraction.trim_right_matches('0')));
+        }
+        else if milliseconds != 0 {
+            try!(write!(f, "{}S", milliseconds / 1000));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: ISO> ISO for IsoInterval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with(&IsoFormat::new(), f)
+    }
+
+    fn fmt_with(&self, format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IsoInterval::StartEnd(ref start, ref end)       => write!(f, "{}/{}", start.iso_with(*format), end.iso_with(*format)),
+            IsoInterval::StartPeriod(ref start, ref period) => write!(f, "{}/{}", start.iso_with(*format), period.iso()),
+            IsoInterval::PeriodEnd(ref period, ref end)     => write!(f, "{}/{}", period.iso(), end.iso_with(*format)),
+        }
+    }
+}
+
+impl<T: ISO> ISO for RepeatingInterval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.repetitions {
+            Some(count)  => try!(write!(f, "R{}/", count)),
+            None         => try!(write!(f, "R/")),
+        }
+
+        ISO::fmt(&self.interval, f)
+    }
+
+    fn fmt_with(&self, format: &IsoFormat, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.repetitions {
+            Some(count)  => try!(write!(f, "R{}/", count)),
+            None         => try!(write!(f, "R/")),
+        }
+
+        self.interval.fmt_with(format, f)
     }
 }
@@ -0,0 +1,176 @@
+//! Translating Unicode LDML date patterns, as used by CLDR, such as
+//! `yyyy-MM-dd'T'HH:mm`, into `DateFormat` fields.
+
+use cal::fmt::custom::{DateFormat, Field, FormatError, Arguments, NumArguments, TextArguments, Pos};
+
+use pad::Alignment;
+
+
+/// The pattern letters that have a field for at least one length.
+static KNOWN_LETTERS: &'static str = "GyuYQqMLwdDEecahHmsSzZxX";
+
+
+impl<'a> DateFormat<'a> {
+
+    /// Translates an LDML date pattern into a `DateFormat`.
+    ///
+    /// Each run of the same letter is one field, and the number of times
+    /// it’s repeated picks its width: `d` gives `7` and `dd` gives `07`;
+    /// `MMM` gives `Jan` and `MMMM` gives `January`. `yy` is the year of
+    /// the century, `Y` is the ISO week-numbering year, and `L` and `c` are
+    /// the stand-alone month and weekday names. Text in single quotes is
+    /// written as it is, and `''` is a single quote, inside quotes or out.
+    /// Any other ASCII letter is reserved, and is an error, as are lengths
+    /// that have no equivalent field, such as `MMMMM`, the narrow month
+    /// name.
+    ///
+    /// Years are numbered astronomically, so `y` gives `0` for 1 BC, and
+    /// the numeric weekdays `e` and `c` count from Monday as 1, whatever
+    /// the locale.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// extern crate datetime;
+    /// extern crate locale;
+    /// use datetime::fmt::DateFormat;
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+    ///
+    /// # fn main() {
+    /// let datetime = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 31).unwrap(), LocalTime::hms(15, 4, 0).unwrap());
+    /// let locale = locale::Time::english();
+    ///
+    /// let format = DateFormat::from_ldml("EEEE, d MMMM y 'at' h:mm a").unwrap();
+    /// assert_eq!(format.format(&datetime, &locale), "Wednesday, 31 January 2024 at 3:04 PM");
+    /// # }
+    /// ```
+    pub fn from_ldml(input: &'a str) -> Result<DateFormat<'a>, FormatError> {
+        let mut fields = Vec::new();
+        let mut literal_start = None;
+        let mut iter = input.char_indices().peekable();
+
+        while let Some((pos, c)) = iter.next() {
+            if c != '\'' && !c.is_ascii_alphabetic() {
+                if literal_start.is_none() {
+                    literal_start = Some(pos);
+                }
+
+                continue;
+            }
+
+            if let Some(start) = literal_start.take() {
+                fields.push(Field::Literal(&input[start .. pos]));
+            }
+
+            if c == '\'' {
+                // A doubled quote is a quote on its own; anything else
+                // starts some quoted text, in which a doubled quote is
+                // still a quote.
+                if let Some(&(_, '\'')) = iter.peek() {
+                    let _ = iter.next();
+                    fields.push(Field::Literal(&input[pos .. pos + 1]));
+                    continue;
+                }
+
+                let mut start = pos + 1;
+                loop {
+                    match iter.next() {
+                        Some((quote_pos, '\'')) => {
+                            if let Some(&(_, '\'')) = iter.peek() {
+                                let _ = iter.next();
+                                fields.push(Field::Literal(&input[start .. quote_pos + 1]));
+                                start = quote_pos + 2;
+                            }
+                            else {
+                                if start < quote_pos {
+                                    fields.push(Field::Literal(&input[start .. quote_pos]));
+                                }
+
+                                break;
+                            }
+                        },
+                        Some(_)  => {},
+                        None     => return Err(FormatError::UnclosedQuote { open_pos: pos }),
+                    }
+                }
+            }
+            else {
+                let mut length = 1;
+                while iter.peek().map(|&(_, next)| next) == Some(c) {
+                    let _ = iter.next();
+                    length += 1;
+                }
+
+                try!(push_field(&mut fields, c, length, pos));
+            }
+        }
+
+        if let Some(start) = literal_start {
+            fields.push(Field::Literal(&input[start ..]));
+        }
+
+        Ok(DateFormat { fields: fields })
+    }
+}
+
+
+/// Adds the field for a run of the given pattern letter.
+fn push_field<'a>(fields: &mut Vec<Field<'a>>, c: char, length: usize, pos: Pos) -> Result<(), FormatError> {
+    let text = TextArguments(Arguments::empty());
+    let num = NumArguments(if length == 1 { Arguments::empty() } else {
+        Arguments {
+            alignment: Some(Alignment::Right),
+            width:     Some(length),
+            pad_char:  Some('0'),
+        }
+    });
+
+    let field = match (c, length) {
+        ('G', n) if n <= 3     => Field::Era(false, text),
+        ('G', 4)               => Field::Era(true, text),
+        ('y', 2)               => Field::YearOfCentury(num),
+        ('y', _) | ('u', _)    => Field::Year(num),
+        ('Y', n) if n != 2     => Field::WeekYear(num),
+
+        ('Q', n) | ('q', n) if n <= 2 => Field::Quarter(num),
+        ('Q', 3) | ('q', 3)    => {
+            fields.push(Field::Literal("Q"));
+            Field::Quarter(NumArguments(Arguments::empty()))
+        },
+
+        ('M', n) | ('L', n) if n <= 2 => Field::MonthNumber(num),
+        ('M', 3)               => Field::MonthName(false, text),
+        ('M', 4)               => Field::MonthName(true, text),
+        ('L', 3)               => Field::StandaloneMonthName(false, text),
+        ('L', 4)               => Field::StandaloneMonthName(true, text),
+
+        ('w', n) if n <= 2     => Field::Week(num),
+        ('d', n) if n <= 2     => Field::Day(num),
+        ('D', n) if n <= 3     => Field::YearDay(num),
+
+        ('E', n) if n <= 3     => Field::WeekdayName(false, text),
+        ('E', 4) | ('e', 4)    => Field::WeekdayName(true, text),
+        ('e', 3)               => Field::WeekdayName(false, text),
+        ('e', n) | ('c', n) if n <= 2 => Field::WeekdayNumber(true, num),
+        ('c', 3)               => Field::StandaloneWeekdayName(false, text),
+        ('c', 4)               => Field::StandaloneWeekdayName(true, text),
+
+        ('a', n) if n <= 4     => Field::AmPm(text),
+        ('h', n) if n <= 2     => Field::Hour12(num),
+        ('H', n) if n <= 2     => Field::Hour(num),
+        ('m', n) if n <= 2     => Field::Minute(num),
+        ('s', n) if n <= 2     => Field::Second(num),
+        ('S', 3)               => Field::Millisecond(num),
+
+        ('z', n) if n <= 3     => Field::ZoneAbbreviation(text),
+        ('Z', n) if n <= 3     => Field::UtcOffset(false, text),
+        ('x', 2) | ('X', 2)    => Field::UtcOffset(false, text),
+        ('Z', 5) | ('x', 3) | ('X', 3) => Field::UtcOffset(true, text),
+
+        (c, _) if KNOWN_LETTERS.contains(c) => return Err(FormatError::UnsupportedLength { c: c, length: length, pos: pos }),
+        (c, _)                 => return Err(FormatError::UnsupportedDirective { c: c, pos: pos }),
+    };
+
+    fields.push(field);
+    Ok(())
+}
@@ -1,4 +1,9 @@
 pub mod iso;
 pub mod custom;
+pub mod strftime;
+pub mod cldr;
+pub mod style;
+pub mod relative;
+mod ldml;
 
 pub use cal::fmt::iso::ISO;
\ No newline at end of file
@@ -0,0 +1,192 @@
+//! Writing how far ahead or behind a time is, such as “3 hours ago” or
+//! “in 2 days”, in a locale’s language.
+//!
+//! The unit is picked by counting up through the units, from seconds to
+//! years, and stopping at the first one where the count is under its
+//! threshold. The count is rounded to the nearest whole number, with
+//! halves rounded away from zero, before it’s checked against the
+//! threshold, so the number that’s written is always the one that picked
+//! the unit. Months and years use their average lengths in the Gregorian
+//! calendar, rather than the calendar months and years in between.
+
+use cal::fmt::cldr::Locale;
+use duration::Duration;
+use instant::Instant;
+
+
+/// A unit that relative times are counted in.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+static UNITS: [RelativeUnit; 7] = [
+    RelativeUnit::Second, RelativeUnit::Minute, RelativeUnit::Hour, RelativeUnit::Day,
+    RelativeUnit::Week, RelativeUnit::Month, RelativeUnit::Year,
+];
+
+impl RelativeUnit {
+
+    /// Returns the number of seconds in one of this unit. A month is a
+    /// twelfth of a year, and a year is 365.2425 days.
+    pub fn seconds(self) -> i64 {
+        match self {
+            RelativeUnit::Second  => 1,
+            RelativeUnit::Minute  => 60,
+            RelativeUnit::Hour    => 60 * 60,
+            RelativeUnit::Day     => 24 * 60 * 60,
+            RelativeUnit::Week    => 7 * 24 * 60 * 60,
+            RelativeUnit::Month   => 2_629_746,
+            RelativeUnit::Year    => 31_556_952,
+        }
+    }
+}
+
+
+/// Whether to use a locale’s words for particular times, such as
+/// “yesterday”, where it has them.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RelativeStyle {
+
+    /// Always write a number, such as “1 day ago”.
+    Numeric,
+
+    /// Write a word where there is one, such as “yesterday”, “now”, or
+    /// “next month”, and a number otherwise.
+    Idiomatic,
+}
+
+
+/// A formatter for relative times, which can be configured by chaining
+/// its methods.
+///
+/// By default, times are numeric, and a unit is used while its count is
+/// below 45 seconds, 45 minutes, 22 hours, 26 days, or 11 months, after
+/// which years are used; weeks are left out.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::Duration;
+/// use datetime::cldr::Locale;
+/// use datetime::relative::RelativeFormat;
+///
+/// let english = RelativeFormat::new(Locale::from_tag("en").unwrap());
+/// assert_eq!(english.format(Duration::of(-3 * 60 * 60)), "3 hours ago");
+/// assert_eq!(english.format(Duration::of(2 * 24 * 60 * 60)), "in 2 days");
+/// assert_eq!(english.idiomatic().format(Duration::of(-24 * 60 * 60)), "yesterday");
+///
+/// let german = RelativeFormat::new(Locale::from_tag("de").unwrap());
+/// assert_eq!(german.format(Duration::of(-90)), "vor 2 Minuten");
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RelativeFormat<'l> {
+    locale: &'l Locale,
+    style: RelativeStyle,
+
+    /// The count of each unit, from seconds to months, at which the next
+    /// unit is used instead.
+    thresholds: [i64; 6],
+}
+
+impl<'l> RelativeFormat<'l> {
+
+    /// Creates a numeric formatter for the given locale, with the default
+    /// thresholds.
+    pub fn new(locale: &'l Locale) -> RelativeFormat<'l> {
+        RelativeFormat {
+            locale: locale,
+            style: RelativeStyle::Numeric,
+            thresholds: [ 45, 45, 22, 26, 0, 11 ],
+        }
+    }
+
+    /// Writes times using words where the locale has them, such as
+    /// “yesterday”, rather than always using numbers.
+    pub fn idiomatic(self) -> RelativeFormat<'l> {
+        self.style(RelativeStyle::Idiomatic)
+    }
+
+    /// Sets whether to use words where the locale has them.
+    pub fn style(mut self, style: RelativeStyle) -> RelativeFormat<'l> {
+        self.style = style;
+        self
+    }
+
+    /// Sets the count of a unit at which the next unit up is used instead,
+    /// so a threshold of 45 for seconds writes 44 seconds as “44 seconds”
+    /// and 45 seconds as “1 minute”. A threshold of 0 leaves the unit out.
+    /// Years have no threshold, as there’s no unit after them, so it’s
+    /// ignored for them.
+    pub fn threshold(mut self, unit: RelativeUnit, threshold: i64) -> RelativeFormat<'l> {
+        if unit != RelativeUnit::Year {
+            self.thresholds[unit as usize] = threshold;
+        }
+
+        self
+    }
+
+    /// Writes a time the given duration away from now: ahead if it’s
+    /// positive, and behind if it’s negative. Durations too long to count
+    /// in milliseconds are treated as the longest one that can be.
+    pub fn format(&self, duration: Duration) -> String {
+        let (seconds, milliseconds) = duration.lengths();
+        self.format_milliseconds(seconds.saturating_mul(1000).saturating_add(milliseconds as i64))
+    }
+
+    /// Writes the time `then` relative to the time `now`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    /// use datetime::cldr::Locale;
+    /// use datetime::relative::RelativeFormat;
+    ///
+    /// let format = RelativeFormat::new(Locale::from_tag("fr").unwrap());
+    /// assert_eq!(format.format_between(Instant::at(1_000), Instant::at(4_600)), "il y a 1 heure");
+    /// ```
+    pub fn format_between(&self, then: Instant, now: Instant) -> String {
+        let seconds = then.seconds().saturating_sub(now.seconds());
+        let milliseconds = then.milliseconds() as i64 - now.milliseconds() as i64;
+        self.format_milliseconds(seconds.saturating_mul(1000).saturating_add(milliseconds))
+    }
+
+    /// Returns the unit that a time the given number of milliseconds away
+    /// is written in, and how many of that unit it is, rounded, with the
+    /// same sign as the time.
+    pub fn unit_and_count(&self, milliseconds: i64) -> (RelativeUnit, i64) {
+        let length = milliseconds.checked_abs().unwrap_or(i64::max_value());
+
+        for &unit in UNITS.iter() {
+            let unit_length = unit.seconds() * 1000;
+            let count = length / unit_length + (length % unit_length * 2 >= unit_length) as i64;
+
+            if unit == RelativeUnit::Year || count < self.thresholds[unit as usize] {
+                return (unit, if milliseconds < 0 { -count } else { count });
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn format_milliseconds(&self, milliseconds: i64) -> String {
+        let (unit, count) = self.unit_and_count(milliseconds);
+
+        if self.style == RelativeStyle::Idiomatic {
+            if let Some(idiom) = self.locale.relative_idiom(unit, count) {
+                return idiom.to_string();
+            }
+        }
+
+        let number = count.abs() as u64;
+        let pattern = self.locale.relative_time_pattern(unit, milliseconds >= 0, self.locale.plural(number));
+        pattern.replace("{0}", &number.to_string())
+    }
+}
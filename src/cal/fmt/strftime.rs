@@ -0,0 +1,226 @@
+//! Translating POSIX and GNU `strftime` format strings, such as
+//! `%Y-%m-%d %H:%M:%S %z`, into `DateFormat` fields, and reading values
+//! with them, as `strptime` does.
+//!
+//! Numeric directives are padded the same way as in C: `%d` gives `07`,
+//! `%e` gives ` 7`, and `%Y` gives `0044`. A `-` flag after the `%`
+//! turns the padding off, `_` pads with spaces, `0` pads with zeros, and a
+//! number gives the width, so `%-d` gives `7` and `%_3j` gives ` 34`. The
+//! `:` flag only goes with `%z`, and adds colons to the offset.
+//!
+//! Names and numbers use the C locale’s formats, so `%c` is
+//! `%a %b %e %H:%M:%S %Y`, and `%x` and `%X` are `%m/%d/%y` and
+//! `%H:%M:%S`. Directives that have no equivalent field, such as `%C` or
+//! `%P`, are errors, rather than being written out as they are.
+//!
+//! When reading, whitespace in the format matches any amount of
+//! whitespace in the input, including none, and padded numbers can leave
+//! out their padding, so `%d` reads both `07` and `7`. Padded numbers
+//! can’t be any longer than their width, so `%Y%m%d` reads `20240203`.
+
+use cal::datetime::Weekday;
+use cal::fmt::custom::{DateFormat, Field, FormatError, Arguments, NumArguments, TextArguments, Parsed, ParseError, LocaleNames};
+
+use pad::Alignment;
+
+
+impl<'a> DateFormat<'a> {
+
+    /// Translates a `strftime` format string into a `DateFormat`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// extern crate datetime;
+    /// extern crate locale;
+    /// use datetime::fmt::DateFormat;
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+    ///
+    /// # fn main() {
+    /// let format = DateFormat::from_strftime("%a %-d %B %Y, %H:%M").unwrap();
+    /// let datetime = LocalDateTime::new(LocalDate::ymd(2024, Month::February, 3).unwrap(), LocalTime::hms(15, 4, 5).unwrap());
+    ///
+    /// assert_eq!(format.format(&datetime, &locale::Time::english()), "Sat 3 February 2024, 15:04");
+    /// # }
+    /// ```
+    pub fn from_strftime(input: &'a str) -> Result<DateFormat<'a>, FormatError> {
+        let mut fields = Vec::new();
+        let mut literal_start = 0;
+        let mut iter = input.char_indices().peekable();
+
+        while let Some((pos, c)) = iter.next() {
+            if c != '%' {
+                continue;
+            }
+
+            push_literal(&mut fields, &input[literal_start .. pos]);
+
+            let mut padding = Padding::Default;
+            let mut width = None;
+            let mut colons = false;
+
+            // Flags and a width come between the `%` and the directive.
+            loop {
+                match iter.peek().cloned() {
+                    Some((_, '-'))                    => padding = Padding::None,
+                    Some((_, '_'))                    => padding = Padding::Spaces,
+                    Some((_, '0')) if width.is_none() => padding = Padding::Zeros,
+                    Some((_, ':'))                    => colons = true,
+                    Some((_, n)) if n.is_digit(10) => {
+                        width = Some(width.unwrap_or(0) * 10 + n.to_digit(10).unwrap() as usize);
+                    },
+                    _ => break,
+                }
+
+                let _ = iter.next();
+            }
+
+            let (directive_pos, directive) = match iter.next() {
+                Some(next)  => next,
+                None        => return Err(FormatError::UnfinishedDirective { pos: pos }),
+            };
+
+            if colons && directive != 'z' {
+                return Err(FormatError::UnsupportedDirective { c: ':', pos: pos });
+            }
+
+            let literal_end = directive_pos + directive.len_utf8();
+
+            // Some directives are shorthand for a sequence of others.
+            let expansion = match directive {
+                'c'        => Some("%a %b %e %H:%M:%S %Y"),
+                'D' | 'x'  => Some("%m/%d/%y"),
+                'F'        => Some("%Y-%m-%d"),
+                'r'        => Some("%I:%M:%S %p"),
+                'R'        => Some("%H:%M"),
+                'T' | 'X'  => Some("%H:%M:%S"),
+                _          => None,
+            };
+
+            if let Some(expansion) = expansion {
+                fields.extend(try!(DateFormat::from_strftime(expansion)).fields);
+                literal_start = literal_end;
+                continue;
+            }
+
+            let num = |default_width, default_pad| NumArguments(padding.arguments(width, default_width, default_pad));
+            let text = || TextArguments(padding.arguments(width, 0, ' '));
+
+            let field = match directive {
+                'a' => Field::WeekdayName(false, text()),
+                'A' => Field::WeekdayName(true, text()),
+                'b' | 'h' => Field::MonthName(false, text()),
+                'B' => Field::MonthName(true, text()),
+                'd' => Field::Day(num(2, '0')),
+                'e' => Field::Day(num(2, ' ')),
+                'G' => Field::WeekYear(num(4, '0')),
+                'H' => Field::Hour(num(2, '0')),
+                'I' => Field::Hour12(num(2, '0')),
+                'j' => Field::YearDay(num(3, '0')),
+                'k' => Field::Hour(num(2, ' ')),
+                'l' => Field::Hour12(num(2, ' ')),
+                'm' => Field::MonthNumber(num(2, '0')),
+                'M' => Field::Minute(num(2, '0')),
+                'n' => Field::Whitespace("\n"),
+                'p' => Field::AmPm(text()),
+                's' => Field::Timestamp(num(0, '0')),
+                'S' => Field::Second(num(2, '0')),
+                't' => Field::Whitespace("\t"),
+                'u' => Field::WeekdayNumber(true, num(0, '0')),
+                'U' => Field::WeekOfYear(Weekday::Sunday, num(2, '0')),
+                'V' => Field::Week(num(2, '0')),
+                'w' => Field::WeekdayNumber(false, num(0, '0')),
+                'W' => Field::WeekOfYear(Weekday::Monday, num(2, '0')),
+                'y' => Field::YearOfCentury(num(2, '0')),
+                'Y' => Field::Year(num(4, '0')),
+                'z' => Field::UtcOffset(colons, text()),
+                'Z' => Field::ZoneAbbreviation(text()),
+                '%' => Field::Literal(&input[directive_pos .. literal_end]),
+                c   => return Err(FormatError::UnsupportedDirective { c: c, pos: pos }),
+            };
+
+            fields.push(field);
+            literal_start = literal_end;
+        }
+
+        push_literal(&mut fields, &input[literal_start ..]);
+        Ok(DateFormat { fields: fields })
+    }
+}
+
+
+/// Reads a value from the input using a `strftime` format string, as
+/// `strptime` does, returning the fields that were read.
+///
+/// ### Examples
+///
+/// ```
+/// extern crate datetime;
+/// extern crate locale;
+/// use datetime::strftime::strptime;
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset};
+///
+/// # fn main() {
+/// let parsed = strptime("2024-02-03 15:04:05 +0530", "%Y-%m-%d %H:%M:%S %z", &locale::Time::english()).unwrap();
+///
+/// let local = LocalDateTime::new(LocalDate::ymd(2024, Month::February, 3).unwrap(), LocalTime::hms(15, 4, 5).unwrap());
+/// let offset = Offset::of_hours_and_minutes(5, 30).unwrap();
+/// assert_eq!(parsed.to_offset_date_time(Offset::utc()), Ok(offset.transform_date(local)));
+/// # }
+/// ```
+pub fn strptime<L: LocaleNames+?Sized>(input: &str, format: &str, locale: &L) -> Result<Parsed, ParseError> {
+    let format = try!(DateFormat::from_strftime(format).map_err(ParseError::Format));
+    format.parse_value(input, locale)
+}
+
+
+/// How a directive’s number should be padded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Padding {
+    Default,
+    None,
+    Spaces,
+    Zeros,
+}
+
+impl Padding {
+
+    /// Returns the arguments for a field, given the width and padding
+    /// that it has by default.
+    fn arguments(self, width: Option<usize>, default_width: usize, default_pad: char) -> Arguments {
+        let pad_char = match self {
+            Padding::Default | Padding::None  => default_pad,
+            Padding::Spaces                   => ' ',
+            Padding::Zeros                    => '0',
+        };
+
+        let width = match (self, width) {
+            (_, Some(width))       => width,
+            (Padding::None, None)  => 0,
+            (_, None)              => default_width,
+        };
+
+        if width == 0 {
+            Arguments::empty()
+        }
+        else {
+            Arguments {
+                alignment: Some(Alignment::Right),
+                width:     Some(width),
+                pad_char:  Some(pad_char),
+            }
+        }
+    }
+}
+
+/// Adds the literal text between directives to the fields, with any runs
+/// of whitespace in fields of their own.
+fn push_literal<'a>(fields: &mut Vec<Field<'a>>, mut literal: &'a str) {
+    while !literal.is_empty() {
+        let is_space = literal.starts_with(char::is_whitespace);
+        let length = literal.find(|c: char| c.is_whitespace() != is_space).unwrap_or(literal.len());
+
+        fields.push(if is_space { Field::Whitespace(&literal[.. length]) } else { Field::Literal(&literal[.. length]) });
+        literal = &literal[length ..];
+    }
+}
@@ -0,0 +1,257 @@
+//! Formatting dates and times in the styles that each locale prefers,
+//! from short ones such as `1/31/24` to full ones such as
+//! `Wednesday, January 31, 2024`, using the patterns in the CLDR data.
+//!
+//! The full and long time styles include the time zone. Values that know
+//! their time zone show its abbreviation, values that only know their
+//! offset show that, such as `+01:00`, and values that know neither leave
+//! that part of the pattern out.
+
+use cal::{DatePiece, TimePiece, ZonePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday};
+use cal::offset::Offset;
+use cal::fmt::cldr::{Locale, Patterns};
+use cal::fmt::custom::DateFormat;
+
+
+/// How much detail to give when writing a date.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum DateStyle {
+
+    /// Just numbers, such as `1/31/24` or `31.01.24`.
+    Short,
+
+    /// An abbreviated month name, such as `Jan 31, 2024`.
+    Medium,
+
+    /// The full month name, such as `January 31, 2024`.
+    Long,
+
+    /// The month name and the weekday, such as `Wednesday, January 31, 2024`.
+    Full,
+}
+
+/// How much detail to give when writing a time.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TimeStyle {
+
+    /// Hours and minutes, such as `3:04 PM` or `15:04`.
+    Short,
+
+    /// Hours, minutes, and seconds, such as `3:04:05 PM`.
+    Medium,
+
+    /// Seconds and the time zone, such as `3:04:05 PM GMT`.
+    Long,
+
+    /// Seconds and the time zone, in the way the locale writes its full
+    /// name. As the library doesn’t have the full names of time zones,
+    /// this uses the abbreviation, as the long style does.
+    Full,
+}
+
+/// A style for writing a date and a time together.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DateTimeStyle {
+    pub date: DateStyle,
+    pub time: TimeStyle,
+}
+
+impl DateStyle {
+
+    /// Returns the LDML pattern that the locale uses for dates in this
+    /// style.
+    pub fn pattern(self, locale: &Locale) -> &'static str {
+        pick(locale.date_patterns(), self as usize)
+    }
+
+    /// Writes the date part of a value in this style, using the locale’s
+    /// pattern and names.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, DateStyle};
+    /// use datetime::cldr::Locale;
+    ///
+    /// let date = LocalDate::ymd(2024, Month::January, 31).unwrap();
+    /// assert_eq!(DateStyle::Short.format(&date, Locale::from_tag("en-US").unwrap()), "1/31/24");
+    /// assert_eq!(DateStyle::Short.format(&date, Locale::from_tag("de-DE").unwrap()), "31.01.24");
+    /// assert_eq!(DateStyle::Long.format(&date, Locale::from_tag("fr").unwrap()), "31 janvier 2024");
+    /// ```
+    pub fn format<T: DatePiece>(self, when: &T, locale: &Locale) -> String {
+        let local = LocalDateTime::new(date_of(when), LocalTime::midnight());
+        format_pattern(self.pattern(locale), &Value::local(local), locale)
+    }
+
+    /// Returns the style for writing a date in this style together with a
+    /// time in the given style.
+    pub fn with_time(self, time: TimeStyle) -> DateTimeStyle {
+        DateTimeStyle { date: self, time: time }
+    }
+}
+
+impl TimeStyle {
+
+    /// Returns the LDML pattern that the locale uses for times in this
+    /// style.
+    pub fn pattern(self, locale: &Locale) -> &'static str {
+        pick(locale.time_patterns(), self as usize)
+    }
+
+    /// Writes the time part of a value in this style, using the locale’s
+    /// pattern and names.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalTime, TimeStyle};
+    /// use datetime::cldr::Locale;
+    ///
+    /// let time = LocalTime::hms(15, 4, 5).unwrap();
+    /// assert_eq!(TimeStyle::Short.format(&time, Locale::from_tag("en-GB").unwrap()), "15:04");
+    /// assert_eq!(TimeStyle::Medium.format(&time, Locale::from_tag("de").unwrap()), "15:04:05");
+    /// ```
+    pub fn format<T: TimePiece+ZonePiece>(self, when: &T, locale: &Locale) -> String {
+        let local = LocalDateTime::new(LocalDate::ymd(1970, Month::January, 1).unwrap(), time_of(when));
+        let value = Value::zoned(local, when);
+        format_pattern(&value.zone_pattern(self.pattern(locale)), &value, locale)
+    }
+}
+
+impl DateTimeStyle {
+
+    /// Returns the LDML pattern that the locale uses for dates and times
+    /// in this style, which puts the date pattern and the time pattern
+    /// together using the locale’s pattern for the date style.
+    pub fn pattern(self, locale: &Locale) -> String {
+        pick(locale.date_time_patterns(), self.date as usize)
+            .replace("{1}", self.date.pattern(locale))
+            .replace("{0}", self.time.pattern(locale))
+    }
+
+    /// Writes a value in this style, using the locale’s pattern and names.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, DateStyle, TimeStyle};
+    /// use datetime::cldr::Locale;
+    ///
+    /// let datetime = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 31).unwrap(), LocalTime::hms(15, 4, 5).unwrap());
+    /// let style = DateStyle::Medium.with_time(TimeStyle::Short);
+    /// assert_eq!(style.format(&datetime, Locale::from_tag("de").unwrap()), "31.01.2024, 15:04");
+    /// ```
+    pub fn format<T: DatePiece+TimePiece+ZonePiece>(self, when: &T, locale: &Locale) -> String {
+        let local = LocalDateTime::new(date_of(when), time_of(when));
+        let value = Value::zoned(local, when);
+        format_pattern(&value.zone_pattern(&self.pattern(locale)), &value, locale)
+    }
+}
+
+
+/// Returns the pattern for a style, given its position in the enum, which
+/// goes from the shortest to the longest.
+fn pick(patterns: &Patterns, style: usize) -> &'static str {
+    [ patterns.short, patterns.medium, patterns.long, patterns.full ][style]
+}
+
+/// Formats a value with an LDML pattern from the locale data, which has to
+/// be one the formatter understands.
+fn format_pattern(pattern: &str, value: &Value, locale: &Locale) -> String {
+    DateFormat::from_ldml(pattern).unwrap().format(value, locale)  // The patterns are all tested
+}
+
+fn date_of<T: DatePiece>(when: &T) -> LocalDate {
+    LocalDate::ymd(when.year(), when.month(), when.day()).unwrap()
+}
+
+fn time_of<T: TimePiece>(when: &T) -> LocalTime {
+    LocalTime::hms_ms(when.hour(), when.minute(), when.second(), when.millisecond()).unwrap()
+}
+
+
+/// A value being formatted in a style, with whichever of its date and
+/// time was missing filled in, and whatever it knows about its time zone.
+struct Value {
+    local: LocalDateTime,
+    offset: Option<Offset>,
+    abbreviation: Option<String>,
+}
+
+impl Value {
+    fn local(local: LocalDateTime) -> Value {
+        Value { local: local, offset: None, abbreviation: None }
+    }
+
+    fn zoned<Z: ZonePiece>(local: LocalDateTime, zone: &Z) -> Value {
+        Value { local: local, offset: zone.utc_offset(), abbreviation: zone.zone_abbreviation() }
+    }
+
+    /// Rewrites the time zone part of a pattern to fit what’s known about
+    /// the value’s time zone: its abbreviation, its offset, or nothing, in
+    /// which case the time zone is left out, along with the spaces and
+    /// brackets around it.
+    fn zone_pattern(&self, pattern: &str) -> String {
+        let replacement = match (&self.abbreviation, self.offset) {
+            (&Some(_), _)     => "z",
+            (&None, Some(_))  => "xxx",
+            (&None, None)     => "",
+        };
+
+        let mut output = String::new();
+        let mut quoted = false;
+        let mut chars = pattern.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                quoted = !quoted;
+            }
+            else if c == 'z' && !quoted {
+                while chars.peek() == Some(&'z') {
+                    let _ = chars.next();
+                }
+
+                if replacement.is_empty() {
+                    let trimmed = output.trim_right_matches(is_zone_surrounding).len();
+                    output.truncate(trimmed);
+                    while chars.peek().map_or(false, |&c| is_zone_surrounding(c)) {
+                        let _ = chars.next();
+                    }
+                }
+
+                output.push_str(replacement);
+                continue;
+            }
+
+            output.push(c);
+        }
+
+        output
+    }
+}
+
+/// Whether a character can be left out along with the time zone.
+fn is_zone_surrounding(c: char) -> bool {
+    c.is_whitespace() || c == '(' || c == ')'
+}
+
+impl DatePiece for Value {
+    fn year(&self) -> i64 { self.local.year() }
+    fn month(&self) -> Month { self.local.month() }
+    fn day(&self) -> i8 { self.local.day() }
+    fn yearday(&self) -> i16 { self.local.yearday() }
+    fn weekday(&self) -> Weekday { self.local.weekday() }
+}
+
+impl TimePiece for Value {
+    fn hour(&self) -> i8 { self.local.hour() }
+    fn minute(&self) -> i8 { self.local.minute() }
+    fn second(&self) -> i8 { self.local.second() }
+    fn millisecond(&self) -> i16 { self.local.millisecond() }
+}
+
+impl ZonePiece for Value {
+    fn utc_offset(&self) -> Option<Offset> { self.offset }
+    fn zone_abbreviation(&self) -> Option<String> { self.abbreviation.clone() }
+}
@@ -0,0 +1,338 @@
+//! Spans of time between two points on the timeline.
+
+use std::cmp::{min, max};
+use std::iter::FromIterator;
+use std::slice::Iter as SliceIter;
+
+use cal::datetime::{LocalDate, LocalDateTime, Error};
+use duration::Duration;
+use instant::Instant;
+
+
+/// An **interval** is a span of time between two points, such as two
+/// instants or two dates.
+///
+/// Intervals are *half-open*: they include their start, but not their end.
+/// This means that two intervals where one ends as the other begins don’t
+/// overlap, which is usually what you want for things like bookings, and
+/// that an interval from a point to the same point is empty.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Interval<T> {
+    start: T,
+    end:   T,
+}
+
+impl<T: Ord + Copy> Interval<T> {
+
+    /// Creates a new interval from the given start and end points,
+    /// returning an error if the end is before the start.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{Instant, Interval};
+    ///
+    /// let morning = Interval::new(Instant::at(0), Instant::at(3600)).unwrap();
+    /// assert!(morning.contains(Instant::at(0)));
+    /// assert!(!morning.contains(Instant::at(3600)));
+    ///
+    /// assert!(Interval::new(Instant::at(3600), Instant::at(0)).is_err());
+    /// ```
+    pub fn new(start: T, end: T) -> Result<Interval<T>, Error> {
+        if start <= end {
+            Ok(Interval { start: start, end: end })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns the point at which this interval starts.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// Returns the point at which this interval ends, which isn’t itself
+    /// part of the interval.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Returns whether this interval contains no points at all.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns whether the given point falls within this interval.
+    pub fn contains(&self, point: T) -> bool {
+        point >= self.start && point < self.end
+    }
+
+    /// Returns whether this interval and the other one have any points in
+    /// common. Intervals that only touch at one end don’t overlap, and
+    /// empty intervals don’t overlap anything.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.is_empty() && !other.is_empty()
+            && self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the interval of the points that are in both this interval
+    /// and the other one, if they overlap.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) {
+            Some(Interval { start: max(self.start, other.start), end: min(self.end, other.end) })
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the interval that covers both this interval and the other
+    /// one, if they overlap or touch. If there’s a gap between them, no
+    /// single interval can cover them, so this returns `None`.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.start <= other.end && other.start <= self.end {
+            Some(Interval { start: min(self.start, other.start), end: max(self.end, other.end) })
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the interval between this interval and the other one, if
+    /// there’s a gap between them.
+    pub fn gap(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.end < other.start {
+            Some(Interval { start: self.end, end: other.start })
+        }
+        else if other.end < self.start {
+            Some(Interval { start: other.end, end: self.start })
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl Interval<Instant> {
+
+    /// Returns the length of this interval.
+    pub fn duration(&self) -> Duration {
+        milliseconds_to_duration(milliseconds_between(self.start, self.end))
+    }
+}
+
+impl Interval<LocalDateTime> {
+
+    /// Returns the length of this interval, ignoring any time zone changes
+    /// that may occur within it.
+    pub fn duration(&self) -> Duration {
+        milliseconds_to_duration(milliseconds_between(self.start.to_instant(), self.end.to_instant()))
+    }
+}
+
+impl Interval<LocalDate> {
+
+    /// Returns the length of this interval, as a whole number of days.
+    pub fn duration(&self) -> Duration {
+        Duration::of(self.days() * 86400)
+    }
+
+    /// Returns the number of days in this interval.
+    pub fn days(&self) -> i64 {
+        self.end.to_rata_die() - self.start.to_rata_die()
+    }
+}
+
+/// Returns the number of milliseconds from one instant to another.
+fn milliseconds_between(start: Instant, end: Instant) -> i64 {
+    (end.seconds() - start.seconds()) * 1000 + (end.milliseconds() - start.milliseconds()) as i64
+}
+
+/// Converts a non-negative number of milliseconds into a duration.
+fn milliseconds_to_duration(milliseconds: i64) -> Duration {
+    Duration::of_ms(milliseconds / 1000, (milliseconds % 1000) as i16)
+}
+
+
+/// An **interval set** is a collection of intervals, kept sorted, with
+/// any intervals that overlap or touch merged together, and any empty
+/// intervals removed.
+///
+/// ### Examples
+///
+/// ```rust
+/// use datetime::{Instant, Interval, IntervalSet};
+///
+/// let span = |start, end| Interval::new(Instant::at(start), Instant::at(end)).unwrap();
+///
+/// let booked: IntervalSet<_> = vec![ span(9, 10), span(14, 16), span(10, 11) ].into_iter().collect();
+/// assert_eq!(booked.intervals(), &[ span(9, 11), span(14, 16) ]);
+///
+/// let free = IntervalSet::from(span(8, 18)).difference(&booked);
+/// assert_eq!(free.intervals(), &[ span(8, 9), span(11, 14), span(16, 18) ]);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+
+    /// Creates a new empty interval set.
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    /// Returns the disjoint intervals in this set, in order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Returns an iterator over the disjoint intervals in this set, in
+    /// order.
+    pub fn iter(&self) -> SliceIter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Returns whether this set contains no points at all.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns whether the given point falls within any of the intervals in
+    /// this set.
+    pub fn contains(&self, point: T) -> bool {
+        self.intervals.iter().any(|i| i.contains(point))
+    }
+
+    /// Adds an interval to this set, merging it with any intervals that it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let mut merged = interval;
+        let mut result = Vec::with_capacity(self.intervals.len() + 1);
+        let mut inserted = false;
+
+        for existing in self.intervals.drain(..) {
+            if let Some(union) = merged.union(&existing) {
+                merged = union;
+            }
+            else if existing.end < merged.start {
+                result.push(existing);
+            }
+            else {
+                if !inserted {
+                    result.push(merged);
+                    inserted = true;
+                }
+
+                result.push(existing);
+            }
+        }
+
+        if !inserted {
+            result.push(merged);
+        }
+
+        self.intervals = result;
+    }
+
+    /// Removes every point in the given interval from this set, splitting
+    /// any intervals that it falls in the middle of.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let mut result = Vec::with_capacity(self.intervals.len() + 1);
+
+        for existing in self.intervals.drain(..) {
+            if !existing.overlaps(&interval) {
+                result.push(existing);
+                continue;
+            }
+
+            if existing.start < interval.start {
+                result.push(Interval { start: existing.start, end: interval.start });
+            }
+
+            if interval.end < existing.end {
+                result.push(Interval { start: interval.end, end: existing.end });
+            }
+        }
+
+        self.intervals = result;
+    }
+
+    /// Returns the set of points that are in either this set or the other.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.insert(*interval);
+        }
+
+        result
+    }
+
+    /// Returns the set of points that are in this set but not the other.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.remove(*interval);
+        }
+
+        result
+    }
+
+    /// Returns the set of points that are in both this set and the other.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        for a in &self.intervals {
+            for b in &other.intervals {
+                if let Some(both) = a.intersection(b) {
+                    result.insert(both);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Ord + Copy> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=Interval<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+
+        set
+    }
+}
+
+impl<'a, T: Ord + Copy> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = SliceIter<'a, Interval<T>>;
+
+    fn into_iter(self) -> SliceIter<'a, Interval<T>> {
+        self.intervals.iter()
+    }
+}
@@ -0,0 +1,154 @@
+//! ISO 8601 time intervals and repeating intervals.
+//!
+//! These are the textual forms such as `2024-01-01/2024-01-08`,
+//! `2024-01-01T09:00:00Z/PT1H`, and `R5/2024-01-01/P1D`. Parsing them is
+//! done in the `parse` module, and formatting them with the `ISO` trait.
+
+use cal::datetime::Error;
+use cal::interval::Interval;
+use cal::period::{Period, PeriodArithmetic};
+
+
+/// An ISO 8601 **time interval**, described by two of its start, end, and
+/// length.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum IsoInterval<T> {
+
+    /// An interval given by its start and end, such as
+    /// `2024-01-01/2024-01-08`.
+    StartEnd(T, T),
+
+    /// An interval given by its start and length, such as
+    /// `2024-01-01/P1W`.
+    StartPeriod(T, Period),
+
+    /// An interval given by its length and end, such as
+    /// `P1W/2024-01-08`.
+    PeriodEnd(Period, T),
+}
+
+impl<T: PeriodArithmetic + Ord + Copy> IsoInterval<T> {
+
+    /// Returns the point at which this interval starts.
+    pub fn start(&self) -> T {
+        match *self {
+            IsoInterval::StartEnd(start, _)       => start,
+            IsoInterval::StartPeriod(start, _)    => start,
+            IsoInterval::PeriodEnd(period, end)   => end.add_period(&-period),
+        }
+    }
+
+    /// Returns the point at which this interval ends.
+    pub fn end(&self) -> T {
+        match *self {
+            IsoInterval::StartEnd(_, end)           => end,
+            IsoInterval::StartPeriod(start, period) => start.add_period(&period),
+            IsoInterval::PeriodEnd(_, end)          => end,
+        }
+    }
+
+    /// Converts this interval into an `Interval` between its start and end
+    /// points, returning an error if the end is before the start.
+    pub fn to_interval(&self) -> Result<Interval<T>, Error> {
+        Interval::new(self.start(), self.end())
+    }
+}
+
+
+/// An ISO 8601 **repeating interval**, such as `R5/2024-01-01/P1D`, which
+/// describes a series of intervals that each follow on from the last.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct RepeatingInterval<T> {
+
+    /// The number of intervals in the series, or `None` if it repeats
+    /// forever.
+    pub repetitions: Option<u32>,
+
+    /// The first interval in the series. If it’s given by its length and
+    /// end, this is the *last* interval instead, and the series runs
+    /// backwards in time from there.
+    pub interval: IsoInterval<T>,
+}
+
+impl<T: PeriodArithmetic + Ord + Copy> RepeatingInterval<T> {
+
+    /// Returns an iterator over the intervals in this series.
+    ///
+    /// Each interval’s start is computed by adding a whole multiple of the
+    /// period to the first one’s start, rather than by adding the period
+    /// over and over again, so a monthly series that begins on the 31st
+    /// returns to the 31st whenever the month has one.
+    ///
+    /// Parsing only accepts series whose intervals end after they start.
+    /// For a series built by hand whose period goes backwards, this stops
+    /// at the first interval, as it would end before it starts, and for
+    /// one whose period is zero, it returns the same empty interval each
+    /// time.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, RepeatingInterval};
+    ///
+    /// let series: RepeatingInterval<LocalDate> = "R3/2024-01-31/P1M".parse().unwrap();
+    /// let starts: Vec<_> = series.occurrences().map(|i| i.start()).collect();
+    ///
+    /// assert_eq!(starts, vec![
+    ///     LocalDate::ymd(2024, Month::January, 31).unwrap(),
+    ///     LocalDate::ymd(2024, Month::February, 29).unwrap(),
+    ///     LocalDate::ymd(2024, Month::March, 31).unwrap(),
+    /// ]);
+    /// ```
+    pub fn occurrences(&self) -> Occurrences<T> {
+        let (anchor, period, backwards) = match self.interval {
+            IsoInterval::StartEnd(start, end)       => (start, start.period_until(&end), false),
+            IsoInterval::StartPeriod(start, period) => (start, period, false),
+            IsoInterval::PeriodEnd(period, end)     => (end, period, true),
+        };
+
+        Occurrences {
+            anchor:     anchor,
+            period:     period,
+            backwards:  backwards,
+            index:      0,
+            remaining:  self.repetitions,
+        }
+    }
+}
+
+
+/// Iterator over the intervals in a `RepeatingInterval`.
+#[derive(Debug, Clone)]
+pub struct Occurrences<T> {
+    anchor:     T,
+    period:     Period,
+    backwards:  bool,
+    index:      i64,
+    remaining:  Option<u32>,
+}
+
+impl<T: PeriodArithmetic + Ord + Copy> Iterator for Occurrences<T> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Interval<T>> {
+        if let Some(remaining) = self.remaining {
+            if remaining == 0 {
+                return None;
+            }
+
+            self.remaining = Some(remaining - 1);
+        }
+
+        let index = self.index;
+        self.index += 1;
+
+        let (start, end) = if self.backwards {
+            (self.anchor.add_period(&(self.period * -(index + 1))), self.anchor.add_period(&(self.period * -index)))
+        }
+        else {
+            (self.anchor.add_period(&(self.period * index)), self.anchor.add_period(&(self.period * (index + 1))))
+        };
+
+        Interval::new(start, end).ok()
+    }
+}
@@ -1,12 +1,24 @@
 //! ISO-8601 date and time calculations, which use years, months, days,
 //! hours, minutes, and seconds.
 
+pub mod calendars;
 pub mod datetime;
 pub mod fmt;
 pub mod offset;
 pub mod parse;
+pub mod period;
+pub mod recurrence;
+pub mod rfc2822;
+pub mod rfc3339;
 pub mod zone;
 pub mod convenience;
+pub mod cron;
+pub mod easter;
+pub mod interval;
+pub mod iso_interval;
+pub mod time_range;
+pub mod truncate;
+pub mod vtimezone;
 
 pub use self::datetime::{LocalDate, LocalTime, LocalDateTime, Weekday, Month};
 pub use self::datetime::{YearMonth, Year};
@@ -68,3 +80,17 @@ pub trait TimePiece {
     /// The millisecond of the second.
     fn millisecond(&self) -> i16;
 }
+
+
+/// The **zone piece** trait is used for date and time values that can
+/// know where in the world they are, through their offset from UTC or
+/// their time zone. Values that know neither use the default methods,
+/// which return nothing.
+pub trait ZonePiece {
+
+    /// The offset from UTC, if it’s known.
+    fn utc_offset(&self) -> Option<Offset> { None }
+
+    /// The abbreviation of the time zone, such as “BST”, if it’s known.
+    fn zone_abbreviation(&self) -> Option<String> { None }
+}
@@ -1,35 +1,40 @@
 //! Datetimes with a fixed UTC offset.
 
+use std::cmp::Ordering;
 use std::error::Error as ErrorTrait;
 use std::fmt;
 
 use duration::Duration;
-use cal::{DatePiece, TimePiece};
+use cal::{DatePiece, TimePiece, ZonePiece};
 use cal::datetime::{LocalDateTime, Month, Weekday, Error as DateTimeError};
 use cal::fmt::ISO;
 use util::RangeExt;
 
 
-#[derive(PartialEq, Copy, Clone)]
+#[derive(PartialEq, Eq, Copy, Clone)]
 pub struct Offset {
     offset_seconds: Option<i32>,
+
+    /// Whether this is the *unknown local offset*, which has the same UTC
+    /// time as `+00:00` but says nothing about where it was recorded.
+    is_unknown: bool,
 }
 
 impl Offset {
-    fn adjust(&self, local: LocalDateTime) -> LocalDateTime {
-        match self.offset_seconds {
-            Some(s) => local + Duration::of(s as i64),
-            None    => local,
-        }
+    pub fn utc() -> Offset {
+        Offset { offset_seconds: None, is_unknown: false }
     }
 
-    pub fn utc() -> Offset {
-        Offset { offset_seconds: None }
+    /// Returns the **unknown local offset**, written as `-00:00` in RFC
+    /// 3339 or `-0000` in RFC 2822. The time it gives is in UTC, but the
+    /// offset of the place it applies to isn’t known.
+    pub fn unknown() -> Offset {
+        Offset { offset_seconds: Some(0), is_unknown: true }
     }
 
     pub fn of_seconds(seconds: i32) -> Result<Offset, Error> {
         if seconds.is_within(-86400..86401) {
-            Ok(Offset { offset_seconds: Some(seconds) })
+            Ok(Offset { offset_seconds: Some(seconds), is_unknown: false })
         }
         else {
             Err(Error::OutOfRange)
@@ -65,6 +70,10 @@ impl Offset {
         self.offset_seconds.is_none()
     }
 
+    pub fn is_unknown(&self) -> bool {
+        self.is_unknown
+    }
+
     pub fn is_negative(&self) -> bool {
         self.hours().is_negative() || self.minutes().is_negative() || self.seconds().is_negative()
     }
@@ -130,49 +139,91 @@ impl ErrorTrait for Error {
 }
 
 
-#[derive(PartialEq, Copy, Clone)]
+#[derive(PartialEq, Eq, Copy, Clone)]
 pub struct OffsetDateTime {
+
+    /// The wall-clock date and time, as it was read in the offset.
     pub local: LocalDateTime,
+
     pub offset: Offset,
 }
 
+impl OffsetDateTime {
+
+    /// Returns the local time in UTC, by taking away the offset.
+    fn utc(&self) -> LocalDateTime {
+        match self.offset.offset_seconds {
+            Some(s) => self.local - Duration::of(s as i64),
+            None    => self.local,
+        }
+    }
+}
+
+/// Datetimes are ordered by the instant they refer to, and then by their
+/// offset, so that the same instant in different offsets is never equal.
+/// The unknown offset comes after `+00:00`.
+impl PartialOrd for OffsetDateTime {
+    fn partial_cmp(&self, other: &OffsetDateTime) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OffsetDateTime {
+    fn cmp(&self, other: &OffsetDateTime) -> Ordering {
+        self.utc().cmp(&other.utc())
+            .then(self.offset.offset_seconds.cmp(&other.offset.offset_seconds))
+            .then(self.offset.is_unknown.cmp(&other.offset.is_unknown))
+    }
+}
+
+/// The date fields are those of the wall-clock datetime in `local`, so
+/// `10:00 +05:30` is still on the day it was written on, rather than having
+/// the offset added to it a second time.
 impl DatePiece for OffsetDateTime {
     fn year(&self) -> i64 {
-        self.offset.adjust(self.local).year()
+        self.local.year()
     }
 
     fn month(&self) -> Month {
-        self.offset.adjust(self.local).month()
+        self.local.month()
     }
 
     fn day(&self) -> i8 {
-        self.offset.adjust(self.local).day()
+        self.local.day()
     }
 
     fn yearday(&self) -> i16 {
-        self.offset.adjust(self.local).yearday()
+        self.local.yearday()
     }
 
     fn weekday(&self) -> Weekday {
-        self.offset.adjust(self.local).weekday()
+        self.local.weekday()
     }
 }
 
+/// The time fields are those of the wall-clock datetime in `local`, so
+/// `10:00 +05:30` has an hour of 10.
 impl TimePiece for OffsetDateTime {
     fn hour(&self) -> i8 {
-        self.offset.adjust(self.local).hour()
+        self.local.hour()
     }
 
     fn minute(&self) -> i8 {
-        self.offset.adjust(self.local).minute()
+        self.local.minute()
     }
 
     fn second(&self) -> i8 {
-        self.offset.adjust(self.local).second()
+        self.local.second()
     }
 
     fn millisecond(&self) -> i16 {
-        self.offset.adjust(self.local).millisecond()
+        self.local.millisecond()
+    }
+}
+
+impl ZonePiece for OffsetDateTime {
+    fn utc_offset(&self) -> Option<Offset> {
+        Some(self.offset)
     }
 }
 
@@ -235,6 +286,13 @@ mod test {
         assert_eq!(debugged, "Offset(-00:25:21)");
     }
 
+    #[test]
+    fn debug_unknown() {
+        let offset = Offset::unknown();
+        let debugged = format!("{:?}", offset);
+        assert_eq!(debugged, "Offset(-00:00)");
+    }
+
     #[test]
     fn debug_offset_date_time() {
         use cal::{LocalDate, LocalTime, LocalDateTime, Month};
@@ -1,105 +1,602 @@
+//! Parsing ISO 8601 dates, times, durations, and intervals.
+//!
+//! Dates can be in the extended format, such as `2024-01-31`, or the basic
+//! format, such as `20240131`, and can be calendar dates, week dates like
+//! `2024-W05-3`, or ordinal dates like `2024-031`. Dates with reduced
+//! precision, such as `2024-01` or `2024-W05`, are taken to be the first
+//! day of the month, week, or year they refer to. Years outside `0000` to
+//! `9999` need a sign, as in `+012024-01-31`; these expanded years can
+//! have up to nine digits, and in the basic format they always have six.
+//!
+//! Times can be given to the hour, minute, or second, and the smallest
+//! unit can have a decimal fraction, so `T10.5` means half past ten.
+//! Fractions are kept to the millisecond, with any further digits
+//! dropped. The time `24:00` means the end of the day, which is the same
+//! as midnight at the start of the next one.
+
 use std::error::Error as ErrorTrait;
 use std::fmt;
 use std::str::FromStr;
 
-use iso8601;
-
-use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Error as DateTimeError};
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year, Error as DateTimeError};
+use cal::iso_interval::{IsoInterval, RepeatingInterval};
 use cal::offset::{Offset, OffsetDateTime, Error as OffsetError};
+use cal::period::{Period, PeriodArithmetic};
 
 
 impl FromStr for LocalDate {
     type Err = Error<DateTimeError>;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        match iso8601::date(input) {
-            Ok(fields)  => fields_to_date(fields).map_err(Error::Date),
-            Err(e)      => Err(Error::Parse(e)),
-        }
+        let mut cursor = Cursor::new(input);
+        let fields = try!(parse_date(&mut cursor));
+        try!(cursor.expect_end());
+        fields_to_date(fields).map_err(Error::Date)
     }
 }
 
 impl FromStr for LocalTime {
     type Err = Error<DateTimeError>;
 
+    /// Parses a time, which can start with a `T`. Any offset at the end is
+    /// checked, but then ignored.
     fn from_str(input: &str) -> Result<LocalTime, Self::Err> {
-        match iso8601::time(input) {
-            Ok(fields)  => fields_to_time(fields).map_err(Error::Date),
-            Err(e)      => Err(Error::Parse(e)),
-        }
+        let mut cursor = Cursor::new(input);
+        let _ = cursor.eat(b"T");
+        let fields = try!(parse_time(&mut cursor));
+        let _ = try!(parse_offset(&mut cursor));
+        try!(cursor.expect_end());
+        fields_to_time(fields).map_err(Error::Date)
     }
 }
 
 impl FromStr for LocalDateTime {
     type Err = Error<DateTimeError>;
 
+    /// Parses a datetime. Any offset at the end is checked, but then
+    /// ignored.
     fn from_str(input: &str) -> Result<LocalDateTime, Self::Err> {
-        let fields = match iso8601::datetime(input) {
-            Ok(fields)  => fields,
-            Err(e)      => return Err(Error::Parse(e)),
-        };
+        let mut cursor = Cursor::new(input);
+        let (date, time) = try!(parse_date_time(&mut cursor));
+        let _ = try!(parse_offset(&mut cursor));
+        try!(cursor.expect_end());
 
-        let date = try!(fields_to_date(fields.date).map_err(Error::Date));
-        let time = try!(fields_to_time(fields.time).map_err(Error::Date));
-        Ok(LocalDateTime::new(date, time))
+        let date = try!(fields_to_date(date).map_err(Error::Date));
+        let time = try!(fields_to_time(time).map_err(Error::Date));
+        Ok(end_of_day(date, time))
     }
 }
 
 impl FromStr for OffsetDateTime {
     type Err = Error<OffsetError>;
 
+    /// Parses a datetime with an offset, which can’t be left out.
     fn from_str(input: &str) -> Result<OffsetDateTime, Self::Err> {
-        let fields = match iso8601::datetime(input) {
-            Ok(fields)  => fields,
-            Err(e)      => return Err(Error::Parse(e)),
+        let mut cursor = Cursor::new(input);
+        let (date, time) = try!(parse_date_time(&mut cursor));
+        let offset = match try!(parse_offset(&mut cursor)) {
+            Some(offset)  => offset,
+            None          => return Err(cursor.error("an offset")),
         };
+        try!(cursor.expect_end());
 
-        let date   = try!(fields_to_date(fields.date).map_err(|e| Error::Date(OffsetError::Date(e))));
-        let time   = try!(fields_to_time(fields.time).map_err(|e| Error::Date(OffsetError::Date(e))));
-        let offset = try!(Offset::of_hours_and_minutes(fields.time.tz_offset_hours as i8, fields.time.tz_offset_minutes as i8).map_err(Error::Date));
-        Ok(offset.transform_date(LocalDateTime::new(date, time)))
+        let date = try!(fields_to_date(date).map_err(|e| Error::Date(OffsetError::Date(e))));
+        let time = try!(fields_to_time(time).map_err(|e| Error::Date(OffsetError::Date(e))));
+        let offset = try!(fields_to_offset(offset).map_err(Error::Date));
+        Ok(offset.transform_date(end_of_day(date, time)))
     }
 }
 
+impl FromStr for Period {
+    type Err = Error<DateTimeError>;
+
+    fn from_str(input: &str) -> Result<Period, Self::Err> {
+        parse_period(input)
+    }
+}
+
+impl<T, E> FromStr for IsoInterval<T>
+where T: FromStr<Err=Error<E>>, E: ErrorTrait {
+    type Err = Error<E>;
+
+    fn from_str(input: &str) -> Result<IsoInterval<T>, Self::Err> {
+        let mut parts = input.splitn(2, '/');
+        let first = parts.next().unwrap();
+        let second = match parts.next() {
+            Some(second)  => second,
+            None          => return Err(Error::Parse { position: input.len(), expected: "'/'" }),
+        };
+
+        // Errors in the second part are reported from the start of the
+        // whole input, or from the start of the part if it was completed
+        // using the first one.
+        let second_start = first.len() + 1;
+        let shift = |e: Error<E>| e.shifted(second_start);
+
+        if first.starts_with('P') {
+            Ok(IsoInterval::PeriodEnd(try!(parse_period(first)), try!(second.parse().map_err(shift))))
+        }
+        else if second.starts_with('P') {
+            Ok(IsoInterval::StartPeriod(try!(first.parse()), try!(parse_period(second).map_err(shift))))
+        }
+        else {
+            let end = complete_interval_end(first, second);
+            let end = try!(end.parse().map_err(|e| if end == second { shift(e) } else { e.moved_to(second_start) }));
+            Ok(IsoInterval::StartEnd(try!(first.parse()), end))
+        }
+    }
+}
+
+impl<T, E> FromStr for RepeatingInterval<T>
+where T: FromStr<Err=Error<E>> + PeriodArithmetic + Ord + Copy, E: ErrorTrait {
+    type Err = Error<E>;
+
+    fn from_str(input: &str) -> Result<RepeatingInterval<T>, Self::Err> {
+        if !input.starts_with('R') {
+            return Err(Error::Parse { position: 0, expected: "'R'" });
+        }
+
+        let slash = try!(input.find('/').ok_or(Error::Parse { position: input.len(), expected: "'/'" }));
+        let repetitions = match &input[1 .. slash] {
+            ""      => None,
+            number  => Some(try!(number.parse().map_err(|_| Error::Parse { position: 1, expected: "a number of repetitions" }))),
+        };
+
+        let interval: IsoInterval<T> = try!(input[slash + 1 ..].parse().map_err(|e: Error<E>| e.shifted(slash + 1)));
+
+        // A series of intervals without any length would never move on.
+        if interval.end() <= interval.start() {
+            return Err(Error::Parse { position: slash + 1, expected: "an interval that ends after it starts" });
+        }
+
+        Ok(RepeatingInterval {
+            repetitions: repetitions,
+            interval:    interval,
+        })
+    }
+}
+
+
+/// Parses an ISO 8601 duration, such as `P1Y2M3DT4H5M6.5S`, into a
+/// period. Weeks are turned into days, and only the seconds can have a
+/// decimal fraction, which is kept to the millisecond.
+fn parse_period<E: ErrorTrait>(input: &str) -> Result<Period, Error<E>> {
+    let invalid = |rest: &str| Error::Parse { position: input.len() - rest.len(), expected: "a duration" };
+
+    if !input.starts_with('P') {
+        return Err(invalid(input));
+    }
+
+    let mut period = Period::zero();
+    let mut rest = &input[1..];
+    let mut in_time = false;
+    let mut last_position = 0;
+
+    while !rest.is_empty() {
+        if rest.starts_with('T') && !in_time {
+            in_time = true;
+            rest = &rest[1..];
+            if rest.is_empty() { return Err(invalid(rest)) }
+            continue;
+        }
+
+        let element = rest;
+
+        let number_length = rest.find(|c: char| !(c.is_digit(10) || c == '.' || c == ',')).unwrap_or(rest.len());
+        if number_length == 0 || number_length == rest.len() {
+            return Err(invalid(element));
+        }
+
+        let (number, designator) = (&rest[.. number_length], rest[number_length ..].chars().next().unwrap());
+        rest = &rest[number_length + designator.len_utf8() ..];
 
-fn fields_to_date(fields: iso8601::Date) -> Result<LocalDate, DateTimeError> {
-    if let iso8601::Date::YMD { year, month, day } = fields {
-        let month_variant = try!(Month::from_one(month as i8));
-        LocalDate::ymd(year as i64, month_variant, day as i8)
+        // Each designator can only appear once, in order.
+        let position = match (in_time, designator) {
+            (false, 'Y') => 1,  (false, 'M') => 2,  (false, 'W') => 3,  (false, 'D') => 4,
+            (true,  'H') => 5,  (true,  'M') => 6,  (true,  'S') => 7,
+            _            => return Err(invalid(element)),
+        };
+
+        if position <= last_position {
+            return Err(invalid(element));
+        }
+
+        last_position = position;
+
+        let (whole, fraction) = match number.find(|c| c == '.' || c == ',') {
+            Some(point)  => (&number[.. point], Some(&number[point + 1 ..])),
+            None         => (number, None),
+        };
+
+        let value: i64 = try!(whole.parse().map_err(|_| invalid(element)));
+
+        match position {
+            1 => period.years   = value,
+            2 => period.months  = value,
+            3 => period.days   += value * 7,
+            4 => period.days   += value,
+            5 => period.hours   = value,
+            6 => period.minutes = value,
+            _ => period.seconds = value,
+        }
+
+        if let Some(fraction) = fraction {
+            // Only the seconds can have a fraction, and it must be last.
+            if position != 7 || !rest.is_empty() || fraction.is_empty() || fraction.contains(|c| c == '.' || c == ',') {
+                return Err(invalid(element));
+            }
+
+            let digits: String = fraction.chars().chain("00".chars()).take(3).collect();
+            period.milliseconds = try!(digits.parse().map_err(|_| invalid(element)));
+        }
     }
-    else if let iso8601::Date::Week { year, ww, d } = fields {
-        let weekday_variant = try!(Weekday::from_one(d as i8));
-        LocalDate::ywd(year as i64, ww as i64, weekday_variant)
+
+    if last_position == 0 {
+        return Err(invalid(rest));
     }
-    else if let iso8601::Date::Ordinal { year, ddd } = fields {
-        LocalDate::yd(year as i64, ddd as i64)
+
+    Ok(period)
+}
+
+/// Fills in the components that have been left out of the end of an
+/// interval, such as the `15` in `2024-02-01/15`, using the start.
+///
+/// ISO 8601 allows the end to omit any of its higher-order components, so
+/// the end’s date is lined up against the end of the start’s date, and a
+/// missing time or offset is taken from the start.
+fn complete_interval_end(start: &str, end: &str) -> String {
+    let (start_date, start_time) = split_date_time(start);
+    let (end_date, end_time) = if end.contains('T') { split_date_time(end) }
+                          else if end.contains(':') { ("", Some(end)) }
+                                               else { (end, None) };
+
+    let mut completed = if end_date.len() < start_date.len() && start_date.is_char_boundary(start_date.len() - end_date.len()) {
+        format!("{}{}", &start_date[.. start_date.len() - end_date.len()], end_date)
     }
     else {
-        unreachable!()  // should be unnecessary??
+        end_date.to_owned()
+    };
+
+    match (end_time, start_time) {
+        (Some(end_time), Some(start_time)) => {
+            completed.push('T');
+            completed.push_str(end_time);
+
+            if split_offset(end_time).1.is_empty() {
+                completed.push_str(split_offset(start_time).1);
+            }
+        },
+        (Some(time), None) | (None, Some(time)) => {
+            completed.push('T');
+            completed.push_str(time);
+        },
+        (None, None) => {},
+    }
+
+    completed
+}
+
+/// Splits a datetime string into its date and time parts, either side of
+/// the `T`.
+fn split_date_time(input: &str) -> (&str, Option<&str>) {
+    match input.find('T') {
+        Some(t)  => (&input[.. t], Some(&input[t + 1 ..])),
+        None     => (input, None),
+    }
+}
+
+/// Splits a time string into the time itself and its UTC offset, if it
+/// has one.
+fn split_offset(time: &str) -> (&str, &str) {
+    match time.find(|c| c == 'Z' || c == '+' || c == '-') {
+        Some(index)  => (&time[.. index], &time[index ..]),
+        None         => (time, ""),
     }
 }
 
-fn fields_to_time(fields: iso8601::Time) -> Result<LocalTime, DateTimeError> {
-    let h  = fields.hour as i8;
-    let m  = fields.minute as i8;
-    let s  = fields.second as i8;
-    let ms = fields.millisecond as i16;
 
-    LocalTime::hms_ms(h, m, s, ms)
+/// The most digits that an expanded year can have.
+const MAX_YEAR_DIGITS: usize = 9;
+
+/// The position that parsing has reached in the input.
+struct Cursor<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Cursor<'a> {
+        Cursor { input: input.as_bytes(), position: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).cloned()
+    }
+
+    /// Moves past the next byte if it’s one of the given ones.
+    fn eat(&mut self, any_of: &[u8]) -> bool {
+        match self.peek() {
+            Some(b) if any_of.contains(&b) => { self.position += 1; true },
+            _                              => false,
+        }
+    }
+
+    /// Returns the number of digits from the current position onwards.
+    fn digits_ahead(&self) -> usize {
+        self.input[self.position ..].iter().take_while(|b| b.is_ascii_digit()).count()
+    }
+
+    /// Reads a number with exactly the given number of digits.
+    fn digits<E: ErrorTrait>(&mut self, count: usize, expected: &'static str) -> Result<i64, Error<E>> {
+        if self.digits_ahead() < count {
+            return Err(self.error(expected));
+        }
+
+        let number = self.input[self.position .. self.position + count].iter().fold(0, |n, &b| n * 10 + (b - b'0') as i64);
+        self.position += count;
+        Ok(number)
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    fn expect_end<E: ErrorTrait>(&self) -> Result<(), Error<E>> {
+        if self.is_at_end() { Ok(()) } else { Err(self.error("the end of the input")) }
+    }
+
+    fn error<E: ErrorTrait>(&self, expected: &'static str) -> Error<E> {
+        Error::Parse { position: self.position, expected: expected }
+    }
+}
+
+
+/// The fields of a date, before they’re checked.
+enum DateFields {
+    Calendar { year: i64, month: i64, day: i64 },
+    Week { year: i64, week: i64, weekday: i64 },
+    Ordinal { year: i64, day: i64 },
+}
+
+/// The fields of a time, before they’re checked, with any decimal fraction
+/// turned into a number of milliseconds to add.
+struct TimeFields {
+    hour: i64,
+    minute: i64,
+    second: i64,
+    fraction_ms: i64,
+}
+
+/// The fields of an offset, before they’re checked.
+enum OffsetFields {
+    Utc,
+    HoursMinutes(i64, i64),
+}
+
+/// Parses a date and a time, separated by a `T`.
+fn parse_date_time<E: ErrorTrait>(cursor: &mut Cursor) -> Result<(DateFields, TimeFields), Error<E>> {
+    let date = try!(parse_date(cursor));
+
+    if !cursor.eat(b"T") {
+        return Err(cursor.error("'T'"));
+    }
+
+    let time = try!(parse_time(cursor));
+    Ok((date, time))
+}
+
+/// Parses a calendar, week, or ordinal date, in the basic or extended
+/// format, with reduced precision allowed.
+fn parse_date<E: ErrorTrait>(cursor: &mut Cursor) -> Result<DateFields, Error<E>> {
+    let sign = match cursor.peek() {
+        Some(b'+')  => { cursor.position += 1; 1 },
+        Some(b'-')  => { cursor.position += 1; -1 },
+        _           => 0,
+    };
+
+    // Expanded years have as many digits as they need in the extended
+    // format, but always six in the basic one.
+    let year_digits = match (sign, cursor.digits_ahead()) {
+        (0, _)                                                                    => 4,
+        (_, n) if n < 4                                                           => 4,
+        (_, n) if cursor.input.get(cursor.position + n) == Some(&b'-') || n <= 6  => n,
+        (_, _)                                                                    => 6,
+    };
+
+    // Any more digits and the number of seconds since the epoch would get
+    // too close to overflowing.
+    if year_digits > MAX_YEAR_DIGITS {
+        return Err(cursor.error("a year with at most nine digits"));
+    }
+
+    let year = try!(cursor.digits(year_digits, "a year")) * if sign == 0 { 1 } else { sign };
+
+    let extended = cursor.eat(b"-");
+
+    if cursor.eat(b"W") {
+        let week = try!(cursor.digits(2, "a week number"));
+
+        let has_weekday = if extended { cursor.eat(b"-") } else { cursor.digits_ahead() > 0 };
+        let weekday = if has_weekday { try!(cursor.digits(1, "a day of the week")) } else { 1 };
+        return Ok(DateFields::Week { year: year, week: week, weekday: weekday });
+    }
+
+    match (extended, cursor.digits_ahead()) {
+        (_, 3)       => Ok(DateFields::Ordinal { year: year, day: try!(cursor.digits(3, "a day of the year")) }),
+        (true, 2)    => {
+            let month = try!(cursor.digits(2, "a month"));
+            let day = if cursor.eat(b"-") { try!(cursor.digits(2, "a day")) } else { 1 };
+            Ok(DateFields::Calendar { year: year, month: month, day: day })
+        },
+        (false, 4)   => {
+            let month = try!(cursor.digits(2, "a month"));
+            let day = try!(cursor.digits(2, "a day"));
+            Ok(DateFields::Calendar { year: year, month: month, day: day })
+        },
+        (false, 0)   => Ok(DateFields::Calendar { year: year, month: 1, day: 1 }),
+        (true, _)    => Err(cursor.error("a month, week, or day of the year")),
+        (false, _)   => Err(cursor.error("'-', a week, or a day of the year")),
+    }
+}
+
+/// Parses a time to the hour, minute, or second, in the basic or extended
+/// format, with a decimal fraction allowed on the last one.
+fn parse_time<E: ErrorTrait>(cursor: &mut Cursor) -> Result<TimeFields, Error<E>> {
+    let hour = try!(cursor.digits(2, "an hour"));
+    let mut fields = TimeFields { hour: hour, minute: 0, second: 0, fraction_ms: 0 };
+    let mut unit_ms = 3_600_000;
+
+    let extended = cursor.eat(b":");
+    if extended || cursor.digits_ahead() > 0 {
+        fields.minute = try!(cursor.digits(2, "a minute"));
+        unit_ms = 60_000;
+
+        if (extended && cursor.eat(b":")) || (!extended && cursor.digits_ahead() > 0) {
+            fields.second = try!(cursor.digits(2, "a second"));
+            unit_ms = 1_000;
+        }
+    }
+
+    if cursor.eat(b".,") {
+        let count = cursor.digits_ahead();
+        if count == 0 {
+            return Err(cursor.error("a digit"));
+        }
+
+        // Only the first nine digits make a difference to the result.
+        let significant = if count > 9 { 9 } else { count };
+        let fraction = try!(cursor.digits(significant, "a digit"));
+        cursor.position += count - significant;
+
+        fields.fraction_ms = fraction * unit_ms / 10_i64.pow(significant as u32);
+    }
+
+    Ok(fields)
+}
+
+/// Parses a `Z` or a numeric offset, if there is one.
+fn parse_offset<E: ErrorTrait>(cursor: &mut Cursor) -> Result<Option<OffsetFields>, Error<E>> {
+    let sign = match cursor.peek() {
+        Some(b'Z')  => { cursor.position += 1; return Ok(Some(OffsetFields::Utc)) },
+        Some(b'+')  => 1,
+        Some(b'-')  => -1,
+        _           => return Ok(None),
+    };
+
+    cursor.position += 1;
+    let hours = try!(cursor.digits(2, "offset hours"));
+
+    let minutes = if cursor.eat(b":") || cursor.digits_ahead() > 0 {
+        try!(cursor.digits(2, "offset minutes"))
+    }
+    else {
+        0
+    };
+
+    Ok(Some(OffsetFields::HoursMinutes(sign * hours, sign * minutes)))
+}
+
+
+fn fields_to_date(fields: DateFields) -> Result<LocalDate, DateTimeError> {
+    match fields {
+        DateFields::Calendar { year, month, day } => {
+            let month_variant = try!(Month::from_one(month as i8));
+            LocalDate::ymd(year, month_variant, day as i8)
+        },
+
+        DateFields::Week { year, week, weekday } => {
+            let weekday_variant = try!(Weekday::from_one(weekday as i8));
+
+            // Only years that start or end on a Thursday have a 53rd week.
+            if week < 1 || week > 53 || (week == 53 && try!(LocalDate::ywd(year, 53, Weekday::Thursday)).year() != year) {
+                return Err(DateTimeError::OutOfRange);
+            }
+
+            LocalDate::ywd(year, week, weekday_variant)
+        },
+
+        DateFields::Ordinal { year, day } => {
+            let days_in_year = if Year(year).is_leap_year() { 366 } else { 365 };
+            if day < 1 || day > days_in_year {
+                return Err(DateTimeError::OutOfRange);
+            }
+
+            LocalDate::yd(year, day)
+        },
+    }
+}
+
+fn fields_to_time(fields: TimeFields) -> Result<LocalTime, DateTimeError> {
+    if fields.hour == 24 && fields.minute == 0 && fields.second == 0 && fields.fraction_ms == 0 {
+        return LocalTime::hms(24, 0, 0);
+    }
+    else if fields.hour >= 24 || fields.minute >= 60 || fields.second >= 60 {
+        return Err(DateTimeError::OutOfRange);
+    }
+
+    let ms = fields.hour * 3_600_000 + fields.minute * 60_000 + fields.second * 1_000 + fields.fraction_ms;
+    LocalTime::hms_ms((ms / 3_600_000) as i8, (ms / 60_000 % 60) as i8, (ms / 1_000 % 60) as i8, (ms % 1_000) as i16)
+}
+
+fn fields_to_offset(fields: OffsetFields) -> Result<Offset, OffsetError> {
+    match fields {
+        OffsetFields::Utc                          => Ok(Offset::utc()),
+        OffsetFields::HoursMinutes(hours, minutes) => Offset::of_hours_and_minutes(hours as i8, minutes as i8),
+    }
+}
+
+/// Turns the time `24:00` into midnight at the start of the next day.
+fn end_of_day(date: LocalDate, time: LocalTime) -> LocalDateTime {
+    if time.hour() == 24 {
+        LocalDateTime::new(date.add_days(1), LocalTime::midnight())
+    }
+    else {
+        LocalDateTime::new(date, time)
+    }
 }
 
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum Error<E: ErrorTrait> {
+
+    /// The fields were read, but they don’t make a valid value.
     Date(E),
-    Parse(String),
+
+    /// The input isn’t in a format that was understood, starting at the
+    /// given byte position.
+    Parse {
+        position: usize,
+        expected: &'static str,
+    },
+}
+
+impl<E: ErrorTrait> Error<E> {
+
+    /// Moves the position of a parse error along, for when the input that
+    /// was parsed is part of a longer string.
+    fn shifted(self, by: usize) -> Error<E> {
+        match self {
+            Error::Parse { position, expected }  => Error::Parse { position: position + by, expected: expected },
+            error                                => error,
+        }
+    }
+
+    /// Moves a parse error to the given position, for when the input that
+    /// was parsed had to be rewritten first.
+    fn moved_to(self, position: usize) -> Error<E> {
+        match self {
+            Error::Parse { expected, .. }  => Error::Parse { position: position, expected: expected },
+            error                          => error,
+        }
+    }
 }
 
 impl<E: ErrorTrait> fmt::Display for Error<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Error::Date(ref error)   => write!(f, "{}: {}", self.description(), error),
-            Error::Parse(ref string) => write!(f, "{}: {}", self.description(), string),
+            Error::Date(ref error)                   => write!(f, "{}: {}", self.description(), error),
+            Error::Parse { position, expected }      => write!(f, "{} at byte {}: expected {}", self.description(), position, expected),
         }
     }
 }
@@ -107,15 +604,15 @@ impl<E: ErrorTrait> fmt::Display for Error<E> {
 impl<E: ErrorTrait> ErrorTrait for Error<E> {
     fn description(&self) -> &str {
         match *self {
-            Error::Date(_)     => "parsing resulted in an invalid date",
-            Error::Parse(_)    => "parse error",
+            Error::Date(_)         => "parsing resulted in an invalid date",
+            Error::Parse { .. }    => "parse error",
         }
     }
 
     fn cause(&self) -> Option<&ErrorTrait> {
         match *self {
             Error::Date(ref error)   => Some(error),
-            Error::Parse(_)          => None,
+            Error::Parse { .. }      => None,
         }
     }
 }
@@ -0,0 +1,177 @@
+//! Calendar-aware lengths of time, such as “one month” or “two days”.
+
+use std::cmp::min;
+use std::ops::{Mul, Neg};
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, LocalDateTime, Month, Year, split_cycles};
+use cal::offset::OffsetDateTime;
+
+
+/// Number of milliseconds in a day.
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+
+/// A **period** is a length of time measured in calendar units, as in an
+/// ISO 8601 duration such as `P1Y2M3DT4H`.
+///
+/// Unlike a `Duration`, a period doesn’t have a fixed length: one month
+/// can be anywhere from 28 to 31 days long, depending on which month it’s
+/// added to. The fields are kept separate so that adding a period to a
+/// datetime can respect the calendar.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Period {
+    pub years:        i64,
+    pub months:       i64,
+    pub days:         i64,
+    pub hours:        i64,
+    pub minutes:      i64,
+    pub seconds:      i64,
+    pub milliseconds: i64,
+}
+
+impl Period {
+
+    /// Creates a new period with every field set to zero.
+    pub fn zero() -> Period {
+        Period::default()
+    }
+
+    /// Creates a new period of the given number of days.
+    pub fn of_days(days: i64) -> Period {
+        Period { days: days, ..Period::default() }
+    }
+
+    /// Creates a new period of the given number of months.
+    pub fn of_months(months: i64) -> Period {
+        Period { months: months, ..Period::default() }
+    }
+
+    /// Returns whether every field of this period is zero.
+    pub fn is_zero(&self) -> bool {
+        *self == Period::zero()
+    }
+
+    /// Returns the total number of milliseconds in the hours, minutes,
+    /// seconds, and milliseconds fields.
+    fn time_milliseconds(&self) -> i64 {
+        ((self.hours * 60 + self.minutes) * 60 + self.seconds) * 1000 + self.milliseconds
+    }
+}
+
+impl Mul<i64> for Period {
+    type Output = Period;
+
+    fn mul(self, amount: i64) -> Period {
+        Period {
+            years:         self.years * amount,
+            months:        self.months * amount,
+            days:          self.days * amount,
+            hours:         self.hours * amount,
+            minutes:       self.minutes * amount,
+            seconds:       self.seconds * amount,
+            milliseconds:  self.milliseconds * amount,
+        }
+    }
+}
+
+impl Neg for Period {
+    type Output = Period;
+
+    fn neg(self) -> Period {
+        self * -1
+    }
+}
+
+
+/// Values that a `Period` can be added to, and measured between.
+pub trait PeriodArithmetic: Sized {
+
+    /// Adds the period to this value. The years and months are added
+    /// first, with the day of the month clamped to the end of the month if
+    /// it would overflow, then the days, and then the rest.
+    fn add_period(&self, period: &Period) -> Self;
+
+    /// Returns the exact period from this value to the other one, as a
+    /// number of days and milliseconds, without any years or months.
+    fn period_until(&self, other: &Self) -> Period;
+}
+
+impl PeriodArithmetic for LocalDate {
+
+    /// Adds the period to this date. Any hours, minutes, or seconds in the
+    /// period are rounded down to a whole number of days.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, Period, PeriodArithmetic};
+    ///
+    /// let date = LocalDate::ymd(2024, Month::January, 31).unwrap();
+    /// assert_eq!(date.add_period(&Period::of_months(1)), LocalDate::ymd(2024, Month::February, 29).unwrap());
+    /// ```
+    fn add_period(&self, period: &Period) -> LocalDate {
+        let extra_days = split_cycles(period.time_milliseconds(), MILLISECONDS_IN_DAY).0;
+        add_months(*self, period.years * 12 + period.months).add_days(period.days + extra_days)
+    }
+
+    fn period_until(&self, other: &LocalDate) -> Period {
+        Period::of_days(other.to_rata_die() - self.to_rata_die())
+    }
+}
+
+impl PeriodArithmetic for LocalDateTime {
+    fn add_period(&self, period: &Period) -> LocalDateTime {
+        let date = add_months(self.date(), period.years * 12 + period.months).add_days(period.days);
+        let moved = LocalDateTime::new(date, self.time());
+
+        let instant = moved.to_instant();
+        let milliseconds = instant.seconds() * 1000 + instant.milliseconds() as i64 + period.time_milliseconds();
+        let (seconds, milliseconds) = split_cycles(milliseconds, 1000);
+        LocalDateTime::at_ms(seconds, milliseconds as i16)
+    }
+
+    fn period_until(&self, other: &LocalDateTime) -> Period {
+        let (start, end) = (self.to_instant(), other.to_instant());
+        let milliseconds = (end.seconds() - start.seconds()) * 1000 + (end.milliseconds() - start.milliseconds()) as i64;
+
+        Period {
+            days:          milliseconds / MILLISECONDS_IN_DAY,
+            milliseconds:  milliseconds % MILLISECONDS_IN_DAY,
+            ..Period::default()
+        }
+    }
+}
+
+impl PeriodArithmetic for OffsetDateTime {
+
+    /// Adds the period to the local time of this datetime, keeping the
+    /// same offset.
+    fn add_period(&self, period: &Period) -> OffsetDateTime {
+        self.offset.transform_date(self.local.add_period(period))
+    }
+
+    fn period_until(&self, other: &OffsetDateTime) -> Period {
+        let offset_difference = other.offset.hours() as i64 * 3600 + other.offset.minutes() as i64 * 60 + other.offset.seconds() as i64
+                              - (self.offset.hours() as i64 * 3600 + self.offset.minutes() as i64 * 60 + self.offset.seconds() as i64);
+
+        let mut period = self.local.period_until(&other.local);
+        period.milliseconds -= offset_difference * 1000;
+        period
+    }
+}
+
+
+/// Adds a number of months to a date, clamping the day to the end of the
+/// resulting month if necessary.
+fn add_months(date: LocalDate, months: i64) -> LocalDate {
+    if months == 0 {
+        return date;
+    }
+
+    let total = date.year() * 12 + date.month().months_from_january() as i64 + months;
+    let (year, month) = split_cycles(total, 12);
+    let month = Month::from_zero(month as i8).unwrap();
+    let day = min(date.day(), month.days_in_month(Year(year).is_leap_year()));
+    LocalDate::ymd(year, month, day).unwrap()
+}
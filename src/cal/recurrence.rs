@@ -0,0 +1,1077 @@
+//! Recurring events, described using iCalendar recurrence rules.
+//!
+//! RFC 5545 describes a series of events with a **recurrence rule**, such
+//! as `FREQ=MONTHLY;BYDAY=-1FR` for “the last Friday of every month”,
+//! along with the date and time of the first event. This module parses
+//! these rules and expands them into the datetimes of each event, one at a
+//! time, so a rule that repeats forever can still be used.
+
+use std::collections::VecDeque;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year, split_cycles};
+use cal::zone::{TimeZone, ZonedDateTime};
+use instant::Instant;
+
+
+/// The number of periods in a row that can produce no events before a rule
+/// is assumed to never produce any more, such as a yearly rule for the
+/// 30th of February.
+const MAX_EMPTY_PERIODS: u32 = 10_000;
+
+
+/// How often a recurrence rule repeats, which is the length of each of
+/// the periods that its events are picked from.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+
+    /// Returns the length of this frequency in milliseconds, if it’s one
+    /// of the frequencies that’s shorter than a day.
+    fn fixed_length(&self) -> Option<i64> {
+        match *self {
+            Frequency::Secondly  => Some(1000),
+            Frequency::Minutely  => Some(60 * 1000),
+            Frequency::Hourly    => Some(60 * 60 * 1000),
+            _                    => None,
+        }
+    }
+}
+
+
+/// A weekday in a `BYDAY` rule part, with an optional ordinal: `MO` means
+/// every Monday, `2MO` means the second Monday, and `-1FR` means the last
+/// Friday, of the month or year.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct ByDay {
+
+    /// Which occurrence of the weekday this is, counting backwards from
+    /// the end if it’s negative, or `None` for every occurrence.
+    pub ordinal: Option<i8>,
+
+    /// The weekday itself.
+    pub weekday: Weekday,
+}
+
+
+/// The point after which a recurrence rule stops.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Until {
+
+    /// A local datetime, in the same time zone as the events.
+    Local(LocalDateTime),
+
+    /// An exact instant, written with a trailing `Z` in a rule.
+    Utc(Instant),
+}
+
+
+/// A **recurrence rule**, as in the `RRULE` property of an iCalendar
+/// event.
+///
+/// The rule picks events out of a series of periods, such as every second
+/// week, using the `by_` fields. Each of these either *expands* the events
+/// in a period, such as listing several days of the week in a weekly rule,
+/// or *limits* them, such as listing months in a daily rule. Empty fields
+/// are ignored, apart from the ones that the rule fills in from the first
+/// event, so that a monthly rule with no days repeats on the same day of
+/// the month.
+///
+/// ### Examples
+///
+/// ```rust
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, RecurrenceRule};
+///
+/// let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=-1FR;COUNT=3".parse().unwrap();
+/// let start = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 1).unwrap(), LocalTime::hm(17, 0).unwrap());
+///
+/// let days: Vec<_> = rule.occurrences(start).map(|e| e.date()).collect();
+/// assert_eq!(days, vec![
+///     LocalDate::ymd(2024, Month::January, 26).unwrap(),
+///     LocalDate::ymd(2024, Month::February, 23).unwrap(),
+///     LocalDate::ymd(2024, Month::March, 29).unwrap(),
+/// ]);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RecurrenceRule {
+    pub frequency:     Frequency,
+    pub interval:      u32,
+    pub count:         Option<u32>,
+    pub until:         Option<Until>,
+    pub by_second:     Vec<i8>,
+    pub by_minute:     Vec<i8>,
+    pub by_hour:       Vec<i8>,
+    pub by_day:        Vec<ByDay>,
+    pub by_month_day:  Vec<i8>,
+    pub by_year_day:   Vec<i16>,
+    pub by_week_no:    Vec<i8>,
+    pub by_month:      Vec<Month>,
+    pub by_set_pos:    Vec<i16>,
+    pub week_start:    Weekday,
+}
+
+impl RecurrenceRule {
+
+    /// Creates a new rule that repeats at the given frequency forever,
+    /// with no other rule parts.
+    pub fn new(frequency: Frequency) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency:     frequency,
+            interval:      1,
+            count:         None,
+            until:         None,
+            by_second:     Vec::new(),
+            by_minute:     Vec::new(),
+            by_hour:       Vec::new(),
+            by_day:        Vec::new(),
+            by_month_day:  Vec::new(),
+            by_year_day:   Vec::new(),
+            by_week_no:    Vec::new(),
+            by_month:      Vec::new(),
+            by_set_pos:    Vec::new(),
+            week_start:    Weekday::Monday,
+        }
+    }
+
+    /// Returns an iterator over the datetimes of the events described by
+    /// this rule, where the first event is at the given datetime.
+    ///
+    /// Events before the start are never returned, and neither is the
+    /// start itself unless it matches the rule. If the rule has a `Utc`
+    /// end point, the events are compared to it as though they were in
+    /// UTC; use `zoned_occurrences` to compare them properly.
+    pub fn occurrences(&self, start: LocalDateTime) -> Occurrences<'_> {
+        Occurrences::new(self, start, None)
+    }
+
+    /// Returns an iterator over the events described by this rule in the
+    /// given time zone, where the first event is at the given local
+    /// datetime.
+    ///
+    /// The rule is expanded using local times, so an event at 09:00 stays
+    /// at 09:00 when the clocks change. Times that fall in a gap or an
+    /// overlap are resolved with `TimeZone::resolve_local`: an impossible
+    /// time is moved forward by the length of the gap, and an ambiguous
+    /// time uses the first of its two offsets.
+    pub fn zoned_occurrences<'a>(&self, start: LocalDateTime, zone: &'a TimeZone) -> Zoned<'a, Occurrences<'a>> {
+        Zoned { inner: Occurrences::new(self, start, Some(zone)), zone: zone }
+    }
+
+    /// Returns a copy of this rule with the parts that are taken from the
+    /// first event filled in, and the lists of times sorted.
+    fn with_defaults(&self, start: LocalDateTime) -> RecurrenceRule {
+        let mut rule = self.clone();
+
+        if rule.by_week_no.is_empty() && rule.by_year_day.is_empty()
+        && rule.by_month_day.is_empty() && rule.by_day.is_empty() {
+            match rule.frequency {
+                Frequency::Yearly => {
+                    if rule.by_month.is_empty() {
+                        rule.by_month = vec![ start.month() ];
+                    }
+
+                    rule.by_month_day = vec![ start.day() ];
+                },
+                Frequency::Monthly => rule.by_month_day = vec![ start.day() ],
+                Frequency::Weekly  => rule.by_day = vec![ ByDay { ordinal: None, weekday: start.weekday() } ],
+                _                  => {},
+            }
+        }
+
+        if rule.by_hour.is_empty() && rule.frequency > Frequency::Hourly {
+            rule.by_hour = vec![ start.hour() ];
+        }
+
+        if rule.by_minute.is_empty() && rule.frequency > Frequency::Minutely {
+            rule.by_minute = vec![ start.minute() ];
+        }
+
+        if rule.by_second.is_empty() && rule.frequency > Frequency::Secondly {
+            rule.by_second = vec![ start.second() ];
+        }
+
+        rule.by_month.sort();
+        rule.by_month.dedup();
+        rule.by_hour.sort();
+        rule.by_minute.sort();
+        rule.by_second.sort();
+        rule
+    }
+
+    /// Returns whether the given date passes all of this rule’s limits on
+    /// which days can have events.
+    fn matches_day(&self, date: LocalDate) -> bool {
+        let leap_year = Year(date.year()).is_leap_year();
+
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+            return false;
+        }
+
+        if !self.by_week_no.is_empty() {
+            let (week, weeks_in_year) = week_number(date, self.week_start);
+            if !self.by_week_no.iter().any(|&w| w as i64 == week || w as i64 == week - weeks_in_year - 1) {
+                return false;
+            }
+        }
+
+        if !self.by_year_day.is_empty() {
+            let yearday = date.yearday();
+            let days_in_year = if leap_year { 366 } else { 365 };
+            if !self.by_year_day.iter().any(|&d| d == yearday || d == yearday - days_in_year - 1) {
+                return false;
+            }
+        }
+
+        if !self.by_month_day.is_empty() {
+            let day = date.day();
+            let days_in_month = date.month().days_in_month(leap_year);
+            if !self.by_month_day.iter().any(|&d| d == day || d == day - days_in_month - 1) {
+                return false;
+            }
+        }
+
+        if !self.by_day.is_empty() && !self.by_day.iter().any(|by_day| self.matches_weekday(date, by_day)) {
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns whether the given date is the weekday in a `BYDAY` part.
+    /// Ordinals count through the month in monthly rules, or in yearly
+    /// rules that list months, and through the year in other yearly rules.
+    /// They’re ignored for the other frequencies.
+    fn matches_weekday(&self, date: LocalDate, by_day: &ByDay) -> bool {
+        if date.weekday() != by_day.weekday {
+            return false;
+        }
+
+        let ordinal = match by_day.ordinal {
+            Some(ordinal)  => ordinal as i64,
+            None           => return true,
+        };
+
+        let (first, last) = match self.frequency {
+            Frequency::Monthly                                      => month_bounds(date.year(), date.month()),
+            Frequency::Yearly if !self.by_month.is_empty()          => month_bounds(date.year(), date.month()),
+            Frequency::Yearly                                       => year_bounds(date.year()),
+            _                                                       => return true,
+        };
+
+        let day = date.to_rata_die();
+        ordinal == (day - first) / 7 + 1 || ordinal == -((last - day) / 7 + 1)
+    }
+}
+
+
+/// Returns the rata die of the first and last days in the given month.
+fn month_bounds(year: i64, month: Month) -> (i64, i64) {
+    let first = LocalDate::ymd(year, month, 1).unwrap().to_rata_die();
+    (first, first + month.days_in_month(Year(year).is_leap_year()) as i64 - 1)
+}
+
+/// Returns the rata die of the first and last days in the given year.
+fn year_bounds(year: i64) -> (i64, i64) {
+    let first = LocalDate::ymd(year, Month::January, 1).unwrap().to_rata_die();
+    let next  = LocalDate::ymd(year + 1, Month::January, 1).unwrap().to_rata_die();
+    (first, next - 1)
+}
+
+/// Returns the number of days from one weekday forward to another.
+fn days_between(from: Weekday, to: Weekday) -> i64 {
+    ((to as i64 - from as i64) % 7 + 7) % 7
+}
+
+/// Returns the rata die of the first day of week one of the given year,
+/// where weeks begin on the given weekday. As in ISO 8601, week one is the
+/// first week with at least four days in the year.
+fn first_week_start(year: i64, week_start: Weekday) -> i64 {
+    let january_first = LocalDate::ymd(year, Month::January, 1).unwrap();
+    let days_into_week = days_between(week_start, january_first.weekday());
+
+    if days_into_week <= 3 {
+        january_first.to_rata_die() - days_into_week
+    }
+    else {
+        january_first.to_rata_die() + 7 - days_into_week
+    }
+}
+
+/// Returns the week number of the given date, along with the number of
+/// weeks in the year that the week belongs to, which isn’t always the
+/// same as the date’s own year.
+fn week_number(date: LocalDate, week_start: Weekday) -> (i64, i64) {
+    let day = date.to_rata_die();
+
+    let year = if day >= first_week_start(date.year() + 1, week_start) { date.year() + 1 }
+          else if day < first_week_start(date.year(), week_start)      { date.year() - 1 }
+                                                                  else { date.year() };
+
+    let first = first_week_start(year, week_start);
+    ((day - first) / 7 + 1, (first_week_start(year + 1, week_start) - first) / 7)
+}
+
+/// Returns the number of milliseconds since the Unix epoch of a datetime.
+fn to_milliseconds(datetime: LocalDateTime) -> i64 {
+    let instant = datetime.to_instant();
+    instant.seconds() * 1000 + instant.milliseconds() as i64
+}
+
+/// Returns the datetime at the given number of milliseconds since the Unix
+/// epoch.
+fn from_milliseconds(milliseconds: i64) -> LocalDateTime {
+    let (seconds, milliseconds) = split_cycles(milliseconds, 1000);
+    LocalDateTime::at_ms(seconds, milliseconds as i16)
+}
+
+
+/// Iterator over the datetimes of the events described by a recurrence
+/// rule.
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    rule:      RecurrenceRule,
+    start:     LocalDateTime,
+    zone:      Option<&'a TimeZone>,
+    period:    i64,
+    pending:   VecDeque<LocalDateTime>,
+    emitted:   u32,
+    finished:  bool,
+}
+
+impl<'a> Occurrences<'a> {
+    fn new(rule: &RecurrenceRule, start: LocalDateTime, zone: Option<&'a TimeZone>) -> Occurrences<'a> {
+        Occurrences {
+            rule:      rule.with_defaults(start),
+            start:     start,
+            zone:      zone,
+            period:    0,
+            pending:   VecDeque::new(),
+            emitted:   0,
+            finished:  rule.count == Some(0),
+        }
+    }
+
+    /// Returns whether the given event comes after the rule’s end point.
+    fn is_past_until(&self, datetime: LocalDateTime) -> bool {
+        match self.rule.until {
+            None                       => false,
+            Some(Until::Local(until))  => datetime > until,
+            Some(Until::Utc(until))    => match self.zone {
+                Some(zone)  => zone.resolve_local(datetime).to_instant() > until,
+                None        => datetime.to_instant() > until,
+            },
+        }
+    }
+
+    /// Fills the queue with the events from the next period that has any,
+    /// returning `false` if there are too many empty periods in a row.
+    fn fill(&mut self) -> bool {
+        let mut empty_periods = 0;
+
+        while self.pending.is_empty() {
+            if empty_periods == MAX_EMPTY_PERIODS {
+                return false;
+            }
+
+            let mut events = self.expand_period();
+
+            if !self.rule.by_set_pos.is_empty() {
+                let count = events.len() as i64;
+                let mut chosen: Vec<_> = self.rule.by_set_pos.iter().filter_map(|&position| {
+                    let index = if position > 0 { position as i64 - 1 } else { count + position as i64 };
+                    if index >= 0 && index < count { Some(events[index as usize]) } else { None }
+                }).collect();
+
+                chosen.sort();
+                chosen.dedup();
+                events = chosen;
+            }
+
+            let start = self.start;
+            self.pending.extend(events.into_iter().filter(|e| *e >= start));
+            empty_periods += 1;
+        }
+
+        true
+    }
+
+    /// Returns every event in the current period, in order, and moves on
+    /// to the next period.
+    fn expand_period(&mut self) -> Vec<LocalDateTime> {
+        let step = self.period * self.rule.interval as i64;
+        self.period += 1;
+
+        let date = self.start.date();
+        let (days, time) = match self.rule.frequency {
+            Frequency::Yearly => {
+                let year = date.year() + step;
+
+                // Only look at the listed months, if there are any, as the
+                // other days would be thrown away anyway.
+                let days = if self.rule.by_month.is_empty() {
+                    let (first, last) = year_bounds(year);
+                    (first .. last + 1).collect()
+                }
+                else {
+                    self.rule.by_month.iter().flat_map(|&month| {
+                        let (first, last) = month_bounds(year, month);
+                        first .. last + 1
+                    }).collect()
+                };
+
+                (days, None)
+            },
+
+            Frequency::Monthly => {
+                let months = date.year() * 12 + date.month().months_from_january() as i64 + step;
+                let (year, month) = split_cycles(months, 12);
+                let (first, last) = month_bounds(year, Month::from_zero(month as i8).unwrap());
+                ((first .. last + 1).collect(), None)
+            },
+
+            Frequency::Weekly => {
+                let first = date.to_rata_die() - days_between(self.rule.week_start, date.weekday()) + step * 7;
+                ((first .. first + 7).collect(), None)
+            },
+
+            Frequency::Daily => {
+                (vec![ date.to_rata_die() + step ], None)
+            },
+
+            _ => {
+                let length = self.rule.frequency.fixed_length().unwrap();
+                let truncated = split_cycles(to_milliseconds(self.start), length).0 * length;
+                let period_start = from_milliseconds(truncated + step * length);
+
+                // Skip straight past any day that can’t have events, rather
+                // than checking each of its periods.
+                if !self.rule.matches_day(period_start.date()) {
+                    let next_day = to_milliseconds(LocalDateTime::new(period_start.date().add_days(1), LocalTime::midnight()));
+                    let step_length = length * self.rule.interval as i64;
+                    let remaining = next_day - to_milliseconds(period_start);
+                    self.period += (remaining + step_length - 1) / step_length - 1;
+                    return Vec::new();
+                }
+
+                (vec![ period_start.date().to_rata_die() ], Some(period_start.time()))
+            },
+        };
+
+        let times = self.times(time);
+        let mut events = Vec::new();
+
+        for day in days {
+            let day = LocalDate::from_rata_die(day);
+            if !self.rule.matches_day(day) {
+                continue;
+            }
+
+            for time in &times {
+                events.push(LocalDateTime::new(day, *time));
+            }
+        }
+
+        events
+    }
+
+    /// Returns the times of day that events in a period can happen at. In
+    /// periods shorter than a day, the period fixes some of the fields,
+    /// which the rule can then only limit.
+    fn times(&self, period_start: Option<LocalTime>) -> Vec<LocalTime> {
+        let frequency = self.rule.frequency;
+        let pick = |fixed: Option<i8>, list: &Vec<i8>| match fixed {
+            Some(value) if list.is_empty() || list.contains(&value) => vec![ value ],
+            Some(_)                                                 => Vec::new(),
+            None                                                    => list.clone(),
+        };
+
+        let field = |at_most: Frequency, value: fn(&LocalTime) -> i8| {
+            period_start.as_ref().and_then(|t| if frequency <= at_most { Some(value(t)) } else { None })
+        };
+
+        let hours   = pick(field(Frequency::Hourly, LocalTime::hour), &self.rule.by_hour);
+        let minutes = pick(field(Frequency::Minutely, LocalTime::minute), &self.rule.by_minute);
+        let seconds = pick(field(Frequency::Secondly, LocalTime::second), &self.rule.by_second);
+
+        let millisecond = self.start.millisecond();
+        let mut times = Vec::new();
+        for &hour in &hours {
+            for &minute in &minutes {
+                for &second in &seconds {
+                    // A BYSECOND of 60 is for leap seconds, which local
+                    // times can’t have, so those times get skipped.
+                    if let Ok(time) = LocalTime::hms_ms(hour, minute, second, millisecond) {
+                        times.push(time);
+                    }
+                }
+            }
+        }
+
+        times
+    }
+}
+
+impl<'a> Iterator for Occurrences<'a> {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<LocalDateTime> {
+        if self.finished {
+            return None;
+        }
+
+        if self.pending.is_empty() && !self.fill() {
+            self.finished = true;
+            return None;
+        }
+
+        let event = self.pending.pop_front().unwrap();
+        if self.is_past_until(event) {
+            self.finished = true;
+            return None;
+        }
+
+        self.emitted += 1;
+        if self.rule.count == Some(self.emitted) {
+            self.finished = true;
+        }
+
+        Some(event)
+    }
+}
+
+
+/// Iterator adapter that places the local datetimes of events in a time
+/// zone.
+#[derive(Debug, Clone)]
+pub struct Zoned<'a, I> {
+    inner: I,
+    zone:  &'a TimeZone,
+}
+
+impl<'a, I: Iterator<Item=LocalDateTime>> Iterator for Zoned<'a, I> {
+    type Item = ZonedDateTime<'a>;
+
+    fn next(&mut self) -> Option<ZonedDateTime<'a>> {
+        let zone = self.zone;
+        self.inner.next().map(|local| zone.resolve_local(local))
+    }
+}
+
+
+/// A **recurrence set** is the complete series of an iCalendar event:
+/// its first event, any recurrence rules, any extra dates (`RDATE`), and
+/// any dates that are excluded (`EXDATE`).
+///
+/// The first event is always part of the series, whether or not it
+/// matches the rules, unless it’s excluded. As RFC 5545 says, it’s also
+/// always the first of each rule’s `COUNT` events, even when it doesn’t
+/// match the rule, so `COUNT=2` with a start that doesn’t match gives the
+/// start and one event from the rule. Each rule’s `COUNT` also includes
+/// the events that are later excluded.
+///
+/// ### Examples
+///
+/// ```rust
+/// use datetime::{LocalDate, Month, RecurrenceSet};
+///
+/// let set: RecurrenceSet = "DTSTART:20240101T090000\nRRULE:FREQ=DAILY;COUNT=4\nEXDATE:20240102T090000".parse().unwrap();
+/// let days: Vec<_> = set.occurrences().map(|e| e.date()).collect();
+///
+/// assert_eq!(days, vec![
+///     LocalDate::ymd(2024, Month::January, 1).unwrap(),
+///     LocalDate::ymd(2024, Month::January, 3).unwrap(),
+///     LocalDate::ymd(2024, Month::January, 4).unwrap(),
+/// ]);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RecurrenceSet {
+
+    /// The datetime of the first event.
+    pub start: LocalDateTime,
+
+    /// The rules that describe the rest of the events.
+    pub rules: Vec<RecurrenceRule>,
+
+    /// Extra events that aren’t described by any of the rules.
+    pub dates: Vec<LocalDateTime>,
+
+    /// Events that are removed from the series.
+    pub exclusions: Vec<LocalDateTime>,
+}
+
+impl RecurrenceSet {
+
+    /// Creates a new recurrence set with only the given first event.
+    pub fn new(start: LocalDateTime) -> RecurrenceSet {
+        RecurrenceSet {
+            start:       start,
+            rules:       Vec::new(),
+            dates:       Vec::new(),
+            exclusions:  Vec::new(),
+        }
+    }
+
+    /// Returns an iterator over the datetimes of the events in this set,
+    /// in order, without any duplicates.
+    pub fn occurrences(&self) -> SetOccurrences<'_> {
+        SetOccurrences::new(self, None)
+    }
+
+    /// Returns an iterator over the events in this set in the given time
+    /// zone, resolving any times in gaps or overlaps in the same way as
+    /// `RecurrenceRule::zoned_occurrences`.
+    pub fn zoned_occurrences<'a>(&self, zone: &'a TimeZone) -> Zoned<'a, SetOccurrences<'a>> {
+        Zoned { inner: SetOccurrences::new(self, Some(zone)), zone: zone }
+    }
+}
+
+
+/// Iterator over the datetimes of the events in a recurrence set.
+#[derive(Debug, Clone)]
+pub struct SetOccurrences<'a> {
+    rules:       Vec<(Occurrences<'a>, Option<LocalDateTime>)>,
+    dates:       VecDeque<LocalDateTime>,
+    exclusions:  Vec<LocalDateTime>,
+}
+
+impl<'a> SetOccurrences<'a> {
+    fn new(set: &RecurrenceSet, zone: Option<&'a TimeZone>) -> SetOccurrences<'a> {
+        let rules = set.rules.iter().map(|rule| {
+            let mut occurrences = Occurrences::new(rule, set.start, zone);
+
+            // The first event counts towards every rule’s count, even when
+            // it doesn’t match the rule, leaving one fewer for the rule.
+            if let Some(count) = rule.count {
+                if occurrences.clone().next() != Some(set.start) {
+                    occurrences.rule.count = Some(count.saturating_sub(1));
+                    occurrences.finished = count <= 1;
+                }
+            }
+
+            let first = occurrences.next();
+            (occurrences, first)
+        }).collect();
+
+        let mut dates = set.dates.clone();
+        dates.push(set.start);
+        dates.sort();
+
+        let mut exclusions = set.exclusions.clone();
+        exclusions.sort();
+
+        SetOccurrences {
+            rules:       rules,
+            dates:       dates.into_iter().collect(),
+            exclusions:  exclusions,
+        }
+    }
+}
+
+impl<'a> Iterator for SetOccurrences<'a> {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<LocalDateTime> {
+        loop {
+            let earliest = self.rules.iter().filter_map(|r| r.1).chain(self.dates.front().cloned()).min();
+            let earliest = match earliest {
+                Some(earliest)  => earliest,
+                None            => return None,
+            };
+
+            // Move every source past this event, so it’s only returned once.
+            for &mut (ref mut occurrences, ref mut next) in &mut self.rules {
+                while *next == Some(earliest) {
+                    *next = occurrences.next();
+                }
+            }
+
+            while self.dates.front() == Some(&earliest) {
+                let _ = self.dates.pop_front();
+            }
+
+            if self.exclusions.binary_search(&earliest).is_err() {
+                return Some(earliest);
+            }
+        }
+    }
+}
+
+
+impl fmt::Display for RecurrenceRule {
+
+    /// Writes this rule in the same form that it’s parsed from, leaving out
+    /// any parts that have their default values.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Secondly  => "SECONDLY",
+            Frequency::Minutely  => "MINUTELY",
+            Frequency::Hourly    => "HOURLY",
+            Frequency::Daily     => "DAILY",
+            Frequency::Weekly    => "WEEKLY",
+            Frequency::Monthly   => "MONTHLY",
+            Frequency::Yearly    => "YEARLY",
+        };
+
+        try!(write!(f, "FREQ={}", frequency));
+
+        match self.until {
+            Some(Until::Local(until))  => try!(write!(f, ";UNTIL={}", format_datetime(until))),
+            Some(Until::Utc(until))    => try!(write!(f, ";UNTIL={}Z", format_datetime(LocalDateTime::from_instant(until)))),
+            None                       => {},
+        }
+
+        if let Some(count) = self.count { try!(write!(f, ";COUNT={}", count)) }
+        if self.interval != 1 { try!(write!(f, ";INTERVAL={}", self.interval)) }
+
+        try!(write_list(f, "BYSECOND", &self.by_second));
+        try!(write_list(f, "BYMINUTE", &self.by_minute));
+        try!(write_list(f, "BYHOUR", &self.by_hour));
+
+        let days: Vec<String> = self.by_day.iter().map(|d| match d.ordinal {
+            Some(ordinal)  => format!("{}{}", ordinal, format_weekday(d.weekday)),
+            None           => format_weekday(d.weekday).to_owned(),
+        }).collect();
+
+        try!(write_list(f, "BYDAY", &days));
+        try!(write_list(f, "BYMONTHDAY", &self.by_month_day));
+        try!(write_list(f, "BYYEARDAY", &self.by_year_day));
+        try!(write_list(f, "BYWEEKNO", &self.by_week_no));
+
+        let months: Vec<usize> = self.by_month.iter().map(|m| m.months_from_january() + 1).collect();
+        try!(write_list(f, "BYMONTH", &months));
+        try!(write_list(f, "BYSETPOS", &self.by_set_pos));
+
+        if self.week_start != Weekday::Monday {
+            try!(write!(f, ";WKST={}", format_weekday(self.week_start)));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for RecurrenceSet {
+
+    /// Writes this set as the iCalendar properties that it’s parsed from,
+    /// one per line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "DTSTART:{}", format_datetime(self.start)));
+
+        for rule in &self.rules {
+            try!(write!(f, "\nRRULE:{}", rule));
+        }
+
+        if !self.dates.is_empty() {
+            let dates: Vec<_> = self.dates.iter().map(|d| format_datetime(*d)).collect();
+            try!(write!(f, "\nRDATE:{}", dates.join(",")));
+        }
+
+        if !self.exclusions.is_empty() {
+            let dates: Vec<_> = self.exclusions.iter().map(|d| format_datetime(*d)).collect();
+            try!(write!(f, "\nEXDATE:{}", dates.join(",")));
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a rule part with a list of values, if there are any.
+fn write_list<T: fmt::Display>(f: &mut fmt::Formatter, name: &str, values: &[T]) -> fmt::Result {
+    if values.is_empty() {
+        return Ok(());
+    }
+
+    try!(write!(f, ";{}=", name));
+    for (index, value) in values.iter().enumerate() {
+        if index > 0 { try!(write!(f, ",")) }
+        try!(write!(f, "{}", value));
+    }
+
+    Ok(())
+}
+
+/// Returns the two-letter iCalendar name of a weekday.
+fn format_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday    => "MO",  Weekday::Tuesday   => "TU",  Weekday::Wednesday => "WE",
+        Weekday::Thursday  => "TH",  Weekday::Friday    => "FR",  Weekday::Saturday  => "SA",
+        Weekday::Sunday    => "SU",
+    }
+}
+
+/// Formats a datetime in the basic format that iCalendar uses, such as
+/// `20240131T090000`. Any milliseconds are left out.
+fn format_datetime(datetime: LocalDateTime) -> String {
+    format!("{:04}{:02}{:02}T{:02}{:02}{:02}",
+            datetime.year(), datetime.month().months_from_january() + 1, datetime.day(),
+            datetime.hour(), datetime.minute(), datetime.second())
+}
+
+
+impl FromStr for RecurrenceRule {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<RecurrenceRule, Self::Err> {
+        let input = if input.len() > 6 && input[.. 6].eq_ignore_ascii_case("RRULE:") { &input[6 ..] } else { input };
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule::new(Frequency::Yearly);
+
+        for part in input.split(';') {
+            let (name, value) = match part.find('=') {
+                Some(index)  => (part[.. index].to_uppercase(), part[index + 1 ..].to_uppercase()),
+                None         => return Err(Error::InvalidValue(part.to_owned())),
+            };
+
+            let invalid = || Error::InvalidValue(part.to_owned());
+
+            match &*name {
+                "FREQ" => frequency = Some(match &*value {
+                    "SECONDLY"  => Frequency::Secondly,
+                    "MINUTELY"  => Frequency::Minutely,
+                    "HOURLY"    => Frequency::Hourly,
+                    "DAILY"     => Frequency::Daily,
+                    "WEEKLY"    => Frequency::Weekly,
+                    "MONTHLY"   => Frequency::Monthly,
+                    "YEARLY"    => Frequency::Yearly,
+                    _           => return Err(invalid()),
+                }),
+
+                "INTERVAL" => {
+                    rule.interval = try!(value.parse().map_err(|_| invalid()));
+                    if rule.interval == 0 { return Err(invalid()) }
+                },
+
+                "COUNT"       => rule.count = Some(try!(value.parse().map_err(|_| invalid()))),
+                "UNTIL"       => rule.until = Some(try!(parse_until(&value).ok_or_else(&invalid))),
+                "BYSECOND"    => rule.by_second    = try!(parse_list(&value, 0, 60, false).ok_or_else(&invalid)),
+                "BYMINUTE"    => rule.by_minute    = try!(parse_list(&value, 0, 59, false).ok_or_else(&invalid)),
+                "BYHOUR"      => rule.by_hour      = try!(parse_list(&value, 0, 23, false).ok_or_else(&invalid)),
+                "BYMONTHDAY"  => rule.by_month_day = try!(parse_list(&value, 1, 31, true).ok_or_else(&invalid)),
+                "BYYEARDAY"   => rule.by_year_day  = try!(parse_list(&value, 1, 366, true).ok_or_else(&invalid)),
+                "BYWEEKNO"    => rule.by_week_no   = try!(parse_list(&value, 1, 53, true).ok_or_else(&invalid)),
+                "BYSETPOS"    => rule.by_set_pos   = try!(parse_list(&value, 1, 366, true).ok_or_else(&invalid)),
+                "WKST"        => rule.week_start   = try!(parse_weekday(&value).ok_or_else(&invalid)),
+
+                "BYMONTH" => {
+                    let months: Vec<i8> = try!(parse_list(&value, 1, 12, false).ok_or_else(&invalid));
+                    rule.by_month = months.into_iter().map(|m| Month::from_one(m).unwrap()).collect();
+                },
+
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        rule.by_day.push(try!(parse_by_day(day).ok_or_else(&invalid)));
+                    }
+                },
+
+                _ => return Err(Error::UnknownPart(name)),
+            }
+        }
+
+        rule.frequency = try!(frequency.ok_or(Error::MissingFrequency));
+
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(Error::CountAndUntil);
+        }
+
+        Ok(rule)
+    }
+}
+
+impl FromStr for RecurrenceSet {
+    type Err = Error;
+
+    /// Parses the recurrence properties of an iCalendar event, one per
+    /// line: `DTSTART`, and any number of `RRULE`, `RDATE`, and `EXDATE`.
+    /// Any property parameters, such as `TZID`, are ignored, so the values
+    /// are all taken to be in the same time zone.
+    fn from_str(input: &str) -> Result<RecurrenceSet, Self::Err> {
+        let mut start = None;
+        let mut rules = Vec::new();
+        let mut dates = Vec::new();
+        let mut exclusions = Vec::new();
+
+        for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (name, value) = match line.find(':') {
+                Some(index)  => (&line[.. index], &line[index + 1 ..]),
+                None         => return Err(Error::InvalidValue(line.to_owned())),
+            };
+
+            // Strip the parameters, which come after a semicolon.
+            let name = name.split(';').next().unwrap().to_uppercase();
+            let invalid = || Error::InvalidValue(line.to_owned());
+
+            match &*name {
+                "DTSTART" => start = Some(try!(parse_datetime(value).map(|d| d.0).ok_or_else(&invalid))),
+                "RRULE"   => rules.push(try!(value.parse())),
+                "RDATE" | "EXDATE" => {
+                    let list = if name == "RDATE" { &mut dates } else { &mut exclusions };
+                    for value in value.split(',') {
+                        list.push(try!(parse_datetime(value).map(|d| d.0).ok_or_else(&invalid)));
+                    }
+                },
+                _ => return Err(Error::UnknownPart(name)),
+            }
+        }
+
+        Ok(RecurrenceSet {
+            start:       try!(start.ok_or(Error::MissingStart)),
+            rules:       rules,
+            dates:       dates,
+            exclusions:  exclusions,
+        })
+    }
+}
+
+/// Parses a comma-separated list of numbers, each of which must be within
+/// the given range, or the negative of it if negatives are allowed.
+fn parse_list<N: FromStr + Into<i64> + Copy>(input: &str, min: i64, max: i64, negatives: bool) -> Option<Vec<N>> {
+    let mut list = Vec::new();
+
+    for number in input.split(',') {
+        let number: N = match number.parse() {
+            Ok(number)  => number,
+            Err(_)      => return None,
+        };
+
+        let value = number.into();
+        if !((value >= min && value <= max) || (negatives && value <= -min && value >= -max && value != 0)) {
+            return None;
+        }
+
+        list.push(number);
+    }
+
+    Some(list)
+}
+
+/// Parses a two-letter iCalendar weekday, such as `MO`.
+fn parse_weekday(input: &str) -> Option<Weekday> {
+    Some(match input {
+        "MO" => Weekday::Monday,    "TU" => Weekday::Tuesday,  "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,  "FR" => Weekday::Friday,   "SA" => Weekday::Saturday,
+        "SU" => Weekday::Sunday,    _    => return None,
+    })
+}
+
+/// Parses a weekday in a `BYDAY` part, such as `MO`, `2TU`, or `-1FR`.
+fn parse_by_day(input: &str) -> Option<ByDay> {
+    if input.len() < 2 || !input.is_char_boundary(input.len() - 2) {
+        return None;
+    }
+
+    let (ordinal, weekday) = input.split_at(input.len() - 2);
+    let weekday = match parse_weekday(weekday) {
+        Some(weekday)  => weekday,
+        None           => return None,
+    };
+
+    let ordinal = if ordinal.is_empty() {
+        None
+    }
+    else {
+        match parse_list::<i8>(ordinal, 1, 53, true) {
+            Some(ref list) if list.len() == 1  => Some(list[0]),
+            _                                  => return None,
+        }
+    };
+
+    Some(ByDay { ordinal: ordinal, weekday: weekday })
+}
+
+/// Parses an `UNTIL` value, which is in UTC if it ends with a `Z`.
+fn parse_until(input: &str) -> Option<Until> {
+    parse_datetime(input).map(|(datetime, utc)| {
+        if utc { Until::Utc(datetime.to_instant()) } else { Until::Local(datetime) }
+    })
+}
+
+/// Parses an iCalendar date or datetime, which is in the basic format
+/// without any separators, such as `20240131` or `20240131T090000Z`,
+/// returning it along with whether it’s in UTC. A date on its own is
+/// taken to be at midnight.
+fn parse_datetime(input: &str) -> Option<(LocalDateTime, bool)> {
+    let (input, utc) = if input.ends_with('Z') || input.ends_with('z') { (&input[.. input.len() - 1], true) } else { (input, false) };
+
+    if !input.bytes().enumerate().all(|(i, b)| if i == 8 { b == b'T' || b == b't' } else { b.is_ascii_digit() }) {
+        return None;
+    }
+
+    let number = |from: usize, to: usize| input[from .. to].parse::<i64>().unwrap();
+
+    let date = match input.len() {
+        8 | 15 => match Month::from_one(number(4, 6) as i8).and_then(|m| LocalDate::ymd(number(0, 4), m, number(6, 8) as i8)) {
+            Ok(date)  => date,
+            Err(_)    => return None,
+        },
+        _ => return None,
+    };
+
+    let time = if input.len() == 15 {
+        match LocalTime::hms(number(9, 11) as i8, number(11, 13) as i8, number(13, 15) as i8) {
+            Ok(time)  => time,
+            Err(_)    => return None,
+        }
+    }
+    else {
+        LocalTime::midnight()
+    };
+
+    Some((LocalDateTime::new(date, time), utc))
+}
+
+
+/// An error that occurs when parsing a recurrence rule or set.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Error {
+
+    /// The rule doesn’t say how often it repeats, with a `FREQ` part.
+    MissingFrequency,
+
+    /// The rule has both a `COUNT` and an `UNTIL` part, which RFC 5545
+    /// doesn’t allow.
+    CountAndUntil,
+
+    /// The set doesn’t have a `DTSTART` property.
+    MissingStart,
+
+    /// A rule part or property that isn’t recognised.
+    UnknownPart(String),
+
+    /// A rule part or property with a value that couldn’t be parsed, or
+    /// that’s out of range.
+    InvalidValue(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnknownPart(ref part)   => write!(f, "{}: {}", self.description(), part),
+            Error::InvalidValue(ref part)  => write!(f, "{}: {}", self.description(), part),
+            _                              => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::MissingFrequency  => "recurrence rule has no frequency",
+            Error::CountAndUntil     => "recurrence rule has both a count and an end",
+            Error::MissingStart      => "recurrence set has no start",
+            Error::UnknownPart(_)    => "unknown recurrence rule part",
+            Error::InvalidValue(_)   => "invalid recurrence rule value",
+        }
+    }
+}
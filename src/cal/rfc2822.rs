@@ -0,0 +1,334 @@
+//! Datetimes in the format used by email headers, as described in RFC 2822
+//! and its successor, RFC 5322.
+//!
+//! These look like `Tue, 1 Jul 2003 10:52:37 +0200`. The parser accepts
+//! the obsolete forms that the RFCs still require readers to handle: zone
+//! names such as `GMT` or `PDT`, two- and three-digit years, comments in
+//! brackets, and extra whitespace, including lines folded with CRLF. The
+//! formatter only ever writes the current form.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset};
+//! use datetime::rfc2822;
+//!
+//! let datetime = rfc2822::parse("Tue, 1 Jul 2003 10:52:37 +0200").unwrap();
+//! let local = LocalDateTime::new(LocalDate::ymd(2003, Month::July, 1).unwrap(), LocalTime::hms(10, 52, 37).unwrap());
+//! assert_eq!(datetime, Offset::of_hours_and_minutes(2, 0).unwrap().transform_date(local));
+//!
+//! assert_eq!(rfc2822::format(&datetime).to_string(), "Tue, 1 Jul 2003 10:52:37 +0200");
+//! ```
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Error as DateTimeError};
+use cal::offset::{Offset, OffsetDateTime, Error as OffsetError};
+
+
+/// The abbreviations for the days of the week, starting with Sunday. HTTP
+/// dates use these too.
+pub(crate) const WEEKDAY_NAMES: [&'static str; 7] = [ "Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat" ];
+
+/// The abbreviations for the months of the year, starting with January.
+/// HTTP dates use these too.
+pub(crate) const MONTH_NAMES: [&'static str; 12] = [ "Jan", "Feb", "Mar", "Apr", "May", "Jun",
+                                          "Jul", "Aug", "Sep", "Oct", "Nov", "Dec" ];
+
+/// The obsolete zone names, and their offsets in hours.
+const ZONE_NAMES: [(&'static str, i8); 10] = [
+    ("UT", 0),   ("GMT", 0),
+    ("EST", -5), ("EDT", -4),
+    ("CST", -6), ("CDT", -5),
+    ("MST", -7), ("MDT", -6),
+    ("PST", -8), ("PDT", -7),
+];
+
+
+/// Parses a datetime in the RFC 2822 format, such as
+/// `Tue, 1 Jul 2003 10:52:37 +0200`.
+///
+/// The day of the week and the seconds are both optional, but if there is
+/// a day of the week, it has to be the right one for the date.
+///
+/// The offsets `UT`, `GMT`, and `Z` all give UTC, and `-0000` gives the
+/// unknown offset. So do the other military single-letter zones: RFC 822
+/// got their signs backwards, so RFC 2822 says not to trust them.
+pub fn parse(input: &str) -> Result<OffsetDateTime, Error> {
+    let tokens = try!(tokenise(input));
+    let mut tokens = tokens.iter().map(|t| &**t).peekable();
+
+    // The day of the week is the only part that starts with a letter.
+    let weekday = match tokens.peek().map(|t| t.as_bytes()[0].is_ascii_alphabetic()) {
+        Some(true) => {
+            let name = tokens.next().unwrap();
+            let weekday = try!(find_name(&WEEKDAY_NAMES, name).ok_or_else(|| Error::UnknownWeekday(name.to_owned())));
+            try!(expect(&mut tokens, ","));
+            Some(try!(Weekday::from_zero(weekday as i8).map_err(Error::Date)))
+        },
+        _ => None,
+    };
+
+    let day = try!(number(&mut tokens, 1, 2));
+
+    let name = try!(tokens.next().ok_or(Error::Syntax));
+    let month = try!(find_name(&MONTH_NAMES, name).ok_or_else(|| Error::UnknownMonth(name.to_owned())));
+    let month = try!(Month::from_zero(month as i8).map_err(Error::Date));
+
+    let year = try!(tokens.peek().ok_or(Error::Syntax)).len();
+    let year = match (year, try!(number(&mut tokens, 2, 9))) {
+        (2, y) if y < 50  => 2000 + y,
+        (2, y) | (3, y)   => 1900 + y,
+        (_, y)            => y,
+    };
+
+    let hour = try!(number(&mut tokens, 2, 2));
+    try!(expect(&mut tokens, ":"));
+    let minute = try!(number(&mut tokens, 2, 2));
+
+    let second = if tokens.peek() == Some(&":") {
+        let _ = tokens.next();
+        try!(number(&mut tokens, 2, 2))
+    }
+    else {
+        0
+    };
+
+    let offset = try!(parse_zone(try!(tokens.next().ok_or(Error::Syntax))));
+
+    if tokens.next().is_some() {
+        return Err(Error::Syntax);
+    }
+
+    let date = try!(LocalDate::ymd(year, month, day as i8).map_err(Error::Date));
+    let time = try!(LocalTime::hms_ms(hour as i8, minute as i8, second as i8, 0).map_err(Error::Date));
+
+    if let Some(weekday) = weekday {
+        if weekday != date.weekday() {
+            return Err(Error::WeekdayMismatch { given: weekday, actual: date.weekday() });
+        }
+    }
+
+    Ok(offset.transform_date(LocalDateTime::new(date, time)))
+}
+
+/// Returns a value that displays the given datetime in the RFC 2822
+/// format, such as `Tue, 1 Jul 2003 10:52:37 +0200`.
+///
+/// The format has no room for milliseconds, or for the seconds part of an
+/// offset, so these are left out.
+pub fn format(datetime: &OffsetDateTime) -> Rfc2822<'_> {
+    Rfc2822(datetime)
+}
+
+/// A datetime that gets displayed in the RFC 2822 format. This is returned
+/// from the `format` function.
+#[derive(Debug, Copy, Clone)]
+pub struct Rfc2822<'a>(&'a OffsetDateTime);
+
+impl<'a> fmt::Display for Rfc2822<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let local = self.0.local;
+        let offset = self.0.offset;
+        let sign = if offset.is_negative() || offset.is_unknown() { '-' } else { '+' };
+
+        write!(f, "{}, {} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+               WEEKDAY_NAMES[local.date().weekday() as usize], local.date().day(),
+               MONTH_NAMES[local.date().month().months_from_january()], local.date().year(),
+               local.time().hour(), local.time().minute(), local.time().second(),
+               sign, offset.hours().abs(), offset.minutes().abs())
+    }
+}
+
+
+/// Splits the input into words, numbers, and punctuation, removing any
+/// whitespace and comments along the way.
+fn tokenise(input: &str) -> Result<Vec<String>, Error> {
+    let mut tokens = Vec::new();
+    let mut depth = 0;
+    let mut escaped = false;
+    let mut current = String::new();
+
+    for c in input.chars() {
+        if depth > 0 {
+            // Comments can nest, and can quote brackets with backslashes.
+            if escaped             { escaped = false }
+            else if c == '\\'      { escaped = true }
+            else if c == '('       { depth += 1 }
+            else if c == ')'       { depth -= 1 }
+            continue;
+        }
+
+        let continues_token = match current.chars().last() {
+            None                                           => false,
+            Some(last) if last.is_ascii_alphabetic()       => c.is_ascii_alphabetic(),
+            Some(_)                                        => c.is_ascii_digit(),
+        };
+
+        if !continues_token && !current.is_empty() {
+            tokens.push(current);
+            current = String::new();
+        }
+
+        match c {
+            '('                                        => depth = 1,
+            ' ' | '\t' | '\r' | '\n'                   => {},
+            ',' | ':'                                  => tokens.push(c.to_string()),
+            '+' | '-'                                  => current.push(c),
+            c if c.is_ascii_alphanumeric()             => current.push(c),
+            _                                          => return Err(Error::Syntax),
+        }
+    }
+
+    if depth > 0 {
+        return Err(Error::Syntax);
+    }
+    else if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    Ok(tokens)
+}
+
+/// Returns the index of the name in the list, ignoring case.
+fn find_name(names: &[&'static str], name: &str) -> Option<usize> {
+    names.iter().position(|n| n.eq_ignore_ascii_case(name))
+}
+
+/// Consumes the given punctuation token, or fails if the next token is
+/// something else.
+fn expect<'a, I: Iterator<Item=&'a str>>(tokens: &mut I, punctuation: &str) -> Result<(), Error> {
+    match tokens.next() {
+        Some(t) if t == punctuation  => Ok(()),
+        _                            => Err(Error::Syntax),
+    }
+}
+
+/// Consumes a number with the given range of digits.
+fn number<'a, I: Iterator<Item=&'a str>>(tokens: &mut I, min_digits: usize, max_digits: usize) -> Result<i64, Error> {
+    match tokens.next() {
+        Some(t) if t.len() >= min_digits && t.len() <= max_digits && t.bytes().all(|b| b.is_ascii_digit()) => {
+            Ok(t.parse().unwrap())
+        },
+        _ => Err(Error::Syntax),
+    }
+}
+
+/// Parses a numeric offset, such as `+0200`, or one of the obsolete zone
+/// names.
+fn parse_zone(zone: &str) -> Result<Offset, Error> {
+    let unknown = || Error::UnknownZone(zone.to_owned());
+
+    if zone.starts_with('+') || zone.starts_with('-') {
+        let digits = &zone[1..];
+        if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(unknown());
+        }
+
+        let hours: i8 = digits[.. 2].parse().unwrap();
+        let minutes: i8 = digits[2 ..].parse().unwrap();
+
+        if zone == "-0000" {
+            Ok(Offset::unknown())
+        }
+        else if zone.starts_with('-') {
+            Offset::of_hours_and_minutes(-hours, -minutes).map_err(Error::Offset)
+        }
+        else {
+            Offset::of_hours_and_minutes(hours, minutes).map_err(Error::Offset)
+        }
+    }
+    else if let Some(&(_, hours)) = ZONE_NAMES.iter().find(|z| z.0.eq_ignore_ascii_case(zone)) {
+        if hours == 0 {
+            Ok(Offset::utc())
+        }
+        else {
+            Offset::of_hours_and_minutes(hours, 0).map_err(Error::Offset)
+        }
+    }
+    else if zone.eq_ignore_ascii_case("Z") {
+        Ok(Offset::utc())
+    }
+    else if zone.len() == 1 && zone.as_bytes()[0].is_ascii_alphabetic() && !zone.eq_ignore_ascii_case("J") {
+        Ok(Offset::unknown())
+    }
+    else {
+        Err(unknown())
+    }
+}
+
+
+/// An error that occurs when parsing an RFC 2822 datetime.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+
+    /// The input isn’t laid out like an RFC 2822 datetime, such as
+    /// missing a colon, having an unclosed comment, or having numbers with
+    /// the wrong number of digits.
+    Syntax,
+
+    /// The name of a day of the week wasn’t recognised.
+    UnknownWeekday(String),
+
+    /// The name of a month wasn’t recognised.
+    UnknownMonth(String),
+
+    /// The offset was neither a number nor a recognised zone name.
+    UnknownZone(String),
+
+    /// The input started with a day of the week, such as `Tue,`, but the
+    /// date falls on a different one.
+    WeekdayMismatch {
+
+        /// The day of the week in the input.
+        given: Weekday,
+
+        /// The day of the week that the date actually falls on.
+        actual: Weekday,
+    },
+
+    /// The day, hour, minute, or second was out of range, such as
+    /// `31 Apr` or `24:00`. A leap second of `:60` counts as out of range,
+    /// as it can’t be represented.
+    Date(DateTimeError),
+
+    /// A numeric offset had 24 hours or more, or 60 minutes or more, such
+    /// as `+2400` or `-0075`.
+    Offset(OffsetError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::UnknownWeekday(ref name)
+            | Error::UnknownMonth(ref name)
+            | Error::UnknownZone(ref name)            => write!(f, "{}: {:?}", self.description(), name),
+            Error::WeekdayMismatch { given, actual }  => write!(f, "{}: given {:?}, but the date is a {:?}", self.description(), given, actual),
+            _                                         => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Syntax                  => "invalid RFC 2822 datetime",
+            Error::UnknownWeekday(_)       => "unknown day of the week",
+            Error::UnknownMonth(_)         => "unknown month",
+            Error::UnknownZone(_)          => "unknown time zone",
+            Error::WeekdayMismatch { .. }  => "day of the week does not match the date",
+            Error::Date(_)                 => "datetime field out of range",
+            Error::Offset(_)               => "offset field out of range",
+        }
+    }
+
+    fn cause(&self) -> Option<&ErrorTrait> {
+        match *self {
+            Error::Date(ref e)    => Some(e),
+            Error::Offset(ref e)  => Some(e),
+            _                     => None,
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Datetimes in the strict profile of ISO 8601 described by RFC 3339.
+//!
+//! The `FromStr` implementation for `OffsetDateTime` accepts everything
+//! that ISO 8601 does, such as week dates, the basic format, and times
+//! without seconds. This module only accepts datetimes in the form
+//! `1985-04-12T23:20:50.52Z`: the date and time in full, separated by a
+//! `T` or a space, with an offset.
+//!
+//! RFC 3339 gives a meaning to `-00:00`: the time is in UTC, but the offset
+//! of the place it’s about is unknown. This is parsed as
+//! `Offset::unknown()`, which isn’t equal to `+00:00` or `Z`.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset};
+//! use datetime::rfc3339::{self, FractionDigits};
+//!
+//! let datetime = rfc3339::parse("1996-12-19T16:39:57-08:00").unwrap();
+//! let local = LocalDateTime::new(LocalDate::ymd(1996, Month::December, 19).unwrap(), LocalTime::hms(16, 39, 57).unwrap());
+//! assert_eq!(datetime, Offset::of_hours_and_minutes(-8, 0).unwrap().transform_date(local));
+//!
+//! assert_eq!(rfc3339::format(&datetime, FractionDigits::Three).to_string(), "1996-12-19T16:39:57.000-08:00");
+//! ```
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Error as DateTimeError};
+use cal::offset::{Offset, OffsetDateTime, Error as OffsetError};
+
+
+/// Parses a datetime in the RFC 3339 format, such as
+/// `1985-04-12T23:20:50.52Z`.
+///
+/// Any number of fraction digits are accepted, but only the first three
+/// are kept, as datetimes are only precise to the millisecond. Leap
+/// seconds can’t be represented, so a time with 60 seconds is an error.
+pub fn parse(input: &str) -> Result<OffsetDateTime, Error> {
+    let input = input.as_bytes();
+    let mut position = 0;
+
+    let year = try!(digits(input, &mut position, 4));
+    try!(expect(input, &mut position, b"-"));
+    let month = try!(digits(input, &mut position, 2));
+    try!(expect(input, &mut position, b"-"));
+    let day = try!(digits(input, &mut position, 2));
+    try!(expect(input, &mut position, b"Tt "));
+    let hour = try!(digits(input, &mut position, 2));
+    try!(expect(input, &mut position, b":"));
+    let minute = try!(digits(input, &mut position, 2));
+    try!(expect(input, &mut position, b":"));
+    let second = try!(digits(input, &mut position, 2));
+
+    let mut millisecond = 0;
+    if input.get(position) == Some(&b'.') {
+        position += 1;
+
+        let start = position;
+        while input.get(position).map(|b| b.is_ascii_digit()) == Some(true) {
+            if position - start < 3 {
+                millisecond = millisecond * 10 + (input[position] - b'0') as i16;
+            }
+
+            position += 1;
+        }
+
+        match position - start {
+            0  => return Err(Error::Syntax(position)),
+            1  => millisecond *= 100,
+            2  => millisecond *= 10,
+            _  => {},
+        }
+    }
+
+    let offset = match input.get(position) {
+        Some(&b'Z') | Some(&b'z') => {
+            position += 1;
+            Offset::utc()
+        },
+        Some(&b'+') | Some(&b'-') => {
+            let negative = input[position] == b'-';
+            position += 1;
+
+            let hours = try!(digits(input, &mut position, 2));
+            try!(expect(input, &mut position, b":"));
+            let minutes = try!(digits(input, &mut position, 2));
+
+            if negative && hours == 0 && minutes == 0 {
+                Offset::unknown()
+            }
+            else if negative {
+                try!(Offset::of_hours_and_minutes(-hours as i8, -minutes as i8).map_err(Error::Offset))
+            }
+            else {
+                try!(Offset::of_hours_and_minutes(hours as i8, minutes as i8).map_err(Error::Offset))
+            }
+        },
+        None     => return Err(Error::MissingOffset),
+        Some(_)  => return Err(Error::Syntax(position)),
+    };
+
+    if position != input.len() {
+        return Err(Error::Syntax(position));
+    }
+
+    let month = try!(Month::from_one(month as i8).map_err(Error::Date));
+    let date = try!(LocalDate::ymd(year, month, day as i8).map_err(Error::Date));
+    let time = try!(LocalTime::hms_ms(hour as i8, minute as i8, second as i8, millisecond).map_err(Error::Date));
+    Ok(offset.transform_date(LocalDateTime::new(date, time)))
+}
+
+/// Reads a number with exactly the given number of digits.
+fn digits(input: &[u8], position: &mut usize, count: usize) -> Result<i64, Error> {
+    let mut number = 0;
+
+    for _ in 0 .. count {
+        match input.get(*position) {
+            Some(&b) if b.is_ascii_digit()  => number = number * 10 + (b - b'0') as i64,
+            _                               => return Err(Error::Syntax(*position)),
+        }
+
+        *position += 1;
+    }
+
+    Ok(number)
+}
+
+/// Reads one of the given characters.
+fn expect(input: &[u8], position: &mut usize, any_of: &[u8]) -> Result<(), Error> {
+    match input.get(*position) {
+        Some(b) if any_of.contains(b)  => { *position += 1; Ok(()) },
+        _                              => Err(Error::Syntax(*position)),
+    }
+}
+
+
+/// The number of digits to write after the decimal point of the seconds.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum FractionDigits {
+
+    /// Whole seconds only, such as `23:20:50`.
+    Zero,
+
+    /// Milliseconds, such as `23:20:50.520`.
+    Three,
+
+    /// Microseconds, such as `23:20:50.520000`.
+    Six,
+
+    /// Nanoseconds, such as `23:20:50.520000000`.
+    Nine,
+
+    /// Milliseconds, unless the time is a whole number of seconds, in
+    /// which case the fraction is left out.
+    Auto,
+}
+
+/// Returns a value that displays the given datetime in the RFC 3339
+/// format, with the given number of fraction digits.
+///
+/// Offsets in RFC 3339 are only precise to the minute, so any seconds in
+/// the offset are left out.
+pub fn format(datetime: &OffsetDateTime, digits: FractionDigits) -> Rfc3339<'_> {
+    Rfc3339 { datetime: datetime, digits: digits }
+}
+
+/// A datetime that gets displayed in the RFC 3339 format. This is returned
+/// from the `format` function.
+#[derive(Debug, Copy, Clone)]
+pub struct Rfc3339<'a> {
+    datetime: &'a OffsetDateTime,
+    digits: FractionDigits,
+}
+
+impl<'a> fmt::Display for Rfc3339<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (date, time) = (self.datetime.local.date(), self.datetime.local.time());
+        let offset = self.datetime.offset;
+
+        try!(write!(f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                    date.year(), date.month().months_from_january() + 1, date.day(),
+                    time.hour(), time.minute(), time.second()));
+
+        match self.digits {
+            FractionDigits::Zero                                   => {},
+            FractionDigits::Auto if time.millisecond() == 0        => {},
+            FractionDigits::Three | FractionDigits::Auto           => try!(write!(f, ".{:03}", time.millisecond())),
+            FractionDigits::Six                                    => try!(write!(f, ".{:03}000", time.millisecond())),
+            FractionDigits::Nine                                   => try!(write!(f, ".{:03}000000", time.millisecond())),
+        }
+
+        if offset.is_utc() {
+            write!(f, "Z")
+        }
+        else {
+            let sign = if offset.is_negative() || offset.is_unknown() { '-' } else { '+' };
+            write!(f, "{}{:02}:{:02}", sign, offset.hours().abs(), offset.minutes().abs())
+        }
+    }
+}
+
+
+/// An error that occurs when parsing an RFC 3339 datetime.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Error {
+
+    /// The input doesn’t follow the format, starting at the given byte.
+    Syntax(usize),
+
+    /// The datetime has no offset, which RFC 3339 requires.
+    MissingOffset,
+
+    /// The month, day, hour, minute, or second was out of range, such as
+    /// `1985-04-31` or `24:00:00`. A leap second of `23:59:60` counts as
+    /// out of range, as it can’t be represented.
+    Date(DateTimeError),
+
+    /// The offset had 24 hours or more, or 60 minutes or more, such as
+    /// `+24:00` or `+05:60`.
+    Offset(OffsetError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Syntax(position)  => write!(f, "{} at byte {}", self.description(), position),
+            _                        => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Syntax(_)      => "invalid RFC 3339 datetime",
+            Error::MissingOffset  => "missing offset",
+            Error::Date(_)        => "datetime field out of range",
+            Error::Offset(_)      => "offset field out of range",
+        }
+    }
+
+    fn cause(&self) -> Option<&ErrorTrait> {
+        match *self {
+            Error::Date(ref e)    => Some(e),
+            Error::Offset(ref e)  => Some(e),
+            _                     => None,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Ranges of times of day, which may cross midnight.
+
+use std::fmt;
+
+use cal::datetime::LocalTime;
+use cal::fmt::ISO;
+use duration::Duration;
+
+
+/// A **time range** is a stretch of time that recurs every day, such as
+/// a shop’s opening hours, *without a date or time zone*.
+///
+/// The range includes its start time but not its end time. If the end is
+/// earlier in the day than the start, the range crosses midnight: from
+/// 22:00 to 06:00 covers the late evening and early morning, but not
+/// midday. A range from a time to the same time is empty, so a range that
+/// covers the whole day has to end at 24:00.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct TimeRange {
+    start: LocalTime,
+    end:   LocalTime,
+}
+
+impl TimeRange {
+
+    /// Creates a new time range from the given start and end times.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalTime, TimeRange};
+    ///
+    /// let night = TimeRange::new(LocalTime::hm(22, 0).unwrap(), LocalTime::hm(6, 0).unwrap());
+    /// assert!(night.contains(LocalTime::hm(23, 30).unwrap()));
+    /// assert!(night.contains(LocalTime::hm(2, 0).unwrap()));
+    /// assert!(!night.contains(LocalTime::hm(12, 0).unwrap()));
+    /// ```
+    pub fn new(start: LocalTime, end: LocalTime) -> TimeRange {
+        TimeRange { start: start, end: end }
+    }
+
+    /// Returns the time at which this range starts.
+    pub fn start(&self) -> LocalTime {
+        self.start
+    }
+
+    /// Returns the time at which this range ends.
+    pub fn end(&self) -> LocalTime {
+        self.end
+    }
+
+    /// Returns whether this range crosses midnight, continuing into the
+    /// next day.
+    pub fn crosses_midnight(&self) -> bool {
+        self.end < self.start
+    }
+
+    /// Returns whether this range contains no times at all.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns whether the given time falls within this range.
+    pub fn contains(&self, time: LocalTime) -> bool {
+        if self.crosses_midnight() {
+            time >= self.start || time < self.end
+        }
+        else {
+            time >= self.start && time < self.end
+        }
+    }
+
+    /// Returns the length of this range.
+    pub fn duration(&self) -> Duration {
+        if self.crosses_midnight() {
+            self.start.until(self.end)
+        }
+        else {
+            // This can’t use `until`, because the end might be 24:00.
+            self.end - self.start
+        }
+    }
+}
+
+impl fmt::Debug for TimeRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TimeRange({}/{})", self.start.iso(), self.end.iso())
+    }
+}
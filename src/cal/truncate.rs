@@ -0,0 +1,261 @@
+//! Truncating and rounding datetimes to the boundaries of units of time.
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year};
+use cal::offset::OffsetDateTime;
+use instant::Instant;
+
+
+/// Number of milliseconds in a day.
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+
+/// A **time unit** is a span of time that a datetime can be truncated or
+/// rounded to, such as “five minutes” or “a week starting on Monday”.
+///
+/// The units shorter than a day can be given a count, to form buckets of
+/// several units. These buckets are counted from midnight, rather than
+/// from any particular epoch, so five-minute buckets always start at
+/// :00, :05, :10, and so on. If the count doesn’t divide evenly into a day,
+/// the last bucket of each day is cut short at midnight.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TimeUnit {
+
+    /// A number of milliseconds.
+    Milliseconds(u32),
+
+    /// A number of seconds.
+    Seconds(u32),
+
+    /// A number of minutes.
+    Minutes(u32),
+
+    /// A number of hours.
+    Hours(u32),
+
+    /// A day, starting at midnight.
+    Day,
+
+    /// A week, starting at midnight on the given weekday.
+    Week(Weekday),
+
+    /// A month, starting at midnight on its first day.
+    Month,
+
+    /// A year, starting at midnight on the 1st of January.
+    Year,
+}
+
+impl TimeUnit {
+
+    /// Returns the length of this unit in milliseconds, if it’s one of the
+    /// units that’s shorter than a day.
+    ///
+    /// Panics if the unit has a count of zero, as there’s no way to divide
+    /// time into buckets of no length.
+    fn fixed_length(&self) -> Option<i64> {
+        let (count, multiplier) = match *self {
+            TimeUnit::Milliseconds(count)  => (count, 1),
+            TimeUnit::Seconds(count)       => (count, 1000),
+            TimeUnit::Minutes(count)       => (count, 60 * 1000),
+            TimeUnit::Hours(count)         => (count, 60 * 60 * 1000),
+            _                              => return None,
+        };
+
+        assert!(count > 0, "time unit {:?} has a count of zero", self);
+        Some(count as i64 * multiplier)
+    }
+
+    /// Returns the start of the unit that contains the given datetime, and
+    /// the start of the unit after it.
+    fn bounds(&self, datetime: LocalDateTime) -> (LocalDateTime, LocalDateTime) {
+        let date = datetime.date();
+
+        let (start, end) = match *self {
+            TimeUnit::Day => (date, date.add_days(1)),
+
+            TimeUnit::Week(first_day) => {
+                let days_back = ((datetime.weekday() as i64 - first_day as i64) % 7 + 7) % 7;
+                let start = date.add_days(-days_back);
+                (start, start.add_days(7))
+            },
+
+            TimeUnit::Month => {
+                let start = LocalDate::ymd(date.year(), date.month(), 1).unwrap();
+                let length = date.month().days_in_month(Year(date.year()).is_leap_year());
+                (start, start.add_days(length as i64))
+            },
+
+            TimeUnit::Year => {
+                let start = LocalDate::ymd(date.year(), Month::January, 1).unwrap();
+                (start, LocalDate::ymd(date.year() + 1, Month::January, 1).unwrap())
+            },
+
+            _ => {
+                let length = self.fixed_length().unwrap();
+                let time = datetime.time();
+                let milliseconds = time.to_seconds() * 1000 + time.millisecond() as i64;
+                let start = milliseconds - milliseconds % length;
+                let end = start + length;
+
+                return (at_millisecond(date, start), at_millisecond(date, end));
+            },
+        };
+
+        (LocalDateTime::new(start, LocalTime::midnight()), LocalDateTime::new(end, LocalTime::midnight()))
+    }
+}
+
+/// Returns the datetime that’s the given number of milliseconds after the
+/// start of the given date, stopping at the following midnight.
+fn at_millisecond(date: LocalDate, milliseconds: i64) -> LocalDateTime {
+    if milliseconds >= MILLISECONDS_IN_DAY {
+        LocalDateTime::new(date.add_days(1), LocalTime::midnight())
+    }
+    else {
+        let time = LocalTime::from_seconds_and_milliseconds_since_midnight(milliseconds / 1000, (milliseconds % 1000) as i16);
+        LocalDateTime::new(date, time)
+    }
+}
+
+/// Returns the number of milliseconds from one datetime to another.
+fn milliseconds_between(from: LocalDateTime, to: LocalDateTime) -> i64 {
+    let (from, to) = (from.to_instant(), to.to_instant());
+    (to.seconds() - from.seconds()) * 1000 + (to.milliseconds() - from.milliseconds()) as i64
+}
+
+
+impl LocalDateTime {
+
+    /// Returns the start of the unit of time that contains this datetime,
+    /// rounding it down.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, TimeUnit};
+    ///
+    /// let date = LocalDate::ymd(2024, Month::January, 31).unwrap();
+    /// let then = LocalDateTime::new(date, LocalTime::hms(14, 37, 12).unwrap());
+    ///
+    /// assert_eq!(then.truncate_to(TimeUnit::Minutes(5)),
+    ///            LocalDateTime::new(date, LocalTime::hm(14, 35).unwrap()));
+    ///
+    /// assert_eq!(then.truncate_to(TimeUnit::Month),
+    ///            LocalDateTime::new(date.add_days(-30), LocalTime::midnight()));
+    /// ```
+    pub fn truncate_to(&self, unit: TimeUnit) -> LocalDateTime {
+        unit.bounds(*self).0
+    }
+
+    /// Returns the start of the next unit of time, unless this datetime is
+    /// already at the start of one, rounding it up.
+    pub fn ceil_to(&self, unit: TimeUnit) -> LocalDateTime {
+        let (start, end) = unit.bounds(*self);
+        if start == *self { start } else { end }
+    }
+
+    /// Returns whichever of the start of this unit of time or the start of
+    /// the next one is closer to this datetime, rounding up when it’s
+    /// exactly halfway between the two.
+    pub fn round_to(&self, unit: TimeUnit) -> LocalDateTime {
+        let (start, end) = unit.bounds(*self);
+        if milliseconds_between(start, *self) < milliseconds_between(*self, end) { start } else { end }
+    }
+}
+
+
+impl Instant {
+
+    /// Returns the start of the unit of time that contains this instant,
+    /// rounding it down. The units are measured in UTC.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{Instant, TimeUnit};
+    ///
+    /// let instant = Instant::at_ms(1_706_711_832, 500);
+    /// assert_eq!(instant.truncate_to(TimeUnit::Hours(1)), Instant::at(1_706_709_600));
+    /// ```
+    pub fn truncate_to(&self, unit: TimeUnit) -> Instant {
+        LocalDateTime::from_instant(*self).truncate_to(unit).to_instant()
+    }
+
+    /// Returns the start of the next unit of time, unless this instant is
+    /// already at the start of one, rounding it up. The units are measured
+    /// in UTC.
+    pub fn ceil_to(&self, unit: TimeUnit) -> Instant {
+        LocalDateTime::from_instant(*self).ceil_to(unit).to_instant()
+    }
+
+    /// Returns whichever unit boundary is closer to this instant, rounding
+    /// up when it’s exactly halfway. The units are measured in UTC.
+    pub fn round_to(&self, unit: TimeUnit) -> Instant {
+        LocalDateTime::from_instant(*self).round_to(unit).to_instant()
+    }
+}
+
+
+impl LocalTime {
+
+    /// Returns the start of the unit of time that contains this time,
+    /// rounding it down.
+    ///
+    /// As a time doesn’t have a date, units of a day or longer all truncate
+    /// it to midnight.
+    pub fn truncate_to(&self, unit: TimeUnit) -> LocalTime {
+        on_any_day(*self).truncate_to(within_day(unit)).time()
+    }
+
+    /// Returns the start of the next unit of time, unless this time is
+    /// already at the start of one, rounding it up. Rounding up past the
+    /// end of the day wraps around to midnight.
+    pub fn ceil_to(&self, unit: TimeUnit) -> LocalTime {
+        on_any_day(*self).ceil_to(within_day(unit)).time()
+    }
+
+    /// Returns whichever unit boundary is closer to this time, rounding up
+    /// when it’s exactly halfway. Rounding up past the end of the day wraps
+    /// around to midnight.
+    pub fn round_to(&self, unit: TimeUnit) -> LocalTime {
+        on_any_day(*self).round_to(within_day(unit)).time()
+    }
+}
+
+/// Attaches an arbitrary date to a time, so it can be rounded as a
+/// datetime.
+fn on_any_day(time: LocalTime) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2000, Month::January, 1).unwrap(), time)
+}
+
+/// Replaces the units that are longer than a day with the day itself.
+fn within_day(unit: TimeUnit) -> TimeUnit {
+    match unit {
+        TimeUnit::Week(_) | TimeUnit::Month | TimeUnit::Year  => TimeUnit::Day,
+        other                                                 => other,
+    }
+}
+
+
+impl OffsetDateTime {
+
+    /// Returns the start of the unit of time that contains this datetime,
+    /// rounding it down. The units are measured in this datetime’s own
+    /// offset, so truncating to a day gives its local midnight.
+    pub fn truncate_to(&self, unit: TimeUnit) -> OffsetDateTime {
+        self.offset.transform_date(self.local.truncate_to(unit))
+    }
+
+    /// Returns the start of the next unit of time, unless this datetime is
+    /// already at the start of one, rounding it up.
+    pub fn ceil_to(&self, unit: TimeUnit) -> OffsetDateTime {
+        self.offset.transform_date(self.local.ceil_to(unit))
+    }
+
+    /// Returns whichever unit boundary is closer to this datetime, rounding
+    /// up when it’s exactly halfway.
+    pub fn round_to(&self, unit: TimeUnit) -> OffsetDateTime {
+        self.offset.transform_date(self.local.round_to(unit))
+    }
+}
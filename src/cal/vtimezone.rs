@@ -0,0 +1,482 @@
+//! Time zones in the iCalendar `VTIMEZONE` format.
+//!
+//! iCalendar files carry their own definitions of the time zones that
+//! their events use, so they can be displayed correctly by programs that
+//! don’t have a copy of the zoneinfo database. Each definition is a list of
+//! *observances*, such as standard time or daylight-saving time, along with
+//! the dates that each one starts on, which are usually given as a
+//! recurrence rule.
+
+use std::borrow::Cow;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+use cal::recurrence::{RecurrenceSet, Error as RecurrenceError};
+use cal::zone::{TimeZone, TimeZoneSource, FixedTimespan, FixedTimespanSet};
+use cal::zone::runtime::{OwnedTimeZone, OwnedFixedTimespanSet, RecurringTransition};
+
+
+/// The longest line that iCalendar allows, in bytes, not counting the line
+/// break. Longer lines have to be folded onto the next line.
+const MAX_LINE_LENGTH: usize = 75;
+
+
+/// A **VTIMEZONE** component of an iCalendar file, which describes a time
+/// zone as a set of observances.
+///
+/// ### Examples
+///
+/// ```rust
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, VTimeZone};
+///
+/// let vtimezone: VTimeZone = "BEGIN:VTIMEZONE
+/// TZID:Europe/Berlin
+/// BEGIN:DAYLIGHT
+/// DTSTART:19810329T020000
+/// RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU
+/// TZOFFSETFROM:+0100
+/// TZOFFSETTO:+0200
+/// TZNAME:CEST
+/// END:DAYLIGHT
+/// BEGIN:STANDARD
+/// DTSTART:19961027T030000
+/// RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU
+/// TZOFFSETFROM:+0200
+/// TZOFFSETTO:+0100
+/// TZNAME:CET
+/// END:STANDARD
+/// END:VTIMEZONE".parse().unwrap();
+///
+/// let zone = vtimezone.to_time_zone(2030);
+/// let midsummer = LocalDateTime::new(LocalDate::ymd(2024, Month::June, 21).unwrap(), LocalTime::midnight());
+/// assert_eq!(zone.offset(midsummer), 7200);
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct VTimeZone {
+
+    /// The identifier that events use to refer to this time zone.
+    pub tzid: String,
+
+    /// The observances that make up this time zone.
+    pub observances: Vec<Observance>,
+}
+
+/// An **observance** is one of the offsets that a time zone uses, such as
+/// standard time or daylight-saving time, and the dates that it starts on.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Observance {
+
+    /// Whether this is a `DAYLIGHT` observance, rather than a `STANDARD`
+    /// one.
+    pub is_dst: bool,
+
+    /// The local times at which this observance starts, given in the
+    /// offset that was in effect *before* it started.
+    pub onsets: RecurrenceSet,
+
+    /// The offset from UTC, in seconds, before this observance starts.
+    pub offset_from: i64,
+
+    /// The offset from UTC, in seconds, while this observance is in effect.
+    pub offset_to: i64,
+
+    /// The abbreviation used during this observance, such as “CEST”.
+    pub name: Option<String>,
+}
+
+impl VTimeZone {
+
+    /// Converts this VTIMEZONE into a time zone, which has every one of its
+    /// transitions up to the end of the given year listed up front.
+    ///
+    /// Observances with recurrence rules are also kept in the time zone,
+    /// so times after that year still follow the rules, although the rules
+    /// have to be expanded again each time one of those times is used.
+    /// Pick a year past the times that will usually be needed.
+    pub fn to_time_zone(&self, until_year: i64) -> TimeZone {
+        let mut transitions = Vec::new();
+        let mut recurring = Vec::new();
+
+        for observance in &self.observances {
+            let transition = RecurringTransition {
+                onsets:       observance.onsets.clone(),
+                offset_from:  observance.offset_from,
+                timespan:     FixedTimespan {
+                    offset:  observance.offset_to,
+                    is_dst:  observance.is_dst,
+                    name:    Cow::Owned(observance.name()),
+                },
+            };
+
+            // Observances without rules only happen on their own dates,
+            // so they can be expanded in full.
+            if observance.onsets.rules.is_empty() {
+                transitions.extend(transition.transitions_until(i64::max_value()));
+            }
+            else {
+                transitions.extend(transition.transitions_until(until_year));
+                recurring.push(transition);
+            }
+        }
+
+        transitions.sort_by_key(|t| t.0);
+
+        // Before the first onset, the offset is the one it changes from.
+        let mut first = match self.observances.iter().min_by_key(|o| o.onsets.start) {
+            Some(earliest) => {
+                let previous = self.observances.iter().find(|o| o.offset_to == earliest.offset_from);
+                FixedTimespan {
+                    offset:  earliest.offset_from,
+                    is_dst:  previous.map(|o| o.is_dst).unwrap_or(false),
+                    name:    Cow::Owned(previous.map(|o| o.name()).unwrap_or_else(|| format_offset(earliest.offset_from))),
+                }
+            },
+            None => FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("UTC") },
+        };
+
+        // Time zones can’t have transitions that keep the same offset, so
+        // these are dropped, although one before any other transitions is
+        // used to give the first timespan its name.
+        let mut rest: Vec<(i64, FixedTimespan<'static>)> = Vec::new();
+        for (instant, timespan) in transitions {
+            let offset = rest.last().map(|t| t.1.offset).unwrap_or(first.offset);
+
+            if timespan.offset != offset {
+                rest.push((instant, timespan));
+            }
+            else if rest.is_empty() {
+                first = timespan;
+            }
+        }
+
+        let fixed_timespans = OwnedFixedTimespanSet { first: first, rest: rest };
+        TimeZone(TimeZoneSource::Runtime(Arc::new(OwnedTimeZone::new(Some(self.tzid.clone()), fixed_timespans, recurring))))
+    }
+
+    /// Creates a VTIMEZONE that describes the given time zone from the
+    /// start of the first year to the end of the last one.
+    ///
+    /// Every transition in that range is listed as its own date, rather
+    /// than as part of a recurrence rule, so the result is exact even for
+    /// zones whose rules have changed. The offset in effect at the start of
+    /// the range is given an observance of its own, so the result is still
+    /// useful when there are no transitions at all.
+    pub fn from_time_zone(tzid: &str, zone: &TimeZone, from_year: i64, to_year: i64) -> VTimeZone {
+        match zone.0 {
+            TimeZoneSource::Static(ref tz)   => from_timespans(tzid, &tz.fixed_timespans, from_year, to_year),
+            TimeZoneSource::Runtime(ref arc) => from_timespans(tzid, &arc.timespans_until(to_year + 1).borrow(), from_year, to_year),
+        }
+    }
+}
+
+impl Observance {
+
+    /// Returns the abbreviation for this observance, or the offset it
+    /// changes to if it doesn’t have one.
+    fn name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format_offset(self.offset_to))
+    }
+}
+
+/// Creates a VTIMEZONE from the transitions in a set of timespans.
+fn from_timespans(tzid: &str, timespans: &FixedTimespanSet, from_year: i64, to_year: i64) -> VTimeZone {
+    let start = LocalDateTime::new(LocalDate::ymd(from_year, Month::January, 1).unwrap(), LocalTime::midnight());
+    let end = LocalDateTime::new(LocalDate::ymd(to_year + 1, Month::January, 1).unwrap(), LocalTime::midnight());
+    let (start, end) = (start.to_instant().seconds(), end.to_instant().seconds());
+
+    let mut current = &timespans.first;
+    let mut observances = Vec::new();
+
+    // The range is in local time, so each transition is compared using
+    // the offset from before it.
+    for &(instant, ref timespan) in timespans.rest {
+        let local = instant + current.offset;
+
+        if local >= end {
+            break;
+        }
+        else if local < start {
+            current = timespan;
+            continue;
+        }
+
+        if observances.is_empty() {
+            observances.push(observance_at(start, current, current));
+        }
+
+        let onset = LocalDateTime::at(local);
+        let matching = observances.iter_mut().find(|o: &&mut Observance| {
+            o.is_dst == timespan.is_dst && o.offset_from == current.offset && o.offset_to == timespan.offset
+            && o.name.as_ref().map(|n| &**n) == Some(&*timespan.name)
+        });
+
+        match matching {
+            Some(observance)  => observance.onsets.dates.push(onset),
+            None              => observances.push(observance_at(local, current, timespan)),
+        }
+
+        current = timespan;
+    }
+
+    if observances.is_empty() {
+        observances.push(observance_at(start, current, current));
+    }
+
+    VTimeZone { tzid: tzid.to_owned(), observances: observances }
+}
+
+/// Creates an observance for the change from one timespan to another,
+/// starting at the given local time.
+fn observance_at(local: i64, from: &FixedTimespan, to: &FixedTimespan) -> Observance {
+    Observance {
+        is_dst:       to.is_dst,
+        onsets:       RecurrenceSet::new(LocalDateTime::at(local)),
+        offset_from:  from.offset,
+        offset_to:    to.offset,
+        name:         Some(to.name.to_string()),
+    }
+}
+
+/// Formats an offset in the form iCalendar uses, such as `+0100`, with the
+/// seconds at the end only if there are any.
+fn format_offset(offset: i64) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+
+    if offset % 60 == 0 {
+        format!("{}{:02}{:02}", sign, offset / 3600, offset / 60 % 60)
+    }
+    else {
+        format!("{}{:02}{:02}{:02}", sign, offset / 3600, offset / 60 % 60, offset % 60)
+    }
+}
+
+/// Parses an offset in the form iCalendar uses, such as `-0500`.
+fn parse_offset(input: &str) -> Option<i64> {
+    let sign = match input.chars().next() {
+        Some('+')  => 1,
+        Some('-')  => -1,
+        _          => return None,
+    };
+
+    let digits = &input[1..];
+    if (digits.len() != 4 && digits.len() != 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let number = |from: usize| digits[from .. from + 2].parse::<i64>().unwrap();
+    let seconds = if digits.len() == 6 { number(4) } else { 0 };
+
+    if number(2) >= 60 || seconds >= 60 {
+        return None;
+    }
+
+    Some(sign * (number(0) * 3600 + number(2) * 60 + seconds))
+}
+
+
+impl fmt::Display for VTimeZone {
+
+    /// Writes this VTIMEZONE as part of an iCalendar file, with CRLF line
+    /// breaks and long lines folded.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write_line(f, "BEGIN:VTIMEZONE"));
+        try!(write_line(f, &format!("TZID:{}", self.tzid)));
+
+        for observance in &self.observances {
+            let kind = if observance.is_dst { "DAYLIGHT" } else { "STANDARD" };
+            try!(write_line(f, &format!("BEGIN:{}", kind)));
+
+            for line in observance.onsets.to_string().lines() {
+                try!(write_line(f, line));
+            }
+
+            try!(write_line(f, &format!("TZOFFSETFROM:{}", format_offset(observance.offset_from))));
+            try!(write_line(f, &format!("TZOFFSETTO:{}", format_offset(observance.offset_to))));
+
+            if let Some(ref name) = observance.name {
+                try!(write_line(f, &format!("TZNAME:{}", name)));
+            }
+
+            try!(write_line(f, &format!("END:{}", kind)));
+        }
+
+        write_line(f, "END:VTIMEZONE")
+    }
+}
+
+/// Writes a line of an iCalendar file, folding it onto more lines that
+/// start with a space if it’s too long.
+fn write_line(f: &mut fmt::Formatter, line: &str) -> fmt::Result {
+    let mut rest = line;
+    let mut limit = MAX_LINE_LENGTH;
+
+    while rest.len() > limit {
+        let mut split = limit;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+
+        try!(write!(f, "{}\r\n ", &rest[.. split]));
+        rest = &rest[split ..];
+        limit = MAX_LINE_LENGTH - 1;
+    }
+
+    write!(f, "{}\r\n", rest)
+}
+
+
+impl FromStr for VTimeZone {
+    type Err = Error;
+
+    /// Parses the first VTIMEZONE component in the input, which can be a
+    /// whole iCalendar file. Properties that don’t affect the offsets, such
+    /// as `TZURL` or `COMMENT`, are ignored.
+    fn from_str(input: &str) -> Result<VTimeZone, Self::Err> {
+        let lines = unfold(input);
+        let mut lines = lines.iter().map(|l| &**l).skip_while(|l| !l.eq_ignore_ascii_case("BEGIN:VTIMEZONE"));
+
+        if lines.next().is_none() {
+            return Err(Error::MissingProperty("BEGIN:VTIMEZONE"));
+        }
+
+        let mut tzid = None;
+        let mut observances = Vec::new();
+
+        loop {
+            let line = try!(lines.next().ok_or(Error::MissingProperty("END:VTIMEZONE")));
+            let (name, value) = split_property(line);
+
+            match (&*name, &*value.to_uppercase()) {
+                ("END", "VTIMEZONE")    => break,
+                ("TZID", _)             => tzid = Some(value.to_owned()),
+                ("BEGIN", "STANDARD")   => observances.push(try!(parse_observance(&mut lines, false))),
+                ("BEGIN", "DAYLIGHT")   => observances.push(try!(parse_observance(&mut lines, true))),
+                ("BEGIN", _)            => return Err(Error::InvalidValue(line.to_owned())),
+                _                       => {},
+            }
+        }
+
+        if observances.is_empty() {
+            return Err(Error::MissingProperty("BEGIN:STANDARD"));
+        }
+
+        Ok(VTimeZone {
+            tzid:         try!(tzid.ok_or(Error::MissingProperty("TZID"))),
+            observances:  observances,
+        })
+    }
+}
+
+/// Parses the lines of a `STANDARD` or `DAYLIGHT` component, up to and
+/// including its `END` line.
+fn parse_observance<'a, I: Iterator<Item=&'a str>>(lines: &mut I, is_dst: bool) -> Result<Observance, Error> {
+    let mut recurrence = Vec::new();
+    let mut offset_from = None;
+    let mut offset_to = None;
+    let mut name = None;
+
+    loop {
+        let line = try!(lines.next().ok_or(Error::MissingProperty("END")));
+        let (property, value) = split_property(line);
+        let invalid = || Error::InvalidValue(line.to_owned());
+
+        match &*property {
+            "END"                              => break,
+            "DTSTART" | "RRULE" | "RDATE"      => recurrence.push(line),
+            "TZOFFSETFROM"                     => offset_from = Some(try!(parse_offset(value).ok_or_else(&invalid))),
+            "TZOFFSETTO"                       => offset_to = Some(try!(parse_offset(value).ok_or_else(&invalid))),
+            "TZNAME"                           => name = Some(value.to_owned()),
+            "BEGIN"                            => return Err(invalid()),
+            _                                  => {},
+        }
+    }
+
+    Ok(Observance {
+        is_dst:       is_dst,
+        onsets:       try!(recurrence.join("\n").parse().map_err(Error::Recurrence)),
+        offset_from:  try!(offset_from.ok_or(Error::MissingProperty("TZOFFSETFROM"))),
+        offset_to:    try!(offset_to.ok_or(Error::MissingProperty("TZOFFSETTO"))),
+        name:         name,
+    })
+}
+
+/// Splits a content line into its upper-cased property name, without any
+/// parameters, and its value.
+fn split_property(line: &str) -> (String, &str) {
+    let (name, value) = match line.find(':') {
+        Some(index)  => (&line[.. index], &line[index + 1 ..]),
+        None         => (line, ""),
+    };
+
+    (name.split(';').next().unwrap().to_uppercase(), value)
+}
+
+/// Splits the input into lines, joining any folded lines back together,
+/// and dropping blank ones.
+fn unfold(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in input.lines() {
+        let line = line.trim_right_matches('\r');
+
+        if line.starts_with(' ') || line.starts_with('\t') {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(&line[1..]);
+                continue;
+            }
+        }
+
+        let line = line.trim();
+        if !line.is_empty() {
+            lines.push(line.to_owned());
+        }
+    }
+
+    lines
+}
+
+
+/// An error that occurs when parsing a VTIMEZONE.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Error {
+
+    /// A required property or line is missing.
+    MissingProperty(&'static str),
+
+    /// A line with a value that couldn’t be parsed.
+    InvalidValue(String),
+
+    /// The onsets of an observance couldn’t be parsed.
+    Recurrence(RecurrenceError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingProperty(name)  => write!(f, "{}: {}", self.description(), name),
+            Error::InvalidValue(ref line) => write!(f, "{}: {}", self.description(), line),
+            Error::Recurrence(ref e)      => write!(f, "{}: {}", self.description(), e),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::MissingProperty(_)  => "missing VTIMEZONE property",
+            Error::InvalidValue(_)     => "invalid VTIMEZONE property",
+            Error::Recurrence(_)       => "invalid VTIMEZONE observance onsets",
+        }
+    }
+
+    fn cause(&self) -> Option<&ErrorTrait> {
+        match *self {
+            Error::Recurrence(ref e)  => Some(e),
+            _                         => None,
+        }
+    }
+}
@@ -5,7 +5,8 @@ use std::sync::Arc;
 
 use duration::Duration;
 use instant::Instant;
-use cal::{LocalDateTime, DatePiece, TimePiece, Month, Weekday};
+use cal::{LocalDateTime, DatePiece, TimePiece, ZonePiece, Month, Weekday, Offset};
+use cal::truncate::TimeUnit;
 use util::RangeExt;
 
 
@@ -44,7 +45,7 @@ impl TimeZone {
     pub fn offset(&self, datetime: LocalDateTime) -> i64 {
         match self.0 {
             TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.offset(datetime),
-            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().offset(datetime),
+            TimeZoneSource::Runtime(ref arc) => arc.timespans_until(datetime.year() + 1).borrow().offset(datetime),
         }
     }
 
@@ -54,7 +55,7 @@ impl TimeZone {
     pub fn name(&self, datetime: LocalDateTime) -> String {
         match self.0 {
             TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.name(datetime),
-            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().name(datetime),
+            TimeZoneSource::Runtime(ref arc) => arc.timespans_until(datetime.year() + 1).borrow().name(datetime),
         }
     }
 
@@ -68,7 +69,7 @@ impl TimeZone {
     pub fn is_fixed(&self) -> bool {
         match self.0 {
             TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.is_fixed(),
-            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().is_fixed(),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().is_fixed() && arc.recurring.is_empty(),
         }
     }
 
@@ -97,7 +98,43 @@ impl TimeZone {
     pub fn convert_local(&self, local: LocalDateTime) -> LocalTimes {
         match self.0 {
             TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.convert_local(local, self.0.clone()),
-            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().convert_local(local, self.0.clone()),
+            TimeZoneSource::Runtime(ref arc) => arc.timespans_until(local.year() + 1).borrow().convert_local(local, self.0.clone()),
+        }
+    }
+
+    /// Converts a local datetime that is *already* informally in this time
+    /// zone into a zoned datetime, like `convert_local`, but always picks
+    /// exactly one result, in the same way as RFC 5545.
+    ///
+    /// An ambiguous time uses the earlier of its two offsets, so it refers
+    /// to the first time the wall clock shows it. An impossible time is
+    /// interpreted using the offset from *before* the gap, which moves it
+    /// forward by the length of the gap: 02:30 on a night when the clocks
+    /// go forward from 02:00 to 03:00 becomes 03:30.
+    pub fn resolve_local(&self, local: LocalDateTime) -> ZonedDateTime<'_> {
+        match self.0 {
+            TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.resolve_local(local, self.0.clone()),
+            TimeZoneSource::Runtime(ref arc) => arc.timespans_until(local.year() + 1).borrow().resolve_local(local, self.0.clone()),
+        }
+    }
+
+    /// Converts a local datetime that is *already* informally in this time
+    /// zone into the first moment that a wall clock in this zone shows it.
+    ///
+    /// An ambiguous time uses the earlier of its two offsets. An impossible
+    /// time is never shown, so this returns the moment the clocks change
+    /// to skip over it instead: 02:30 on a night when the clocks go forward
+    /// from 02:00 to 03:00 becomes 03:00.
+    pub fn earliest_local(&self, local: LocalDateTime) -> ZonedDateTime<'_> {
+        let (adjusted, offset) = match self.0 {
+            TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.earliest_at(local, self.0.clone()),
+            TimeZoneSource::Runtime(ref arc) => arc.timespans_until(local.year() + 1).borrow().earliest_at(local, self.0.clone()),
+        };
+
+        ZonedDateTime {
+            adjusted:        adjusted,
+            current_offset:  offset,
+            time_zone:       self.0.clone(),
         }
     }
 }
@@ -162,7 +199,7 @@ impl<'a> FixedTimespanSet<'a> {
         self.rest.is_empty()
     }
 
-    fn convert_local(&self, local: LocalDateTime, source: TimeZoneSource<'a>) -> LocalTimes<'a> {
+    fn convert_local<'z>(&self, local: LocalDateTime, source: TimeZoneSource<'z>) -> LocalTimes<'z> {
         let unix_timestamp = local.to_instant().seconds();
 
         let zonify = |offset| ZonedDateTime {
@@ -178,8 +215,6 @@ impl<'a> FixedTimespanSet<'a> {
             assert!(timespans.current.offset != previous_zone.offset,
                     "Offsets cannot be equal! Is this a non-transition transition?");
 
-            println!("unix timestamp {:?}, previous time {:?}", unix_timestamp, previous_transition_time);
-
             // Test whether this timestamp is in the *overlap* after the
             // current timespan starts but before the previous one ends.
             if previous_zone.offset > timespans.current.offset
@@ -203,9 +238,6 @@ impl<'a> FixedTimespanSet<'a> {
             assert!(timespans.current.offset != next_zone.offset,
                 "Offsets cannot be equal! Is this a non-transition transition?");
 
-            println!("unix timestamp {:?}, next time {:?}", unix_timestamp, next_transition_time);
-            println!("offset 1 {:?}, offset 2 {:?}", next_zone.offset, timespans.current.offset);
-
             // Test whether this timestamp is in the *overlap* after the
             // next timespan starts but before the current one ends.
             if timespans.current.offset > next_zone.offset
@@ -224,9 +256,100 @@ impl<'a> FixedTimespanSet<'a> {
             }
         }
 
+        // The timespan was found by treating the local time as though it
+        // were in UTC, so when the offset is large enough, the actual time
+        // can fall into one of the timespans either side of it.
+        if let Some(&(next_transition_time, ref next_zone)) = timespans.next {
+            if unix_timestamp - timespans.current.offset >= next_transition_time {
+                return LocalTimes::Precise(zonify(next_zone.offset));
+            }
+        }
+
+        if let Some((previous_zone, previous_transition_time)) = timespans.previous {
+            if unix_timestamp - timespans.current.offset < previous_transition_time {
+                return LocalTimes::Precise(zonify(previous_zone.offset));
+            }
+        }
+
         LocalTimes::Precise(zonify(timespans.current.offset))
     }
 
+    /// Returns the first moment at which the given local time appears on a
+    /// wall clock, as the local time itself and the offset in effect. If
+    /// it never appears, because the clocks skip over it, this is the
+    /// moment that the clocks change instead.
+    fn earliest_at(&self, local: LocalDateTime, source: TimeZoneSource) -> (LocalDateTime, i64) {
+        match self.convert_local(local, source) {
+            LocalTimes::Precise(zoned)             => (zoned.adjusted, zoned.current_offset),
+            LocalTimes::Ambiguous { earlier, .. }  => (earlier.adjusted, earlier.current_offset),
+            LocalTimes::Impossible => match self.gap_transition(local) {
+                Some((transition, _, offset))  => (LocalDateTime::at(transition + offset), offset),
+                None                           => (local, self.offset(local)),
+            },
+        }
+    }
+
+    /// Returns the wall-clock time and offset at the given local time, for
+    /// the start of a unit of time containing a time with the given offset.
+    /// When the local time happens twice, the one with that offset is used
+    /// if there is one, so the start is never after the time it was found
+    /// from; otherwise, this is the same as `earliest_at`.
+    fn start_at(&self, local: LocalDateTime, offset: i64, source: TimeZoneSource<'a>) -> (LocalDateTime, i64) {
+        if let LocalTimes::Ambiguous { later, .. } = self.convert_local(local, source.clone()) {
+            if later.current_offset == offset {
+                return (later.adjusted, later.current_offset);
+            }
+        }
+
+        self.earliest_at(local, source)
+    }
+
+    /// Converts the given local time to a zoned time, picking the earlier
+    /// offset for an ambiguous time, and moving an impossible time forward
+    /// by the length of the gap.
+    fn resolve_local<'z>(&self, local: LocalDateTime, source: TimeZoneSource<'z>) -> ZonedDateTime<'z> {
+        match self.convert_local(local, source.clone()) {
+            LocalTimes::Precise(zoned)             => zoned,
+            LocalTimes::Ambiguous { earlier, .. }  => earlier,
+            LocalTimes::Impossible => {
+                let (before, after) = match self.gap_transition(local) {
+                    Some((_, before, after))  => (before, after),
+                    None                      => (self.offset(local), self.offset(local)),
+                };
+
+                ZonedDateTime {
+                    adjusted:        local + Duration::of(after - before),
+                    current_offset:  after,
+                    time_zone:       source,
+                }
+            },
+        }
+    }
+
+    /// Returns the transition that causes the given local time to be
+    /// impossible, along with the offsets before and after it, or `None`
+    /// if the local time isn’t in a gap.
+    fn gap_transition(&self, local: LocalDateTime) -> Option<(i64, i64, i64)> {
+        let unix_timestamp = local.to_instant().seconds();
+        let timespans = self.find_with_surroundings(unix_timestamp);
+
+        if let Some((previous_zone, previous_transition_time)) = timespans.previous {
+            if previous_zone.offset < timespans.current.offset
+            && (unix_timestamp - previous_transition_time).is_within(previous_zone.offset .. timespans.current.offset) {
+                return Some((previous_transition_time, previous_zone.offset, timespans.current.offset));
+            }
+        }
+
+        if let Some(&(next_transition_time, ref next_zone)) = timespans.next {
+            if timespans.current.offset < next_zone.offset
+            && (unix_timestamp - next_transition_time).is_within(timespans.current.offset .. next_zone.offset) {
+                return Some((next_transition_time, timespans.current.offset, next_zone.offset));
+            }
+        }
+
+        None
+    }
+
     fn find_with_surroundings(&self, time: i64) -> Surroundings {
         if let Some((position, _)) = self.rest.iter().enumerate().take_while(|&(_, t)| t.0 < time).last() {
             // There’s a matching time in the ‘rest’ list, so return that
@@ -331,6 +454,36 @@ impl<'a> ZonedDateTime<'a> {
     pub fn to_instant(&self) -> Instant {
         (self.adjusted - Duration::of(self.current_offset)).to_instant()
     }
+
+    /// Returns the local datetime that a wall clock in this datetime’s
+    /// time zone would show.
+    pub fn to_local(&self) -> LocalDateTime {
+        self.adjusted
+    }
+
+    /// Returns the start of the unit of time that contains this datetime,
+    /// as seen on a wall clock in its time zone.
+    ///
+    /// The start of a unit doesn’t always exist: when clocks go forward at
+    /// midnight, the day begins at 01:00 instead, so that’s what truncating
+    /// to a day returns. When the start happens twice because clocks went
+    /// back, the one with this datetime’s offset is used if there is one,
+    /// and the earlier of the two otherwise, so the start is never later
+    /// than this datetime.
+    pub fn truncate_to(&self, unit: TimeUnit) -> ZonedDateTime<'a> {
+        let start = self.adjusted.truncate_to(unit);
+
+        let (adjusted, offset) = match self.time_zone {
+            TimeZoneSource::Static(tz)       => tz.fixed_timespans.start_at(start, self.current_offset, self.time_zone.clone()),
+            TimeZoneSource::Runtime(ref arc) => arc.timespans_until(start.year() + 1).borrow().start_at(start, self.current_offset, self.time_zone.clone()),
+        };
+
+        ZonedDateTime {
+            adjusted:        adjusted,
+            current_offset:  offset,
+            time_zone:       self.time_zone.clone(),
+        }
+    }
 }
 
 impl<'a> DatePiece for ZonedDateTime<'a> {
@@ -348,6 +501,21 @@ impl<'a> TimePiece for ZonedDateTime<'a> {
     fn millisecond(&self) -> i16 { self.adjusted.millisecond() }
 }
 
+impl<'a> ZonePiece for ZonedDateTime<'a> {
+    fn utc_offset(&self) -> Option<Offset> {
+        Offset::of_seconds(self.current_offset as i32).ok()
+    }
+
+    fn zone_abbreviation(&self) -> Option<String> {
+        let utc = self.adjusted - Duration::of(self.current_offset);
+
+        Some(match self.time_zone {
+            TimeZoneSource::Static(tz)       => tz.fixed_timespans.name(utc),
+            TimeZoneSource::Runtime(ref arc) => arc.timespans_until(utc.year() + 1).borrow().name(utc),
+        })
+    }
+}
+
 
 /// The “type” of time that a transition is specified in.
 #[derive(PartialEq, Debug, Copy, Clone)]
@@ -369,15 +537,125 @@ pub enum TimeType {
 }
 
 pub mod runtime {
-    use super::{FixedTimespan, FixedTimespanSet};
+    use std::borrow::Cow;
+    use std::ops::Deref;
+    use std::sync::{Arc, Mutex};
+
+    use cal::{LocalDateTime, DatePiece};
+    use cal::recurrence::RecurrenceSet;
+    use super::{FixedTimespan, FixedTimespanSet, TimeZone, TimeZoneSource};
 
-    #[derive(PartialEq, Debug)]
+    #[derive(Debug)]
     pub struct OwnedTimeZone {
         pub name: Option<String>,
         pub fixed_timespans: OwnedFixedTimespanSet,
+
+        /// Transitions that keep happening after the last one in the set,
+        /// such as the start of daylight-saving time each spring. These
+        /// are expanded on demand for times past the end of the set.
+        pub recurring: Vec<RecurringTransition>,
+
+        /// The timespans with the recurring transitions carried on, along
+        /// with the last year that they reach, kept so that they only get
+        /// expanded again when a later year is needed.
+        extended: Mutex<Option<(i64, Arc<OwnedFixedTimespanSet>)>>,
+    }
+
+    /// The number of years past the one asked for that recurring
+    /// transitions get carried on to at a time.
+    const EXTEND_YEARS: i64 = 10;
+
+    impl OwnedTimeZone {
+
+        /// Creates a new zone from its timespans and the transitions that
+        /// carry on after them.
+        pub fn new(name: Option<String>, fixed_timespans: OwnedFixedTimespanSet, recurring: Vec<RecurringTransition>) -> OwnedTimeZone {
+            OwnedTimeZone {
+                name: name,
+                fixed_timespans: fixed_timespans,
+                recurring: recurring,
+                extended: Mutex::new(None),
+            }
+        }
+
+        /// Returns this zone’s timespans, carrying on its recurring
+        /// transitions after the last one in the set until at least the end
+        /// of the given year. The set is returned as it is if it already
+        /// reaches that year, or if there’s nothing to carry on.
+        ///
+        /// Carried-on sets are kept around, so looking up a year that’s
+        /// already been reached doesn’t expand the transitions again.
+        pub fn timespans_until(&self, year: i64) -> Timespans<'_> {
+            let last = self.fixed_timespans.rest.last().map(|t| t.0);
+            if self.recurring.is_empty() || last.map_or(false, |last| LocalDateTime::at(last).year() >= year) {
+                return Timespans::Fixed(&self.fixed_timespans);
+            }
+
+            // Nothing ever gets left half-written in the cache, so it’s
+            // still fine to use after a panic elsewhere.
+            let mut extended = match self.extended.lock() {
+                Ok(guard)     => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+
+            if let Some((until, ref set)) = *extended {
+                if until >= year {
+                    return Timespans::Extended(set.clone());
+                }
+            }
+
+            // Expand a few years past the one asked for, so that a run of
+            // lookups moving forward through time doesn’t expand the
+            // transitions again for every year.
+            let until = year + EXTEND_YEARS;
+            let mut transitions: Vec<_> = self.recurring.iter()
+                .flat_map(|r| r.transitions_until(until))
+                .filter(|t| last.map_or(true, |last| t.0 > last))
+                .collect();
+            transitions.sort_by_key(|t| t.0);
+
+            // Time zones can’t have transitions that keep the same offset.
+            let mut set = self.fixed_timespans.clone();
+            for (instant, timespan) in transitions {
+                if timespan.offset != set.rest.last().map_or(set.first.offset, |t| t.1.offset) {
+                    set.rest.push((instant, timespan));
+                }
+            }
+
+            let set = Arc::new(set);
+            *extended = Some((until, set.clone()));
+            Timespans::Extended(set)
+        }
+    }
+
+    /// A zone’s timespans, either as they were given or with its recurring
+    /// transitions carried on.
+    #[derive(Debug)]
+    pub enum Timespans<'a> {
+        Fixed(&'a OwnedFixedTimespanSet),
+        Extended(Arc<OwnedFixedTimespanSet>),
+    }
+
+    impl<'a> Deref for Timespans<'a> {
+        type Target = OwnedFixedTimespanSet;
+
+        fn deref(&self) -> &OwnedFixedTimespanSet {
+            match *self {
+                Timespans::Fixed(set)        => set,
+                Timespans::Extended(ref set) => &**set,
+            }
+        }
+    }
+
+    impl PartialEq for OwnedTimeZone {
+        fn eq(&self, other: &OwnedTimeZone) -> bool {
+            self.name == other.name
+                && self.fixed_timespans == other.fixed_timespans
+                && self.recurring == other.recurring
+        }
     }
 
-    #[derive(PartialEq, Debug)]
+    #[derive(PartialEq, Debug, Clone)]
     pub struct OwnedFixedTimespanSet {
         pub first: FixedTimespan<'static>,
         pub rest: Vec<(i64, FixedTimespan<'static>)>,
@@ -391,6 +669,43 @@ pub mod runtime {
             }
         }
     }
+
+    /// A transition into a timespan that happens on a schedule, given as a
+    /// recurrence set of the local times that it happens at.
+    #[derive(PartialEq, Debug, Clone)]
+    pub struct RecurringTransition {
+
+        /// The local times of the transitions, in the offset from before
+        /// each one.
+        pub onsets: RecurrenceSet,
+
+        /// The offset, in seconds, in effect before each transition.
+        pub offset_from: i64,
+
+        /// The timespan that each transition changes to.
+        pub timespan: FixedTimespan<'static>,
+    }
+
+    impl RecurringTransition {
+
+        /// Returns every one of these transitions up to the end of the given
+        /// year, as Unix timestamps paired with the timespan.
+        pub fn transitions_until(&self, year: i64) -> Vec<(i64, FixedTimespan<'static>)> {
+            // The onsets are in the offset from before the transition, so
+            // place them in a zone with only that offset to find the
+            // instants that they happen at.
+            let fixed_timespans = OwnedFixedTimespanSet {
+                first: FixedTimespan { offset: self.offset_from, is_dst: false, name: Cow::Borrowed("") },
+                rest: Vec::new(),
+            };
+            let before = TimeZone(TimeZoneSource::Runtime(Arc::new(OwnedTimeZone::new(None, fixed_timespans, Vec::new()))));
+
+            self.onsets.zoned_occurrences(&before)
+                .take_while(|onset| onset.year() <= year)
+                .map(|onset| (onset.to_instant().seconds(), self.timespan.clone()))
+                .collect()
+        }
+    }
 }
 
 #[cfg(test)]
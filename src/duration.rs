@@ -1,6 +1,11 @@
-//! Lengths of time on the timeline.
+//! Lengths of time on the timeline, and reading and writing them in the
+//! forms used by config files and command-line flags, such as `90s`,
+//! `1h30m`, or `2 days 3 hours`.
 
+use std::error::Error as ErrorTrait;
+use std::fmt;
 use std::ops::{Add, Sub, Mul};
+use std::str::FromStr;
 
 
 /// A **duration** is a length of time on the timeline, irrespective of
@@ -79,3 +84,299 @@ impl Mul<i64> for Duration {
         Duration::of_ms(self.seconds * amount + ms / 1000, (ms % 1000) as i16)
     }
 }
+
+
+/// The units that a duration can be written in, with how many nanoseconds
+/// there are in each. These are the units of Go’s `time.ParseDuration` and
+/// of systemd’s time spans.
+static UNITS: &'static [(&'static str, i128)] = &[
+    ("ns", 1), ("nsec", 1), ("nanosecond", 1), ("nanoseconds", 1),
+    ("us", 1_000), ("µs", 1_000), ("μs", 1_000), ("usec", 1_000), ("microsecond", 1_000), ("microseconds", 1_000),
+    ("ms", 1_000_000), ("msec", 1_000_000), ("millisecond", 1_000_000), ("milliseconds", 1_000_000),
+    ("s", NANOS_PER_SECOND), ("sec", NANOS_PER_SECOND), ("second", NANOS_PER_SECOND), ("seconds", NANOS_PER_SECOND),
+    ("m", 60 * NANOS_PER_SECOND), ("min", 60 * NANOS_PER_SECOND), ("minute", 60 * NANOS_PER_SECOND), ("minutes", 60 * NANOS_PER_SECOND),
+    ("h", 3_600 * NANOS_PER_SECOND), ("hr", 3_600 * NANOS_PER_SECOND), ("hour", 3_600 * NANOS_PER_SECOND), ("hours", 3_600 * NANOS_PER_SECOND),
+    ("d", 86_400 * NANOS_PER_SECOND), ("day", 86_400 * NANOS_PER_SECOND), ("days", 86_400 * NANOS_PER_SECOND),
+    ("w", 604_800 * NANOS_PER_SECOND), ("week", 604_800 * NANOS_PER_SECOND), ("weeks", 604_800 * NANOS_PER_SECOND),
+];
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// The units that durations are written in, from the largest to the
+/// smallest, with how many milliseconds there are in each, and their
+/// short and long English names.
+static WRITTEN_UNITS: [(i128, &'static str, &'static str); 6] = [
+    (604_800_000, "w",  "week"),
+    (86_400_000,  "d",  "day"),
+    (3_600_000,   "h",  "hour"),
+    (60_000,      "m",  "minute"),
+    (1_000,       "s",  "second"),
+    (1,           "ms", "millisecond"),
+];
+
+impl Duration {
+
+    /// Returns the length of this duration in milliseconds.
+    fn total_milliseconds(&self) -> i128 {
+        self.seconds as i128 * 1000 + self.milliseconds as i128
+    }
+
+    /// Splits this duration into a count of each written unit, skipping
+    /// the ones with none. Negative durations have positive counts.
+    fn written_units(&self) -> Vec<(i128, &'static str, &'static str)> {
+        let mut remaining = self.total_milliseconds().abs();
+        let mut counts = Vec::new();
+
+        for &(length, short, long) in WRITTEN_UNITS.iter() {
+            if remaining >= length {
+                counts.push((remaining / length, short, long));
+                remaining %= length;
+            }
+        }
+
+        counts
+    }
+
+    /// Writes this duration in English, with a space between each unit,
+    /// such as `2 days 3 hours`. Like the compact form, this can be read
+    /// back in.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Duration;
+    ///
+    /// assert_eq!(Duration::of(5400).to_long_string(), "1 hour 30 minutes");
+    /// assert_eq!(Duration::of_ms(1, 500).to_long_string(), "1 second 500 milliseconds");
+    /// assert_eq!(Duration::zero().to_long_string(), "0 seconds");
+    /// ```
+    pub fn to_long_string(&self) -> String {
+        let counts = self.written_units();
+        if counts.is_empty() {
+            return "0 seconds".to_string();
+        }
+
+        let words: Vec<String> = counts.iter()
+            .map(|&(count, _, name)| format!("{} {}{}", count, name, if count == 1 { "" } else { "s" }))
+            .collect();
+
+        let sign = if self.seconds < 0 { "-" } else { "" };
+        format!("{}{}", sign, words.join(" "))
+    }
+}
+
+/// Durations are written in a compact form, with each unit’s count
+/// followed by its abbreviation, from weeks down to milliseconds, such as
+/// `1h30m` or `2w3d`. Units with a count of zero are left out, apart from
+/// a zero-length duration, which is `0s`.
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts = self.written_units();
+        if counts.is_empty() {
+            return write!(f, "0s");
+        }
+
+        if self.seconds < 0 {
+            try!(write!(f, "-"));
+        }
+
+        for &(count, name, _) in &counts {
+            try!(write!(f, "{}{}", count, name));
+        }
+
+        Ok(())
+    }
+}
+
+/// Durations are read as a sequence of numbers, each followed by a unit,
+/// such as `1h30m`, `1.5h`, or `2 days 3 hours`, with optional spaces
+/// between them, and an optional sign at the start. The units are `ns`,
+/// `us` or `µs`, `ms`, `s`, `m`, `h`, `d`, and `w`, along with the longer
+/// names that systemd accepts, such as `sec`, `min`, or `hours`. A `0` on
+/// its own needs no unit.
+///
+/// Durations only count milliseconds, so anything smaller is dropped.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::Duration;
+///
+/// assert_eq!("90s".parse(), Ok(Duration::of(90)));
+/// assert_eq!("1h30m".parse(), Ok(Duration::of(5400)));
+/// assert_eq!("1.5h".parse(), Ok(Duration::of(5400)));
+/// assert_eq!("2 days 3 hours".parse(), Ok(Duration::of(183_600)));
+/// ```
+impl FromStr for Duration {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Duration, Self::Err> {
+        let mut pos = skip_spaces(input, 0);
+        if pos == input.len() {
+            return Err(Error::Empty);
+        }
+
+        let negative = input[pos..].starts_with('-');
+        if negative || input[pos..].starts_with('+') {
+            pos += 1;
+        }
+
+        let mut nanoseconds: i128 = 0;
+        loop {
+            let number_pos = skip_spaces(input, pos);
+            let (number, number_end) = try!(parse_number(input, number_pos));
+            let unit_pos = skip_spaces(input, number_end);
+            let unit_end = input[unit_pos..].find(|c: char| !c.is_alphabetic()).map_or(input.len(), |length| unit_pos + length);
+
+            if unit_pos == unit_end {
+                // Only a lone zero can leave out its unit.
+                if number.whole == 0 && number.fraction == 0 && nanoseconds == 0 && skip_spaces(input, unit_end) == input.len() {
+                    return Ok(Duration::zero());
+                }
+
+                return Err(Error::MissingUnit { pos: unit_pos });
+            }
+
+            let unit = &input[unit_pos .. unit_end];
+            let unit_length = match UNITS.iter().find(|&&(name, _)| name == unit) {
+                Some(&(_, length))  => length,
+                None                => return Err(Error::UnknownUnit { unit: unit.to_owned(), pos: unit_pos }),
+            };
+
+            nanoseconds = try!(number.times(unit_length)
+                                     .and_then(|length| nanoseconds.checked_add(length))
+                                     .ok_or(Error::Overflow { pos: number_pos }));
+
+            pos = skip_spaces(input, unit_end);
+            if pos == input.len() {
+                break;
+            }
+        }
+
+        let milliseconds = nanoseconds / 1_000_000;
+        let (mut seconds, mut milliseconds) = (milliseconds / 1000, milliseconds % 1000);
+        if negative && milliseconds > 0 {
+            seconds = -seconds - 1;
+            milliseconds = 1000 - milliseconds;
+        }
+        else if negative {
+            seconds = -seconds;
+        }
+
+        if seconds > i64::max_value() as i128 || seconds < i64::min_value() as i128 {
+            return Err(Error::Overflow { pos: 0 });
+        }
+
+        Ok(Duration::of_ms(seconds as i64, milliseconds as i16))
+    }
+}
+
+/// A number that was read, with its whole part and its fractional part,
+/// which is the digits after the point as a number, and how many of them
+/// there were.
+struct Number {
+    whole: i128,
+    fraction: i128,
+    fraction_digits: u32,
+}
+
+impl Number {
+
+    /// Multiplies this number by a unit’s length, rounding down, or
+    /// returns `None` if that overflows.
+    fn times(&self, unit_length: i128) -> Option<i128> {
+        let fraction = self.fraction * unit_length / 10_i128.pow(self.fraction_digits);
+        self.whole.checked_mul(unit_length).and_then(|whole| whole.checked_add(fraction))
+    }
+}
+
+/// Reads a number, such as `90`, `1.5`, or `.5`, returning it and the
+/// position after it. Digits after the eighteenth past the point are read
+/// but ignored.
+fn parse_number(input: &str, pos: usize) -> Result<(Number, usize), Error> {
+    let rest = &input[pos..];
+    let whole_length = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let mut number = Number { whole: 0, fraction: 0, fraction_digits: 0 };
+    let mut end = pos + whole_length;
+
+    for b in rest[.. whole_length].bytes() {
+        number.whole = try!(number.whole.checked_mul(10).and_then(|n| n.checked_add((b - b'0') as i128)).ok_or(Error::Overflow { pos: pos }));
+    }
+
+    if rest[whole_length..].starts_with('.') {
+        let fraction = &rest[whole_length + 1 ..];
+        let fraction_length = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+
+        for b in fraction[.. fraction_length].bytes().take(18) {
+            number.fraction = number.fraction * 10 + (b - b'0') as i128;
+            number.fraction_digits += 1;
+        }
+
+        if whole_length == 0 && fraction_length == 0 {
+            return Err(Error::InvalidNumber { pos: pos });
+        }
+
+        end += 1 + fraction_length;
+    }
+    else if whole_length == 0 {
+        return Err(Error::InvalidNumber { pos: pos });
+    }
+
+    Ok((number, end))
+}
+
+/// Returns the position of the first character at or after the given one
+/// that isn’t whitespace.
+fn skip_spaces(input: &str, pos: usize) -> usize {
+    let rest = &input[pos..];
+    pos + rest.len() - rest.trim_left().len()
+}
+
+
+/// An error that occurs when reading a duration.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Error {
+
+    /// The input was empty.
+    Empty,
+
+    /// There was something other than a number where a number should be,
+    /// at the given position.
+    InvalidNumber { pos: usize },
+
+    /// A number, other than a lone zero, wasn’t followed by a unit. The
+    /// position is where the unit should be.
+    MissingUnit { pos: usize },
+
+    /// A number was followed by a unit that isn’t recognised, which
+    /// starts at the given position.
+    UnknownUnit { unit: String, pos: usize },
+
+    /// The duration is too long to be stored. The position is that of the
+    /// number that made it too long.
+    Overflow { pos: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Empty                          => write!(f, "{}", self.description()),
+            Error::InvalidNumber { pos }          => write!(f, "{} at position {}", self.description(), pos),
+            Error::MissingUnit { pos }            => write!(f, "{} at position {}", self.description(), pos),
+            Error::UnknownUnit { ref unit, pos }  => write!(f, "{} ‘{}’ at position {}", self.description(), unit, pos),
+            Error::Overflow { pos }               => write!(f, "{} at position {}", self.description(), pos),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Empty               => "empty duration",
+            Error::InvalidNumber { .. } => "expected a number",
+            Error::MissingUnit { .. }  => "missing unit in duration",
+            Error::UnknownUnit { .. }  => "unknown unit in duration",
+            Error::Overflow { .. }     => "duration is too long",
+        }
+    }
+}
@@ -0,0 +1,424 @@
+//! Conversions between instants and the timestamp formats of other systems.
+//!
+//! Every format here counts time from its own epoch, in its own units, so
+//! each pair of functions converts between that and an `Instant`. They’re
+//! all done with checked integer arithmetic, returning an error rather
+//! than wrapping around when a timestamp can’t be represented on the other
+//! side. Formats that are more precise than a millisecond are truncated
+//! towards the past when read.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+use instant::Instant;
+
+
+/// Seconds from the Windows FILETIME epoch, 1601-01-01, to the Unix epoch.
+const FILETIME_EPOCH: i64 = 11_644_473_600;
+
+/// Seconds from the NTP epoch, 1900-01-01, to the Unix epoch.
+const NTP_EPOCH: i64 = 2_208_988_800;
+
+/// Seconds from the Unix epoch to the Cocoa epoch, 2001-01-01.
+const COCOA_EPOCH: i64 = 978_307_200;
+
+/// Seconds from the Unix epoch to the GPS epoch, 1980-01-06.
+const GPS_EPOCH: i64 = 315_964_800;
+
+/// Seconds from the .NET epoch, 0001-01-01, to the Unix epoch.
+const DOTNET_EPOCH: i64 = 62_135_596_800;
+
+/// The number of ticks in .NET’s `DateTime.MaxValue`, the last tick of the
+/// 31st of December, 9999.
+const DOTNET_MAX_TICKS: i64 = 3_155_378_975_999_999_999;
+
+/// Seconds from the 1900 spreadsheet epoch, 1899-12-30, to the Unix epoch.
+const EXCEL_1900_EPOCH: i64 = 2_209_161_600;
+
+/// Seconds from the 1904 spreadsheet epoch, 1904-01-01, to the Unix epoch.
+const EXCEL_1904_EPOCH: i64 = 2_082_844_800;
+
+/// Number of 100-nanosecond ticks in a millisecond.
+const TICKS_PER_MILLISECOND: i64 = 10_000;
+
+/// Number of milliseconds in a day.
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+/// Number of milliseconds in a week.
+const MILLISECONDS_PER_WEEK: i64 = 7 * MILLISECONDS_PER_DAY;
+
+/// The difference between GPS time and UTC, in seconds, which has stood at
+/// 18 since the leap second at the end of 2016.
+///
+/// GPS time doesn’t have leap seconds, so it drifts further ahead of UTC
+/// each time one is added. Pass a different value to the GPS functions
+/// when dealing with timestamps from before 2017.
+pub const GPS_LEAP_SECONDS: i64 = 18;
+
+
+/// Creates an instant from a Windows **FILETIME**: the number of
+/// 100-nanosecond intervals since midnight on the 1st of January, 1601.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::Instant;
+/// use datetime::epoch;
+///
+/// assert_eq!(epoch::from_filetime(116_444_736_000_000_000), Instant::at_epoch());
+/// ```
+pub fn from_filetime(filetime: u64) -> Instant {
+    // A u64 of ticks is always well within range in milliseconds.
+    let milliseconds = (filetime / TICKS_PER_MILLISECOND as u64) as i64;
+    Instant::from_total_milliseconds(milliseconds - FILETIME_EPOCH * 1000)
+}
+
+/// Returns the Windows **FILETIME** at the given instant, or an error if
+/// it’s before 1601.
+pub fn to_filetime(instant: Instant) -> Result<u64, Error> {
+    let milliseconds = try!(milliseconds_since(instant, -FILETIME_EPOCH));
+    if milliseconds < 0 {
+        return Err(Error::OutOfRange);
+    }
+
+    let ticks = try!(milliseconds.checked_mul(TICKS_PER_MILLISECOND).ok_or(Error::OutOfRange));
+    Ok(ticks as u64)
+}
+
+
+/// Creates an instant from a 64-bit **NTP timestamp**, which has the
+/// number of seconds since midnight on the 1st of January, 1900, in its
+/// upper 32 bits, and the fraction of the second in its lower 32 bits.
+///
+/// Only the first NTP era, which ends in February 2036, is supported, as
+/// the timestamp doesn’t say which era it’s in.
+pub fn from_ntp(timestamp: u64) -> Instant {
+    let seconds = (timestamp >> 32) as i64;
+    let fraction = timestamp & 0xFFFF_FFFF;
+    let milliseconds = (fraction * 1000) >> 32;
+
+    Instant::at_ms(seconds - NTP_EPOCH, milliseconds as i16)
+}
+
+/// Returns the 64-bit **NTP timestamp** at the given instant, or an error
+/// if it falls outside the first NTP era, from 1900 to 2036.
+pub fn to_ntp(instant: Instant) -> Result<u64, Error> {
+    let milliseconds = try!(milliseconds_since(instant, -NTP_EPOCH));
+    if milliseconds < 0 || milliseconds / 1000 > 0xFFFF_FFFF {
+        return Err(Error::OutOfRange);
+    }
+
+    // Round the fraction up, so that reading it back in truncates to the
+    // same millisecond.
+    let seconds = (milliseconds / 1000) as u64;
+    let fraction = ((((milliseconds % 1000) as u64) << 32) + 999) / 1000;
+    Ok(seconds << 32 | fraction)
+}
+
+
+/// Creates an instant from a **Cocoa absolute time**, as used by Core Data
+/// and `NSDate`: the number of seconds since midnight on the 1st of
+/// January, 2001. The result is rounded to the nearest millisecond.
+///
+/// This returns an error if the number isn’t finite, or is too large to be
+/// represented.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::Instant;
+/// use datetime::epoch;
+///
+/// assert_eq!(epoch::from_cocoa(0.0), Ok(Instant::at(978_307_200)));
+/// ```
+pub fn from_cocoa(seconds: f64) -> Result<Instant, Error> {
+    let milliseconds = try!(float_to_integer(seconds * 1000.0));
+    let milliseconds = try!(milliseconds.checked_add(COCOA_EPOCH * 1000).ok_or(Error::OutOfRange));
+    Ok(Instant::from_total_milliseconds(milliseconds))
+}
+
+/// Returns the **Cocoa absolute time** at the given instant.
+pub fn to_cocoa(instant: Instant) -> Result<f64, Error> {
+    let milliseconds = try!(milliseconds_since(instant, COCOA_EPOCH));
+    Ok(milliseconds as f64 / 1000.0)
+}
+
+
+/// Creates an instant from a **GPS time**, given as a week number and
+/// the number of milliseconds into that week, counting from midnight on
+/// the 6th of January, 1980.
+///
+/// The week number is the full one, rather than the ten-bit number that
+/// the satellites broadcast, which rolls over every 1024 weeks. The number
+/// of leap seconds between GPS time and UTC has to be given, as GPS time
+/// doesn’t have them; `GPS_LEAP_SECONDS` is the current value.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+/// use datetime::epoch;
+///
+/// let instant = epoch::from_gps(2296, 18_000, epoch::GPS_LEAP_SECONDS).unwrap();
+/// let date = LocalDate::ymd(2024, Month::January, 7).unwrap();
+/// assert_eq!(LocalDateTime::from_instant(instant), LocalDateTime::new(date, LocalTime::midnight()));
+/// ```
+pub fn from_gps(week: u32, milliseconds_of_week: u32, leap_seconds: i64) -> Result<Instant, Error> {
+    if milliseconds_of_week as i64 >= MILLISECONDS_PER_WEEK {
+        return Err(Error::InvalidValue);
+    }
+
+    let milliseconds = week as i64 * MILLISECONDS_PER_WEEK + milliseconds_of_week as i64;
+    let offset = try!(leap_seconds.checked_mul(1000).ok_or(Error::OutOfRange));
+    let milliseconds = try!(milliseconds.checked_sub(offset).ok_or(Error::OutOfRange));
+    let milliseconds = try!(milliseconds.checked_add(GPS_EPOCH * 1000).ok_or(Error::OutOfRange));
+    Ok(Instant::from_total_milliseconds(milliseconds))
+}
+
+/// Returns the **GPS time** at the given instant, as a week number and the
+/// number of milliseconds into that week, or an error if the instant is
+/// before the GPS epoch. See `from_gps`.
+pub fn to_gps(instant: Instant, leap_seconds: i64) -> Result<(u32, u32), Error> {
+    let milliseconds = try!(milliseconds_since(instant, GPS_EPOCH));
+    let offset = try!(leap_seconds.checked_mul(1000).ok_or(Error::OutOfRange));
+    let milliseconds = try!(milliseconds.checked_add(offset).ok_or(Error::OutOfRange));
+
+    let week = milliseconds / MILLISECONDS_PER_WEEK;
+    if milliseconds < 0 || week > u32::max_value() as i64 {
+        return Err(Error::OutOfRange);
+    }
+
+    Ok((week as u32, (milliseconds % MILLISECONDS_PER_WEEK) as u32))
+}
+
+
+/// Creates an instant from a number of **.NET ticks**, as returned by
+/// `DateTime.Ticks`: the number of 100-nanosecond intervals since midnight
+/// on the 1st of January in the year 1.
+///
+/// This returns an error if the number is outside the range of a .NET
+/// `DateTime`, which stops at the end of the year 9999.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::Instant;
+/// use datetime::epoch;
+///
+/// assert_eq!(epoch::from_dotnet_ticks(621_355_968_000_000_000), Ok(Instant::at_epoch()));
+/// ```
+pub fn from_dotnet_ticks(ticks: i64) -> Result<Instant, Error> {
+    if ticks < 0 || ticks > DOTNET_MAX_TICKS {
+        return Err(Error::OutOfRange);
+    }
+
+    Ok(Instant::from_total_milliseconds(ticks / TICKS_PER_MILLISECOND - DOTNET_EPOCH * 1000))
+}
+
+/// Returns the number of **.NET ticks** at the given instant, or an error
+/// if it’s outside the range of a .NET `DateTime`.
+pub fn to_dotnet_ticks(instant: Instant) -> Result<i64, Error> {
+    let milliseconds = try!(milliseconds_since(instant, -DOTNET_EPOCH));
+    let ticks = try!(milliseconds.checked_mul(TICKS_PER_MILLISECOND).ok_or(Error::OutOfRange));
+
+    if ticks < 0 || ticks > DOTNET_MAX_TICKS {
+        return Err(Error::OutOfRange);
+    }
+
+    Ok(ticks)
+}
+
+
+/// The two ways that spreadsheets number their dates.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SerialDateSystem {
+
+    /// The default system, inherited from Lotus 1-2-3, where day 1 is the
+    /// 1st of January, 1900. Lotus treated 1900 as a leap year, so day 60
+    /// is the nonexistent 29th of February, and every day after that is
+    /// counted from the 30th of December, 1899.
+    Excel1900,
+
+    /// The system used by early Macintosh spreadsheets, where day 0 is the
+    /// 1st of January, 1904.
+    Excel1904,
+}
+
+impl SerialDateSystem {
+
+    /// The number of seconds between this system’s epoch and the Unix
+    /// epoch, and the last serial day that spreadsheets allow, which is the
+    /// 31st of December, 9999, in both systems.
+    fn epoch_and_limit(&self) -> (i64, i64) {
+        match *self {
+            SerialDateSystem::Excel1900 => (EXCEL_1900_EPOCH, 2_958_465),
+            SerialDateSystem::Excel1904 => (EXCEL_1904_EPOCH, 2_957_003),
+        }
+    }
+}
+
+/// Creates an instant from a spreadsheet **serial date**: a number of days,
+/// with the time of day as the fraction, in the given date system. The
+/// result is rounded to the nearest millisecond.
+///
+/// This returns an error for the 29th of February, 1900, which the 1900
+/// system has but which never existed, and for serial dates outside the
+/// range that spreadsheets accept.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+/// use datetime::epoch::{self, SerialDateSystem};
+///
+/// let instant = epoch::from_serial_date(45_322.5, SerialDateSystem::Excel1900).unwrap();
+/// let date = LocalDate::ymd(2024, Month::January, 31).unwrap();
+/// assert_eq!(LocalDateTime::from_instant(instant), LocalDateTime::new(date, LocalTime::hms(12, 0, 0).unwrap()));
+/// ```
+pub fn from_serial_date(serial: f64, system: SerialDateSystem) -> Result<Instant, Error> {
+    let (epoch, limit) = system.epoch_and_limit();
+    let mut milliseconds = try!(float_to_integer(serial * MILLISECONDS_PER_DAY as f64));
+
+    if milliseconds < 0 || milliseconds >= (limit + 1) * MILLISECONDS_PER_DAY {
+        return Err(Error::OutOfRange);
+    }
+
+    if system == SerialDateSystem::Excel1900 {
+        if milliseconds >= 60 * MILLISECONDS_PER_DAY && milliseconds < 61 * MILLISECONDS_PER_DAY {
+            return Err(Error::InvalidValue);
+        }
+        else if milliseconds < 60 * MILLISECONDS_PER_DAY {
+            milliseconds += MILLISECONDS_PER_DAY;
+        }
+    }
+
+    Ok(Instant::from_total_milliseconds(milliseconds - epoch * 1000))
+}
+
+/// Returns the spreadsheet **serial date** at the given instant, in the
+/// given date system, or an error if it’s outside the range that
+/// spreadsheets accept. See `from_serial_date`.
+pub fn to_serial_date(instant: Instant, system: SerialDateSystem) -> Result<f64, Error> {
+    let (epoch, limit) = system.epoch_and_limit();
+    let mut milliseconds = try!(milliseconds_since(instant, -epoch));
+
+    if system == SerialDateSystem::Excel1900 && milliseconds < 61 * MILLISECONDS_PER_DAY {
+        milliseconds -= MILLISECONDS_PER_DAY;
+    }
+
+    if milliseconds < 0 || milliseconds >= (limit + 1) * MILLISECONDS_PER_DAY {
+        return Err(Error::OutOfRange);
+    }
+
+    Ok(milliseconds as f64 / MILLISECONDS_PER_DAY as f64)
+}
+
+
+/// Creates a date-time from an MS-DOS **packed date and time**, as found in
+/// FAT directory entries and ZIP archives.
+///
+/// The date has the year since 1980 in its top seven bits, then four bits
+/// of month and five of day; the time has five bits of hour, six of
+/// minute, and five of *half* the second. There’s no time zone, so this
+/// gives a local date-time rather than an instant: use its `to_instant`
+/// method if the timestamp is known to be in UTC.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+/// use datetime::epoch;
+///
+/// let date_time = epoch::from_dos(0x5821, 0x6000).unwrap();
+/// let date = LocalDate::ymd(2024, Month::January, 1).unwrap();
+/// assert_eq!(date_time, LocalDateTime::new(date, LocalTime::hms(12, 0, 0).unwrap()));
+/// ```
+pub fn from_dos(date: u16, time: u16) -> Result<LocalDateTime, Error> {
+    let year   = 1980 + (date >> 9) as i64;
+    let month  = ((date >> 5) & 0x0F) as i8;
+    let day    = (date & 0x1F) as i8;
+    let hour   = (time >> 11) as i8;
+    let minute = ((time >> 5) & 0x3F) as i8;
+    let second = ((time & 0x1F) * 2) as i8;
+
+    let month = try!(Month::from_one(month).map_err(|_| Error::InvalidValue));
+    let date = try!(LocalDate::ymd(year, month, day).map_err(|_| Error::InvalidValue));
+    let time = try!(LocalTime::hms_ms(hour, minute, second, 0).map_err(|_| Error::InvalidValue));
+    Ok(LocalDateTime::new(date, time))
+}
+
+/// Returns the MS-DOS **packed date and time** of the given date-time, as a
+/// `(date, time)` pair, or an error if the year is outside the range of
+/// 1980 to 2107. Odd seconds are rounded down. See `from_dos`.
+pub fn to_dos(date_time: LocalDateTime) -> Result<(u16, u16), Error> {
+    let years = date_time.year() - 1980;
+    if years < 0 || years > 0x7F {
+        return Err(Error::OutOfRange);
+    }
+
+    let date = (years as u16) << 9
+             | (date_time.month() as u16) << 5
+             | date_time.day() as u16;
+
+    let time = (date_time.hour() as u16) << 11
+             | (date_time.minute() as u16) << 5
+             | (date_time.second() / 2) as u16;
+
+    Ok((date, time))
+}
+
+
+/// Returns the number of milliseconds between the epoch, given as a number
+/// of seconds since the Unix epoch, and the instant.
+fn milliseconds_since(instant: Instant, epoch: i64) -> Result<i64, Error> {
+    instant.seconds().checked_sub(epoch)
+        .and_then(|seconds| seconds.checked_mul(1000))
+        .and_then(|ms| ms.checked_add(instant.milliseconds() as i64))
+        .ok_or(Error::OutOfRange)
+}
+
+/// Rounds a float to the nearest integer, returning an error if it isn’t
+/// finite or doesn’t fit in an `i64`.
+fn float_to_integer(number: f64) -> Result<i64, Error> {
+    let rounded = number.round();
+
+    // 2⁶³ is exactly representable as a float, but the largest i64 isn’t.
+    if rounded.is_finite() && rounded >= -9_223_372_036_854_775_808.0 && rounded < 9_223_372_036_854_775_808.0 {
+        Ok(rounded as i64)
+    }
+    else {
+        Err(Error::OutOfRange)
+    }
+}
+
+
+/// An error that occurs when converting to or from another system’s
+/// timestamp format.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum Error {
+
+    /// The timestamp is outside the range that the other format, or an
+    /// `Instant`, can represent.
+    OutOfRange,
+
+    /// The timestamp doesn’t describe a real point in time, such as a DOS
+    /// date with a thirteenth month.
+    InvalidValue,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::OutOfRange    => "timestamp out of range",
+            Error::InvalidValue  => "invalid timestamp",
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Instants in the format used by HTTP headers such as `Date`, `Expires`,
+//! and `Last-Modified`, as described in section 5.6.7 of RFC 9110.
+//!
+//! HTTP dates are always in UTC, and are always written as an
+//! **IMF-fixdate**, such as `Sun, 06 Nov 1994 08:49:37 GMT`. Recipients
+//! also have to accept two obsolete formats:
+//!
+//! - the **RFC 850** format, `Sunday, 06-Nov-94 08:49:37 GMT`, which has
+//!   the full name of the day and a two-digit year;
+//! - C’s **asctime** format, `Sun Nov  6 08:49:37 1994`.
+//!
+//! ### Examples
+//!
+//! ```
+//! use datetime::Instant;
+//! use datetime::http_date;
+//!
+//! let instant = http_date::parse("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+//! assert_eq!(instant, Instant::at(784_111_777));
+//! assert_eq!(http_date::parse("Sun Nov  6 08:49:37 1994"), Ok(instant));
+//!
+//! assert_eq!(http_date::format(instant).to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+//! ```
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Error as DateTimeError};
+use cal::rfc2822::{WEEKDAY_NAMES, MONTH_NAMES};
+use instant::Instant;
+
+
+/// The full names of the days of the week, starting with Sunday, as used
+/// in RFC 850 dates. The other formats use the same abbreviations as
+/// RFC 2822.
+const FULL_WEEKDAY_NAMES: [&'static str; 7] = [ "Sunday", "Monday", "Tuesday", "Wednesday",
+                                                "Thursday", "Friday", "Saturday" ];
+
+
+/// Returns a value that displays the given instant as an IMF-fixdate,
+/// such as `Sun, 06 Nov 1994 08:49:37 GMT`, without allocating.
+///
+/// Milliseconds are left out. Years before 1 or after 9999 can’t be
+/// written in four digits, and are not valid in HTTP headers.
+pub fn format(instant: Instant) -> HttpDate {
+    HttpDate(instant)
+}
+
+/// An instant that gets displayed as an IMF-fixdate. This is returned from
+/// the `format` function.
+#[derive(Debug, Copy, Clone)]
+pub struct HttpDate(Instant);
+
+impl fmt::Display for HttpDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let datetime = LocalDateTime::from_instant(self.0);
+        let (date, time) = (datetime.date(), datetime.time());
+
+        write!(f, "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+               WEEKDAY_NAMES[date.weekday() as usize], date.day(),
+               MONTH_NAMES[date.month().months_from_january()], date.year(),
+               time.hour(), time.minute(), time.second())
+    }
+}
+
+
+/// Parses an HTTP date in any of the three formats.
+///
+/// RFC 850 dates only have two digits for the year, so they’re taken to be
+/// in the century that puts them no more than 50 years after the current
+/// time. Use `parse_at` to give a different time to compare against.
+pub fn parse(input: &str) -> Result<Instant, Error> {
+    parse_at(input, Instant::now())
+}
+
+/// Parses an HTTP date in any of the three formats, using the given
+/// instant as the current time when working out the century of a
+/// two-digit year.
+pub fn parse_at(input: &str, now: Instant) -> Result<Instant, Error> {
+    let input = input.as_bytes();
+
+    let datetime = if input.get(3) == Some(&b',') {
+        try!(parse_imf_fixdate(input))
+    }
+    else if input.contains(&b',') {
+        try!(parse_rfc850(input, LocalDateTime::from_instant(now).year()))
+    }
+    else {
+        try!(parse_asctime(input))
+    };
+
+    Ok(datetime.to_instant())
+}
+
+/// Parses an IMF-fixdate, such as `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn parse_imf_fixdate(input: &[u8]) -> Result<LocalDateTime, Error> {
+    if input.len() != 29 || &input[3 .. 5] != b", " || input[7] != b' ' || input[11] != b' '
+    || input[16] != b' ' || &input[25 ..] != b" GMT" {
+        return Err(Error::Syntax);
+    }
+
+    let weekday = try!(weekday(&input[.. 3], true));
+    let day = try!(number(&input[5 .. 7]));
+    let month = try!(month(&input[8 .. 11]));
+    let year = try!(number(&input[12 .. 16]));
+    let time = try!(time_of_day(&input[17 .. 25]));
+
+    datetime(weekday, year, month, day, time)
+}
+
+/// Parses an RFC 850 date, such as `Sunday, 06-Nov-94 08:49:37 GMT`,
+/// deciding the century based on the given current year.
+fn parse_rfc850(input: &[u8], current_year: i64) -> Result<LocalDateTime, Error> {
+    let comma = input.iter().position(|&b| b == b',').unwrap();
+    let (name, rest) = (&input[.. comma], &input[comma ..]);
+
+    if rest.len() != 24 || &rest[.. 2] != b", " || rest[4] != b'-' || rest[8] != b'-'
+    || rest[11] != b' ' || &rest[20 ..] != b" GMT" {
+        return Err(Error::Syntax);
+    }
+
+    let weekday = try!(weekday(name, false));
+    let day = try!(number(&rest[2 .. 4]));
+    let month = try!(month(&rest[5 .. 8]));
+    let year = try!(number(&rest[9 .. 11]));
+    let time = try!(time_of_day(&rest[12 .. 20]));
+
+    // Pick the latest year ending in these two digits that’s no more than
+    // 50 years in the future.
+    let mut year = current_year - current_year % 100 + year;
+    if year > current_year + 50 {
+        year -= 100;
+    }
+
+    datetime(weekday, year, month, day, time)
+}
+
+/// Parses an asctime date, such as `Sun Nov  6 08:49:37 1994`.
+fn parse_asctime(input: &[u8]) -> Result<LocalDateTime, Error> {
+    if input.len() != 24 || input[3] != b' ' || input[7] != b' ' || input[10] != b' ' || input[19] != b' ' {
+        return Err(Error::Syntax);
+    }
+
+    let weekday = try!(weekday(&input[.. 3], true));
+    let month = try!(month(&input[4 .. 7]));
+
+    // The day is padded with a space, rather than a zero.
+    let day = match input[8] {
+        b' '  => try!(number(&input[9 .. 10])),
+        _     => try!(number(&input[8 .. 10])),
+    };
+
+    let time = try!(time_of_day(&input[11 .. 19]));
+    let year = try!(number(&input[20 ..]));
+
+    datetime(weekday, year, month, day, time)
+}
+
+/// Puts the fields together, checking that the day of the week matches.
+fn datetime(weekday: Weekday, year: i64, month: Month, day: i64, time: LocalTime) -> Result<LocalDateTime, Error> {
+    let date = try!(LocalDate::ymd(year, month, day as i8).map_err(Error::Date));
+
+    if date.weekday() != weekday {
+        return Err(Error::WeekdayMismatch { given: weekday, actual: date.weekday() });
+    }
+
+    Ok(LocalDateTime::new(date, time))
+}
+
+/// Parses a time in the form `08:49:37`.
+fn time_of_day(input: &[u8]) -> Result<LocalTime, Error> {
+    if input[2] != b':' || input[5] != b':' {
+        return Err(Error::Syntax);
+    }
+
+    let hour = try!(number(&input[0 .. 2]));
+    let minute = try!(number(&input[3 .. 5]));
+    let second = try!(number(&input[6 .. 8]));
+    LocalTime::hms_ms(hour as i8, minute as i8, second as i8, 0).map_err(Error::Date)
+}
+
+/// Parses a string of ASCII digits.
+fn number(input: &[u8]) -> Result<i64, Error> {
+    if input.is_empty() || !input.iter().all(|b| b.is_ascii_digit()) {
+        return Err(Error::Syntax);
+    }
+
+    Ok(input.iter().fold(0, |n, &b| n * 10 + (b - b'0') as i64))
+}
+
+/// Parses the name of a day of the week, which is case-sensitive in HTTP.
+fn weekday(input: &[u8], abbreviated: bool) -> Result<Weekday, Error> {
+    let names = if abbreviated { &WEEKDAY_NAMES } else { &FULL_WEEKDAY_NAMES };
+    let index = names.iter().position(|name| name.as_bytes() == input);
+
+    match index {
+        Some(index)  => Weekday::from_zero(index as i8).map_err(Error::Date),
+        None         => Err(Error::Syntax),
+    }
+}
+
+/// Parses the abbreviated name of a month, which is case-sensitive in
+/// HTTP.
+fn month(input: &[u8]) -> Result<Month, Error> {
+    match MONTH_NAMES.iter().position(|name| name.as_bytes() == input) {
+        Some(index)  => Month::from_zero(index as i8).map_err(Error::Date),
+        None         => Err(Error::Syntax),
+    }
+}
+
+
+/// An error that occurs when parsing an HTTP date.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Error {
+
+    /// The input isn’t in any of the three HTTP date formats. This includes
+    /// names of days and months that aren’t capitalised the way HTTP
+    /// requires, such as `sun` or `NOV`.
+    Syntax,
+
+    /// The name of the day that every HTTP date starts with isn’t the day
+    /// that the date falls on.
+    WeekdayMismatch {
+
+        /// The day of the week in the input.
+        given: Weekday,
+
+        /// The day of the week that the date actually falls on.
+        actual: Weekday,
+    },
+
+    /// The day, hour, minute, or second was out of range, such as
+    /// `31 Apr` in an IMF-fixdate or `30-Feb-94` in an RFC 850 date. HTTP
+    /// dates have no leap seconds, so `:60` is out of range too.
+    Date(DateTimeError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::WeekdayMismatch { given, actual }  => write!(f, "{}: given {:?}, but the date is a {:?}", self.description(), given, actual),
+            _                                         => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Syntax                  => "invalid HTTP date",
+            Error::WeekdayMismatch { .. }  => "day of the week does not match the date",
+            Error::Date(_)                 => "datetime field out of range",
+        }
+    }
+
+    fn cause(&self) -> Option<&ErrorTrait> {
+        match *self {
+            Error::Date(ref e)  => Some(e),
+            _                   => None,
+        }
+    }
+}
@@ -7,6 +7,17 @@ use system::sys_time;
 use duration::Duration;
 
 
+/// The Julian Date at midnight on the 1st of January, 1970.
+const UNIX_EPOCH_JULIAN_DATE: f64 = 2_440_587.5;
+
+/// The Modified Julian Date at midnight on the 1st of January, 1970.
+const UNIX_EPOCH_MODIFIED_JULIAN_DATE: f64 = 40_587.0;
+
+/// Number of milliseconds in a day, as a float for the Julian Date
+/// calculations. Leap seconds are ignored here, as everywhere else.
+const MILLISECONDS_IN_DAY: f64 = 86_400_000.0;
+
+
 /// An **instant** is an exact point on the timeline, irrespective of time
 /// zone or calendar format, with millisecond precision.
 ///
@@ -56,6 +67,74 @@ impl Instant {
     pub fn milliseconds(&self) -> i16 {
         self.milliseconds
     }
+
+    /// Creates a new Instant from a **Julian Date**: the number of days,
+    /// including the fraction of the day, since noon on the 1st of January,
+    /// 4713 BC, in the Julian calendar. The result is rounded to the
+    /// nearest millisecond.
+    ///
+    /// A Julian Date of around 2.4 million only has enough precision left
+    /// over in an `f64` for tens of microseconds, which is more than enough
+    /// for this library’s milliseconds.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::from_julian_date(2_440_587.5), Instant::at_epoch());
+    /// assert_eq!(Instant::from_julian_date(2_451_545.0), Instant::at(946_728_000));
+    /// ```
+    pub fn from_julian_date(julian_date: f64) -> Instant {
+        let milliseconds = ((julian_date - UNIX_EPOCH_JULIAN_DATE) * MILLISECONDS_IN_DAY).round() as i64;
+        Instant::from_total_milliseconds(milliseconds)
+    }
+
+    /// Returns the **Julian Date** at this instant. See `from_julian_date`.
+    pub fn to_julian_date(&self) -> f64 {
+        self.total_milliseconds() as f64 / MILLISECONDS_IN_DAY + UNIX_EPOCH_JULIAN_DATE
+    }
+
+    /// Creates a new Instant from a **Modified Julian Date**: the number of
+    /// days, including the fraction of the day, since midnight on the 17th
+    /// of November, 1858. The result is rounded to the nearest millisecond.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::from_modified_julian_date(40_587.0), Instant::at_epoch());
+    /// ```
+    pub fn from_modified_julian_date(modified_julian_date: f64) -> Instant {
+        let milliseconds = ((modified_julian_date - UNIX_EPOCH_MODIFIED_JULIAN_DATE) * MILLISECONDS_IN_DAY).round() as i64;
+        Instant::from_total_milliseconds(milliseconds)
+    }
+
+    /// Returns the **Modified Julian Date** at this instant. See
+    /// `from_modified_julian_date`.
+    pub fn to_modified_julian_date(&self) -> f64 {
+        self.total_milliseconds() as f64 / MILLISECONDS_IN_DAY + UNIX_EPOCH_MODIFIED_JULIAN_DATE
+    }
+
+    /// Returns the total number of milliseconds since the Unix epoch.
+    fn total_milliseconds(&self) -> i64 {
+        self.seconds * 1000 + self.milliseconds as i64
+    }
+
+    /// Creates a new Instant from a total number of milliseconds since the
+    /// Unix epoch, keeping the millisecond field positive.
+    pub(crate) fn from_total_milliseconds(milliseconds: i64) -> Instant {
+        let mut seconds = milliseconds / 1000;
+        let mut remainder = milliseconds % 1000;
+
+        if remainder < 0 {
+            remainder += 1000;
+            seconds -= 1;
+        }
+
+        Instant::at_ms(seconds, remainder as i16)
+    }
 }
 
 impl fmt::Debug for Instant {
@@ -14,29 +14,53 @@ extern crate locale;
 extern crate libc;
 extern crate num_traits;
 extern crate pad;
-extern crate iso8601;
+extern crate unicode_width;
 
 #[cfg(windows)] extern crate kernel32;
 #[cfg(windows)] extern crate winapi;
 
 
 mod cal;
-pub use cal::{DatePiece, TimePiece};
+pub use cal::{DatePiece, TimePiece, ZonePiece};
+pub use cal::cron::CronSchedule;
 pub use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year, YearMonth};
+pub use cal::easter::MovableFeast;
+pub use cal::interval::{Interval, IntervalSet};
+pub use cal::iso_interval::{IsoInterval, RepeatingInterval};
+pub use cal::time_range::TimeRange;
+pub use cal::truncate::TimeUnit;
+pub use cal::vtimezone::VTimeZone;
 pub use cal::fmt::custom as fmt;
+pub use cal::fmt::style::{DateStyle, TimeStyle, DateTimeStyle};
 pub use cal::fmt::ISO;  // TODO: replace this with just a 'fmt' import
 pub use cal::offset::{Offset, OffsetDateTime};
+pub use cal::period::{Period, PeriodArithmetic};
+pub use cal::recurrence::{RecurrenceRule, RecurrenceSet};
 pub use cal::zone::{TimeZone, ZonedDateTime};
 pub use cal::zone as zone;
+pub use cal::fmt::iso as iso;
+pub use cal::parse as parse;
+pub use cal::fmt::strftime as strftime;
+pub use cal::fmt::cldr as cldr;
+pub use cal::fmt::relative as relative;
+pub use cal::cron as cron;
+pub use cal::recurrence as recurrence;
+pub use cal::rfc2822 as rfc2822;
+pub use cal::rfc3339 as rfc3339;
+pub use cal::vtimezone as vtimezone;
+pub use cal::calendars as calendars;
 
 pub use cal::convenience;
 
-mod duration;
+pub mod duration;
 pub use duration::Duration;
 
 mod instant;
 pub use instant::Instant;
 
+pub mod epoch;
+pub mod http_date;
+
 mod system;
 pub use system::sys_timezone;
 
@@ -0,0 +1,218 @@
+extern crate datetime;
+pub use datetime::{LocalDate, Month, Year};
+pub use datetime::calendars::CalendarDate;
+
+
+/// Checks that every day in the given range of Gregorian years survives a
+/// round trip through the given calendar.
+macro_rules! round_trip {
+    ($name: ident: $calendar: ty, $years: expr) => {
+        #[test]
+        fn $name() {
+            for year in $years {
+                for date in Year(year).months(..).flat_map(|ym| ym.days(..)) {
+                    let converted = <$calendar>::from_local_date(date).unwrap();
+                    assert_eq!(converted.to_local_date(), date, "{:?}", converted);
+                }
+            }
+        }
+    };
+}
+
+
+mod julian {
+    use super::*;
+    use datetime::calendars::JulianDate;
+
+    #[test]
+    fn gregorian_reform() {
+        let date = JulianDate::ymd(1582, Month::October, 4).unwrap();
+        assert_eq!(date.to_local_date(), LocalDate::ymd(1582, Month::October, 14).unwrap());
+    }
+
+    #[test]
+    fn thirteen_days_behind() {
+        let date = JulianDate::from_local_date(LocalDate::ymd(2024, Month::January, 14).unwrap()).unwrap();
+        assert_eq!(date, JulianDate::ymd(2024, Month::January, 1).unwrap());
+    }
+
+    #[test]
+    fn julian_leap_day() {
+        let date = JulianDate::ymd(1900, Month::February, 29).unwrap();
+        assert_eq!(date.to_local_date(), LocalDate::ymd(1900, Month::March, 13).unwrap());
+    }
+
+    #[test]
+    fn year_zero() {
+        let date = JulianDate::ymd(1, Month::January, 1).unwrap();
+        assert_eq!(date.to_local_date(), LocalDate::ymd(0, Month::December, 30).unwrap());
+    }
+
+    round_trip!(round_trip_recent: JulianDate, 1890 .. 2110);
+    round_trip!(round_trip_ancient: JulianDate, -10 .. 10);
+}
+
+
+mod hebrew {
+    use super::*;
+    use datetime::calendars::{HebrewDate, HebrewMonth};
+
+    #[test]
+    fn rosh_hashanah() {
+        for &(hebrew_year, year, month, day) in [
+            (5783, 2022, Month::September, 26),
+            (5784, 2023, Month::September, 16),
+            (5785, 2024, Month::October,    3),
+        ].iter() {
+            let date = HebrewDate::ymd(hebrew_year, HebrewMonth::Tishri, 1).unwrap();
+            assert_eq!(date.to_local_date(), LocalDate::ymd(year, month, day).unwrap());
+        }
+    }
+
+    #[test]
+    fn passover() {
+        let date = HebrewDate::from_local_date(LocalDate::ymd(2024, Month::April, 23).unwrap()).unwrap();
+        assert_eq!(date, HebrewDate::ymd(5784, HebrewMonth::Nisan, 15).unwrap());
+    }
+
+    #[test]
+    fn purim_in_a_leap_year() {
+        let date = HebrewDate::from_local_date(LocalDate::ymd(2024, Month::March, 24).unwrap()).unwrap();
+        assert_eq!(date, HebrewDate::ymd(5784, HebrewMonth::AdarII, 14).unwrap());
+        assert_eq!(date.month_name(), "Adar II");
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(HebrewDate::is_leap_year(5784));
+        assert!(!HebrewDate::is_leap_year(5785));
+        assert_eq!(HebrewDate::days_in_year(5784), 383);
+        assert_eq!(HebrewDate::days_in_year(5785), 355);
+    }
+
+    #[test]
+    fn adar_names() {
+        assert_eq!(HebrewMonth::Adar.name(false), "Adar");
+        assert_eq!(HebrewMonth::Adar.name(true), "Adar I");
+        assert_eq!(HebrewMonth::Tishri.native_name(false), "תשרי");
+    }
+
+    round_trip!(round_trip_recent: HebrewDate, 1990 .. 2030);
+}
+
+
+mod islamic {
+    use super::*;
+    use datetime::calendars::{IslamicDate, IslamicMonth};
+
+    #[test]
+    fn epoch() {
+        let date = IslamicDate::ymd(1, IslamicMonth::Muharram, 1).unwrap();
+        assert_eq!(date.to_local_date(), LocalDate::ymd(622, Month::July, 19).unwrap());
+    }
+
+    #[test]
+    fn millennium() {
+        let date = IslamicDate::from_local_date(LocalDate::ymd(2000, Month::January, 1).unwrap()).unwrap();
+        assert_eq!(date, IslamicDate::ymd(1420, IslamicMonth::Ramadan, 24).unwrap());
+    }
+
+    #[test]
+    fn new_year_1445() {
+        let date = IslamicDate::ymd(1445, IslamicMonth::Muharram, 1).unwrap();
+        assert_eq!(date.to_local_date(), LocalDate::ymd(2023, Month::July, 19).unwrap());
+    }
+
+    #[test]
+    fn leap_years() {
+        let leap_years: Vec<_> = (1 .. 31).filter(|y| IslamicDate::is_leap_year(*y)).collect();
+        assert_eq!(leap_years, vec![ 2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29 ]);
+    }
+
+    #[test]
+    fn no_thirtieth_of_a_common_year() {
+        assert!(IslamicDate::ymd(1444, IslamicMonth::DhuAlHijjah, 30).is_err());
+        assert!(IslamicDate::ymd(1444, IslamicMonth::Ramadan, 30).is_ok());
+    }
+
+    round_trip!(round_trip_recent: IslamicDate, 1990 .. 2030);
+    round_trip!(round_trip_early: IslamicDate, 600 .. 640);
+}
+
+
+mod persian {
+    use super::*;
+    use datetime::calendars::{PersianDate, PersianMonth};
+
+    #[test]
+    fn nowruz() {
+        for &(persian_year, year, day) in [
+            (1399, 2020, 20),
+            (1400, 2021, 21),
+            (1403, 2024, 20),
+            (1404, 2025, 21),
+        ].iter() {
+            let date = PersianDate::ymd(persian_year, PersianMonth::Farvardin, 1).unwrap();
+            assert_eq!(date.to_local_date(), LocalDate::ymd(year, Month::March, day).unwrap());
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        assert_eq!(PersianDate::is_leap_year(1399), Ok(true));
+        assert_eq!(PersianDate::is_leap_year(1400), Ok(false));
+        assert_eq!(PersianDate::is_leap_year(1403), Ok(true));
+        assert!(PersianDate::ymd(1403, PersianMonth::Esfand, 30).is_ok());
+        assert!(PersianDate::ymd(1402, PersianMonth::Esfand, 30).is_err());
+    }
+
+    #[test]
+    fn last_day_of_the_year() {
+        let date = PersianDate::from_local_date(LocalDate::ymd(2025, Month::March, 20).unwrap()).unwrap();
+        assert_eq!(date, PersianDate::ymd(1403, PersianMonth::Esfand, 30).unwrap());
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(PersianDate::ymd(3178, PersianMonth::Farvardin, 1).is_err());
+        assert!(PersianDate::from_local_date(LocalDate::ymd(500, Month::January, 1).unwrap()).is_err());
+    }
+
+    round_trip!(round_trip_recent: PersianDate, 1990 .. 2030);
+}
+
+
+mod japanese {
+    use super::*;
+    use datetime::calendars::{JapaneseDate, Era};
+
+    #[test]
+    fn change_of_era() {
+        let heisei = JapaneseDate::from_local_date(LocalDate::ymd(2019, Month::April, 30).unwrap()).unwrap();
+        assert_eq!(heisei, JapaneseDate::new(Era::Heisei, 31, Month::April, 30).unwrap());
+
+        let reiwa = JapaneseDate::from_local_date(LocalDate::ymd(2019, Month::May, 1).unwrap()).unwrap();
+        assert_eq!(reiwa, JapaneseDate::new(Era::Reiwa, 1, Month::May, 1).unwrap());
+    }
+
+    #[test]
+    fn showa_64() {
+        let date = JapaneseDate::from_local_date(LocalDate::ymd(1989, Month::January, 7).unwrap()).unwrap();
+        assert_eq!(date.era(), Era::Showa);
+        assert_eq!(date.year(), 64);
+    }
+
+    #[test]
+    fn before_meiji() {
+        assert!(JapaneseDate::from_local_date(LocalDate::ymd(1868, Month::January, 1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn names() {
+        let date = JapaneseDate::new(Era::Reiwa, 6, Month::January, 31).unwrap();
+        assert_eq!(date.era().native_name(), "令和");
+        assert_eq!(date.native_month_name(), "1月");
+    }
+
+    round_trip!(round_trip_recent: JapaneseDate, 1910 .. 2030);
+}
@@ -0,0 +1,159 @@
+extern crate datetime;
+
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday};
+use datetime::cldr::Locale;
+use datetime::fmt::{DateFormat, LocaleNames};
+
+
+fn datetime() -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2024, Month::March, 3).unwrap(), LocalTime::hms(15, 4, 5).unwrap())
+}
+
+fn locale(tag: &str) -> &'static Locale {
+    Locale::from_tag(tag).unwrap()
+}
+
+fn format(pattern: &str, tag: &str) -> String {
+    DateFormat::from_ldml(pattern).unwrap().format(&datetime(), locale(tag))
+}
+
+
+mod tags {
+    use super::*;
+
+    #[test]
+    fn exact() {
+        assert_eq!(locale("en-GB").tag(), "en-GB");
+        assert_eq!(locale("ja").tag(), "ja");
+    }
+
+    #[test]
+    fn case_and_underscores() {
+        assert_eq!(locale("EN-gb").tag(), "en-GB");
+        assert_eq!(locale("fr_FR").tag(), "fr");
+    }
+
+    #[test]
+    fn falls_back_to_language() {
+        assert_eq!(locale("en-US").tag(), "en");
+        assert_eq!(locale("de-Latn-AT").tag(), "de");
+        assert_eq!(locale("en-GB-u-ca-gregory").tag(), "en-GB");
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(Locale::from_tag("xx"), None);
+        assert_eq!(Locale::from_tag(""), None);
+    }
+
+    #[test]
+    fn all() {
+        assert!(Locale::all().iter().any(|locale| locale.tag() == "de"));
+        assert!(Locale::all().iter().all(|locale| Locale::from_tag(locale.tag()) == Some(locale)));
+    }
+}
+
+
+mod names {
+    use super::*;
+
+    #[test]
+    fn months() {
+        assert_eq!(format("MMMM MMM", "de"), "März März");
+        assert_eq!(format("MMMM MMM", "fr"), "mars mars");
+        assert_eq!(format("MMMM MMM", "ja"), "3月 3月");
+    }
+
+    #[test]
+    fn standalone_months() {
+        assert_eq!(locale("de").month_name(0, false, false), "Jan.");
+        assert_eq!(locale("de").month_name(0, false, true), "Jan");
+        assert_eq!(format("d. MMM / LLL", "de"), "3. März / Mär");
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(format("EEEE EEE", "es"), "domingo dom");
+        assert_eq!(format("EEE ccc", "de"), "So. So");
+    }
+
+    #[test]
+    fn am_pm() {
+        assert_eq!(format("h:mm a", "en"), "3:04 PM");
+        assert_eq!(format("h:mm a", "en-GB"), "3:04 pm");
+        assert_eq!(format("h:mm a", "es"), "3:04 p.\u{a0}m.");
+        assert_eq!(format("ah:mm", "ja"), "午後3:04");
+    }
+
+    #[test]
+    fn eras() {
+        assert_eq!(format("G GGGG", "en"), "AD Anno Domini");
+        assert_eq!(format("GGGG", "fr"), "après Jésus-Christ");
+    }
+
+    #[test]
+    fn locale_time_still_works() {
+        extern crate locale;
+        let format = DateFormat::from_ldml("EEEE d MMMM").unwrap();
+        assert_eq!(format.format(&datetime(), &locale::Time::english()), "Sunday 3 March");
+    }
+}
+
+
+mod patterns {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        assert_eq!(locale("en-US").date_patterns().short, "M/d/yy");
+        assert_eq!(locale("de-DE").date_patterns().short, "dd.MM.yy");
+        assert_eq!(locale("en-GB").date_patterns().full, "EEEE d MMMM y");
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(locale("en").time_patterns().short, "h:mm\u{202f}a");
+        assert_eq!(locale("fr").time_patterns().medium, "HH:mm:ss");
+    }
+
+    #[test]
+    fn date_times() {
+        assert_eq!(locale("en").date_time_patterns().medium, "{1}, {0}");
+        assert_eq!(locale("ja").date_time_patterns().short, "{1} {0}");
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format(locale("en").date_patterns().full, "en"), "Sunday, March 3, 2024");
+        assert_eq!(format(locale("de").date_patterns().long, "de"), "3. März 2024");
+        assert_eq!(format(locale("es").date_patterns().long, "es"), "3 de marzo de 2024");
+        assert_eq!(format(locale("ja").date_patterns().full, "ja"), "2024年3月3日日曜日");
+    }
+
+    #[test]
+    fn first_weekday() {
+        assert_eq!(locale("en-US").first_weekday(), Weekday::Sunday);
+        assert_eq!(locale("en-GB").first_weekday(), Weekday::Monday);
+        assert_eq!(locale("de").first_weekday(), Weekday::Monday);
+        assert_eq!(locale("ja").first_weekday(), Weekday::Sunday);
+    }
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn localised_names() {
+        let format = DateFormat::from_ldml("EEEE, d. MMMM y").unwrap();
+        let parsed = format.parse_value("Sonntag, 3. März 2024", locale("de")).unwrap();
+        assert_eq!(parsed.to_local_date(), Ok(datetime().date()));
+    }
+
+    #[test]
+    fn localised_am_pm() {
+        let format = DateFormat::from_ldml("h:mm a").unwrap();
+        let parsed = format.parse_value("3:04 p.\u{a0}m.", locale("es")).unwrap();
+        assert_eq!(parsed.to_local_time(), Ok(LocalTime::hm(15, 4).unwrap()));
+    }
+}
@@ -0,0 +1,308 @@
+extern crate datetime;
+use datetime::{Instant, LocalDate, LocalTime, LocalDateTime, Month, TimePiece};
+use datetime::CronSchedule;
+use datetime::cron::Error;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+
+fn at(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hm(hour, minute).unwrap())
+}
+
+fn next(expression: &str, after: LocalDateTime) -> Option<LocalDateTime> {
+    expression.parse::<CronSchedule>().unwrap().next_local(after)
+}
+
+fn previous(expression: &str, before: LocalDateTime) -> Option<LocalDateTime> {
+    expression.parse::<CronSchedule>().unwrap().previous_local(before)
+}
+
+fn series(expression: &str, after: LocalDateTime, count: usize) -> Vec<LocalDateTime> {
+    let schedule: CronSchedule = expression.parse().unwrap();
+    let mut times = Vec::new();
+    let mut time = after;
+
+    while times.len() < count {
+        time = schedule.next_local(time).unwrap();
+        times.push(time);
+    }
+
+    times
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn macros() {
+        assert_eq!("@daily".parse::<CronSchedule>(), "0 0 * * *".parse::<CronSchedule>());
+        assert_eq!("@midnight".parse::<CronSchedule>(), "0 0 * * *".parse::<CronSchedule>());
+        assert_eq!("@annually".parse::<CronSchedule>(), "0 0 1 1 *".parse::<CronSchedule>());
+        assert_eq!("@reboot".parse::<CronSchedule>(), Err(Error::UnknownMacro("@reboot".to_owned())));
+    }
+
+    #[test]
+    fn sunday_is_zero_or_seven() {
+        assert_eq!("0 0 * * 7".parse::<CronSchedule>(), "0 0 * * 0".parse::<CronSchedule>());
+        assert_eq!("0 0 * * 5-7".parse::<CronSchedule>(), "0 0 * * 0,5,6".parse::<CronSchedule>());
+    }
+
+    #[test]
+    fn wrapping_weekday_ranges() {
+        assert_eq!("0 0 * * FRI-SUN".parse::<CronSchedule>(), "0 0 * * 5,6,0".parse::<CronSchedule>());
+        assert_eq!("0 0 * * SAT-TUE".parse::<CronSchedule>(), "0 0 * * 6,0,1,2".parse::<CronSchedule>());
+        assert_eq!("0 0 * * 5-1/2".parse::<CronSchedule>(), "0 0 * * 5,0".parse::<CronSchedule>());
+        assert_eq!(next("0 9 * * FRI-SUN", at(2024, Month::January, 1, 0, 0)), Some(at(2024, Month::January, 5, 9, 0)));
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!("0 0 1 jan-mar MON".parse::<CronSchedule>(), "0 0 1 1-3 1".parse::<CronSchedule>());
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!("0 * * * * *".parse::<CronSchedule>(), "* * * * *".parse::<CronSchedule>());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("* * * *".parse::<CronSchedule>(), Err(Error::FieldCount(4)));
+        assert_eq!("60 * * * *".parse::<CronSchedule>(), Err(Error::InvalidField("60".to_owned())));
+        assert!("* 24 * * *".parse::<CronSchedule>().is_err());
+        assert!("* * 0 * *".parse::<CronSchedule>().is_err());
+        assert!("* * * 13 *".parse::<CronSchedule>().is_err());
+        assert!("*/0 * * * *".parse::<CronSchedule>().is_err());
+        assert!("5-1 * * * *".parse::<CronSchedule>().is_err());
+        assert!("* * 32W * *".parse::<CronSchedule>().is_err());
+        assert!("* * * * 5#6".parse::<CronSchedule>().is_err());
+        assert!("* * * * FOO".parse::<CronSchedule>().is_err());
+    }
+}
+
+
+mod next_times {
+    use super::*;
+
+    #[test]
+    fn every_minute() {
+        assert_eq!(next("* * * * *", at(2024, Month::January, 1, 12, 0)), Some(at(2024, Month::January, 1, 12, 1)));
+    }
+
+    #[test]
+    fn strictly_after() {
+        let after = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 1).unwrap(), LocalTime::hms_ms(12, 0, 0, 500).unwrap());
+        assert_eq!(next("0 12 * * *", after), Some(at(2024, Month::January, 2, 12, 0)));
+    }
+
+    #[test]
+    fn steps() {
+        assert_eq!(series("*/15 9-10 * * *", at(2024, Month::January, 1, 10, 40), 3),
+                   vec![ at(2024, Month::January, 1, 10, 45), at(2024, Month::January, 2, 9, 0), at(2024, Month::January, 2, 9, 15) ]);
+
+        assert_eq!(series("10-50/20 * * * *", at(2024, Month::January, 1, 0, 0), 3),
+                   vec![ at(2024, Month::January, 1, 0, 10), at(2024, Month::January, 1, 0, 30), at(2024, Month::January, 1, 0, 50) ]);
+    }
+
+    #[test]
+    fn with_seconds() {
+        let after = at(2024, Month::January, 1, 0, 0);
+        let time = next("*/20 * * * * *", after).unwrap();
+        assert_eq!(time.time(), LocalTime::hms(0, 0, 20).unwrap());
+    }
+
+    #[test]
+    fn end_of_year() {
+        assert_eq!(next("@yearly", at(2024, Month::June, 1, 0, 0)), Some(at(2025, Month::January, 1, 0, 0)));
+    }
+
+    #[test]
+    fn either_day_field() {
+        // Both day fields are restricted, so either one can match.
+        assert_eq!(series("0 0 13 * FRI", at(2024, Month::September, 1, 0, 0), 3),
+                   vec![ at(2024, Month::September, 6, 0, 0), at(2024, Month::September, 13, 0, 0), at(2024, Month::September, 20, 0, 0) ]);
+    }
+
+    #[test]
+    fn both_day_fields_with_star_step() {
+        // A field starting with * isn’t restricted, so both have to match.
+        assert_eq!(next("0 0 */2 * MON", at(2024, Month::January, 1, 0, 0)), Some(at(2024, Month::January, 15, 0, 0)));
+    }
+
+    #[test]
+    fn last_day() {
+        assert_eq!(series("0 0 L * *", at(2024, Month::January, 1, 0, 0), 3),
+                   vec![ at(2024, Month::January, 31, 0, 0), at(2024, Month::February, 29, 0, 0), at(2024, Month::March, 31, 0, 0) ]);
+
+        assert_eq!(next("0 0 L-2 * *", at(2023, Month::February, 1, 0, 0)), Some(at(2023, Month::February, 26, 0, 0)));
+    }
+
+    #[test]
+    fn nearest_weekday() {
+        // The 15th of June 2024 is a Saturday, and the 1st is too.
+        assert_eq!(next("0 0 15W * *", at(2024, Month::June, 1, 0, 0)), Some(at(2024, Month::June, 14, 0, 0)));
+        assert_eq!(next("0 0 1W * *", at(2024, Month::May, 31, 0, 0)), Some(at(2024, Month::June, 3, 0, 0)));
+
+        // The 30th of June 2024 is a Sunday, and the last day of the month.
+        assert_eq!(next("0 0 30W * *", at(2024, Month::June, 1, 0, 0)), Some(at(2024, Month::June, 28, 0, 0)));
+    }
+
+    #[test]
+    fn last_weekday() {
+        // The 31st of August 2024 is a Saturday.
+        assert_eq!(next("0 0 LW * *", at(2024, Month::August, 1, 0, 0)), Some(at(2024, Month::August, 30, 0, 0)));
+    }
+
+    #[test]
+    fn nth_weekday() {
+        assert_eq!(series("0 0 ? * 5#3", at(2024, Month::January, 1, 0, 0), 2),
+                   vec![ at(2024, Month::January, 19, 0, 0), at(2024, Month::February, 16, 0, 0) ]);
+    }
+
+    #[test]
+    fn last_weekday_of_month() {
+        assert_eq!(series("0 0 * * FRIL", at(2024, Month::January, 1, 0, 0), 2),
+                   vec![ at(2024, Month::January, 26, 0, 0), at(2024, Month::February, 23, 0, 0) ]);
+    }
+
+    #[test]
+    fn leap_day() {
+        assert_eq!(next("0 0 29 2 *", at(2024, Month::March, 1, 0, 0)), Some(at(2028, Month::February, 29, 0, 0)));
+    }
+
+    #[test]
+    fn never() {
+        assert_eq!(next("0 0 30 2 *", at(2024, Month::January, 1, 0, 0)), None);
+        assert_eq!(previous("0 0 31 4 *", at(2024, Month::January, 1, 0, 0)), None);
+    }
+}
+
+
+mod previous_times {
+    use super::*;
+
+    #[test]
+    fn same_day() {
+        assert_eq!(previous("30 9 * * *", at(2024, Month::January, 2, 12, 0)), Some(at(2024, Month::January, 2, 9, 30)));
+    }
+
+    #[test]
+    fn strictly_before() {
+        assert_eq!(previous("30 9 * * *", at(2024, Month::January, 2, 9, 30)), Some(at(2024, Month::January, 1, 9, 30)));
+    }
+
+    #[test]
+    fn across_months() {
+        assert_eq!(previous("0 0 L 2 *", at(2024, Month::January, 1, 0, 0)), Some(at(2023, Month::February, 28, 0, 0)));
+    }
+
+    #[test]
+    fn matches_next() {
+        let schedule: CronSchedule = "*/7 */3 * * MON-FRI".parse().unwrap();
+        let mut time = schedule.next_local(at(2024, Month::January, 1, 0, 0)).unwrap();
+
+        for _ in 0 .. 50 {
+            let after = schedule.next_local(time).unwrap();
+            assert_eq!(schedule.previous_local(after), Some(time));
+            time = after;
+        }
+    }
+}
+
+
+mod zoned {
+    use super::*;
+
+    // America/New_York in 2024.
+    const EASTERN: &'static StaticTimeZone<'static> = &StaticTimeZone {
+        name: "Eastern",
+        fixed_timespans: FixedTimespanSet {
+            first: FixedTimespan {
+                offset: -5 * 3600,
+                is_dst: false,
+                name: Cow::Borrowed("EST"),
+            },
+            rest: &[
+                (1_710_054_000, FixedTimespan {
+                    offset: -4 * 3600,
+                    is_dst: true,
+                    name: Cow::Borrowed("EDT"),
+                }),
+                (1_730_613_600, FixedTimespan {
+                    offset: -5 * 3600,
+                    is_dst: false,
+                    name: Cow::Borrowed("EST"),
+                }),
+            ],
+        },
+    };
+
+    /// Returns the first instant that a wall clock in the zone shows the
+    /// given local time.
+    fn wall(zone: &TimeZone, local: LocalDateTime) -> Instant {
+        zone.earliest_local(local).to_instant()
+    }
+
+    #[test]
+    fn follows_wall_clock() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let schedule: CronSchedule = "0 9 * * *".parse().unwrap();
+
+        let before = schedule.next_in(wall(&zone, at(2024, Month::March, 9, 0, 0)), &zone).unwrap();
+        let after = schedule.next_in(before.to_instant(), &zone).unwrap();
+        assert_eq!(after.hour(), 9);
+        assert_eq!(after.to_instant().seconds() - before.to_instant().seconds(), 23 * 3600);
+    }
+
+    #[test]
+    fn skipped_times_fire_once_at_the_change() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let schedule: CronSchedule = "*/20 * * * *".parse().unwrap();
+
+        let fire = schedule.next_in(wall(&zone, at(2024, Month::March, 10, 1, 50)), &zone).unwrap();
+        assert_eq!(fire.to_instant().seconds(), 1_710_054_000);
+        assert_eq!((fire.hour(), fire.minute()), (3, 0));
+
+        let fire = schedule.next_in(fire.to_instant(), &zone).unwrap();
+        assert_eq!((fire.hour(), fire.minute()), (3, 20));
+    }
+
+    #[test]
+    fn repeated_times_fire_once() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let schedule: CronSchedule = "30 * * * *".parse().unwrap();
+
+        let first = schedule.next_in(wall(&zone, at(2024, Month::November, 3, 1, 0)), &zone).unwrap();
+        assert_eq!(first.to_instant().seconds(), 1_730_613_600 - 1800);
+
+        let second = schedule.next_in(first.to_instant(), &zone).unwrap();
+        assert_eq!((second.hour(), second.minute()), (2, 30));
+        assert_eq!(second.to_instant().seconds() - first.to_instant().seconds(), 2 * 3600);
+    }
+
+    #[test]
+    fn never_goes_back_from_a_repeated_hour() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let schedule: CronSchedule = "50 * * * *".parse().unwrap();
+
+        // 01:40 the second time around, after 01:50 the first time.
+        let after = Instant::at(1_730_613_600 + 40 * 60);
+        let fire = schedule.next_in(after, &zone).unwrap();
+        assert_eq!((fire.hour(), fire.minute()), (2, 50));
+        assert_eq!(fire.to_instant().seconds(), 1_730_613_600 + 110 * 60);
+
+        let fire = schedule.previous_in(fire.to_instant(), &zone).unwrap();
+        assert_eq!(fire.to_instant().seconds(), 1_730_613_600 - 10 * 60);
+    }
+
+    #[test]
+    fn previous() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let schedule: CronSchedule = "30 2 * * *".parse().unwrap();
+
+        let fire = schedule.previous_in(wall(&zone, at(2024, Month::March, 10, 12, 0)), &zone).unwrap();
+        assert_eq!(fire.to_instant().seconds(), 1_710_054_000);
+    }
+}
@@ -1,5 +1,6 @@
 extern crate datetime;
 pub use datetime::Duration;
+pub use datetime::duration::Error;
 
 
 mod addition {
@@ -65,3 +66,135 @@ mod multiplication {
         assert_eq!(Duration::of(1), Duration::of_ms(0, 500) * 2)
     }
 }
+
+
+mod parsing {
+    use super::*;
+
+    fn parse(input: &str) -> Duration {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn single_units() {
+        assert_eq!(parse("90s"), Duration::of(90));
+        assert_eq!(parse("250ms"), Duration::of_ms(0, 250));
+        assert_eq!(parse("2w"), Duration::of(14 * 86_400));
+    }
+
+    #[test]
+    fn combined() {
+        assert_eq!(parse("1h30m"), Duration::of(5400));
+        assert_eq!(parse("1d2h3m4s5ms"), Duration::of_ms(93_784, 5));
+        assert_eq!(parse("2 days 3 hours"), Duration::of(183_600));
+        assert_eq!(parse("5 min 10 sec"), Duration::of(310));
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(parse("1.5h"), Duration::of(5400));
+        assert_eq!(parse(".5s"), Duration::of_ms(0, 500));
+        assert_eq!(parse("0.0015s"), Duration::of_ms(0, 1));
+    }
+
+    #[test]
+    fn small_units_are_truncated() {
+        assert_eq!(parse("1500us"), Duration::of_ms(0, 1));
+        assert_eq!(parse("1500µs"), Duration::of_ms(0, 1));
+        assert_eq!(parse("999999ns"), Duration::zero());
+    }
+
+    #[test]
+    fn signs() {
+        assert_eq!(parse("-1h"), Duration::of(-3600));
+        assert_eq!(parse("-1.5s"), Duration::of_ms(-2, 500));
+        assert_eq!(parse("+30s"), Duration::of(30));
+    }
+
+    #[test]
+    fn lone_zero() {
+        assert_eq!(parse("0"), Duration::zero());
+        assert_eq!(parse(" 0 "), Duration::zero());
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    fn parse(input: &str) -> Error {
+        input.parse::<Duration>().unwrap_err()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parse(""), Error::Empty);
+        assert_eq!(parse("  "), Error::Empty);
+    }
+
+    #[test]
+    fn invalid_number() {
+        assert_eq!(parse("h"), Error::InvalidNumber { pos: 0 });
+        assert_eq!(parse("1h."), Error::InvalidNumber { pos: 2 });
+    }
+
+    #[test]
+    fn missing_unit() {
+        assert_eq!(parse("90"), Error::MissingUnit { pos: 2 });
+        assert_eq!(parse("1h30"), Error::MissingUnit { pos: 4 });
+    }
+
+    #[test]
+    fn unknown_unit() {
+        assert_eq!(parse("1h30x"), Error::UnknownUnit { unit: "x".to_string(), pos: 4 });
+        assert_eq!(parse("3 fortnights"), Error::UnknownUnit { unit: "fortnights".to_string(), pos: 2 });
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(parse("1s 99999999999999999999999999999w"), Error::Overflow { pos: 3 });
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(parse("1h30x").to_string(), "unknown unit in duration ‘x’ at position 4");
+    }
+}
+
+
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn compact() {
+        assert_eq!(Duration::of(5400).to_string(), "1h30m");
+        assert_eq!(Duration::of_ms(1, 500).to_string(), "1s500ms");
+        assert_eq!(Duration::of(694_861).to_string(), "1w1d1h1m1s");
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Duration::zero().to_string(), "0s");
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(Duration::of(-3600).to_string(), "-1h");
+        assert_eq!(Duration::of_ms(-1, 500).to_string(), "-500ms");
+    }
+
+    #[test]
+    fn long() {
+        assert_eq!(Duration::of(183_600).to_long_string(), "2 days 3 hours");
+        assert_eq!(Duration::of(60).to_long_string(), "1 minute");
+        assert_eq!(Duration::of(-90).to_long_string(), "-1 minute 30 seconds");
+    }
+
+    #[test]
+    fn round_trips() {
+        for &duration in &[ Duration::of(5400), Duration::of_ms(-2, 250), Duration::of_ms(1_234_567, 89), Duration::zero() ] {
+            assert_eq!(duration.to_string().parse(), Ok(duration));
+            assert_eq!(duration.to_long_string().parse(), Ok(duration));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+extern crate datetime;
+pub use datetime::{Year, LocalDate, Month, MovableFeast};
+
+
+mod gregorian {
+    use super::*;
+
+    #[test]
+    fn known_dates() {
+        for &(year, month, day) in [
+            (1818, Month::March, 22),  // earliest possible
+            (1943, Month::April, 25),  // latest possible
+            (1961, Month::April,  2),
+            (2000, Month::April, 23),
+            (2008, Month::March, 23),
+            (2019, Month::April, 21),
+            (2024, Month::March, 31),
+            (2038, Month::April, 25),
+            (2285, Month::March, 22),
+        ].iter() {
+            assert_eq!(Year(year).easter_gregorian(), LocalDate::ymd(year, month, day).unwrap());
+        }
+    }
+
+    #[test]
+    fn always_a_sunday() {
+        use datetime::{DatePiece, Weekday};
+
+        for year in -500 .. 3000 {
+            assert_eq!(Year(year).easter_gregorian().weekday(), Weekday::Sunday);
+        }
+    }
+}
+
+
+mod orthodox {
+    use super::*;
+
+    #[test]
+    fn julian_fields() {
+        assert_eq!(Year(2023).easter_julian(), (Month::April,  3));
+        assert_eq!(Year(2024).easter_julian(), (Month::April, 22));
+    }
+
+    #[test]
+    fn known_dates() {
+        for &(year, month, day) in [
+            (1900, Month::April, 22),
+            (2000, Month::April, 30),
+            (2019, Month::April, 28),
+            (2023, Month::April, 16),
+            (2024, Month::May,    5),
+            (2025, Month::April, 20),
+            (2100, Month::May,    2),
+        ].iter() {
+            assert_eq!(Year(year).easter_orthodox(), LocalDate::ymd(year, month, day).unwrap());
+        }
+    }
+}
+
+
+mod feasts {
+    use super::*;
+
+    #[test]
+    fn holidays_in_2024() {
+        let easter = Year(2024).easter_gregorian();
+
+        assert_eq!(MovableFeast::AshWednesday.relative_to(easter), LocalDate::ymd(2024, Month::February, 14).unwrap());
+        assert_eq!(MovableFeast::GoodFriday.relative_to(easter),   LocalDate::ymd(2024, Month::March,    29).unwrap());
+        assert_eq!(MovableFeast::EasterMonday.relative_to(easter), LocalDate::ymd(2024, Month::April,     1).unwrap());
+        assert_eq!(MovableFeast::Ascension.relative_to(easter),    LocalDate::ymd(2024, Month::May,       9).unwrap());
+        assert_eq!(MovableFeast::Pentecost.relative_to(easter),    LocalDate::ymd(2024, Month::May,      19).unwrap());
+        assert_eq!(MovableFeast::WhitMonday.relative_to(easter),   LocalDate::ymd(2024, Month::May,      20).unwrap());
+        assert_eq!(MovableFeast::CorpusChristi.relative_to(easter), LocalDate::ymd(2024, Month::May,     30).unwrap());
+    }
+
+    #[test]
+    fn orthodox_good_friday() {
+        let easter = Year(2024).easter_orthodox();
+        assert_eq!(MovableFeast::GoodFriday.relative_to(easter), LocalDate::ymd(2024, Month::May, 3).unwrap());
+    }
+}
@@ -0,0 +1,232 @@
+extern crate datetime;
+pub use datetime::{Instant, LocalDate, LocalTime, LocalDateTime, Month};
+pub use datetime::epoch::{self, Error};
+
+
+/// Midnight UTC on the 1st of January, 2024.
+pub fn new_year_2024() -> Instant {
+    Instant::at(1_704_067_200)
+}
+
+
+mod filetime {
+    use super::*;
+
+    #[test]
+    fn unix_epoch() {
+        assert_eq!(epoch::from_filetime(116_444_736_000_000_000), Instant::at_epoch());
+        assert_eq!(epoch::to_filetime(Instant::at_epoch()), Ok(116_444_736_000_000_000));
+    }
+
+    #[test]
+    fn new_year() {
+        assert_eq!(epoch::from_filetime(133_485_408_000_000_000), new_year_2024());
+        assert_eq!(epoch::to_filetime(new_year_2024()), Ok(133_485_408_000_000_000));
+    }
+
+    #[test]
+    fn truncates_ticks() {
+        assert_eq!(epoch::from_filetime(133_485_408_000_019_999), Instant::at_ms(1_704_067_200, 1));
+    }
+
+    #[test]
+    fn before_1601() {
+        assert_eq!(epoch::from_filetime(0), Instant::at(-11_644_473_600));
+        assert_eq!(epoch::to_filetime(Instant::at(-11_644_473_601)), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(epoch::to_filetime(Instant::at(i64::max_value())), Err(Error::OutOfRange));
+    }
+}
+
+
+mod ntp {
+    use super::*;
+
+    #[test]
+    fn unix_epoch() {
+        assert_eq!(epoch::from_ntp(0x83AA_7E80_0000_0000), Instant::at_epoch());
+        assert_eq!(epoch::to_ntp(Instant::at_epoch()), Ok(0x83AA_7E80_0000_0000));
+    }
+
+    #[test]
+    fn half_a_second() {
+        assert_eq!(epoch::from_ntp(0x83AA_7E80_8000_0000), Instant::at_ms(0, 500));
+    }
+
+    #[test]
+    fn milliseconds_round_trip() {
+        for ms in 0 .. 1000 {
+            let instant = Instant::at_ms(1_704_067_200, ms);
+            assert_eq!(epoch::from_ntp(epoch::to_ntp(instant).unwrap()), instant);
+        }
+    }
+
+    #[test]
+    fn end_of_era() {
+        assert_eq!(epoch::from_ntp(0xFFFF_FFFF_0000_0000), Instant::at(2_085_978_495));
+        assert_eq!(epoch::to_ntp(Instant::at(2_085_978_496)), Err(Error::OutOfRange));
+        assert_eq!(epoch::to_ntp(Instant::at(-2_208_988_801)), Err(Error::OutOfRange));
+    }
+}
+
+
+mod cocoa {
+    use super::*;
+
+    #[test]
+    fn reference_date() {
+        assert_eq!(epoch::from_cocoa(0.0), Ok(Instant::at(978_307_200)));
+        assert_eq!(epoch::to_cocoa(new_year_2024()), Ok(725_760_000.0));
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(epoch::from_cocoa(725_760_000.25), Ok(Instant::at_ms(1_704_067_200, 250)));
+        assert_eq!(epoch::from_cocoa(-0.5), Ok(Instant::at_ms(978_307_199, 500)));
+    }
+
+    #[test]
+    fn not_finite() {
+        assert_eq!(epoch::from_cocoa(::std::f64::NAN), Err(Error::OutOfRange));
+        assert_eq!(epoch::from_cocoa(::std::f64::INFINITY), Err(Error::OutOfRange));
+        assert_eq!(epoch::from_cocoa(1e300), Err(Error::OutOfRange));
+    }
+}
+
+
+mod gps {
+    use super::*;
+
+    #[test]
+    fn epoch_without_leap_seconds() {
+        assert_eq!(epoch::from_gps(0, 0, 0), Ok(Instant::at(315_964_800)));
+        assert_eq!(epoch::to_gps(Instant::at(315_964_800), 0), Ok((0, 0)));
+    }
+
+    #[test]
+    fn week_2296() {
+        let sunday = Instant::at(1_704_585_600);
+        assert_eq!(epoch::to_gps(sunday, epoch::GPS_LEAP_SECONDS), Ok((2296, 18_000)));
+        assert_eq!(epoch::from_gps(2296, 18_000, epoch::GPS_LEAP_SECONDS), Ok(sunday));
+    }
+
+    #[test]
+    fn end_of_week() {
+        assert_eq!(epoch::from_gps(0, 604_800_000, 0), Err(Error::InvalidValue));
+        assert_eq!(epoch::to_gps(Instant::at_ms(315_964_800 + 604_799, 999), 0), Ok((0, 604_799_999)));
+    }
+
+    #[test]
+    fn before_epoch() {
+        assert_eq!(epoch::to_gps(Instant::at(315_964_799), 0), Err(Error::OutOfRange));
+    }
+}
+
+
+mod dotnet {
+    use super::*;
+
+    #[test]
+    fn unix_epoch() {
+        assert_eq!(epoch::from_dotnet_ticks(621_355_968_000_000_000), Ok(Instant::at_epoch()));
+        assert_eq!(epoch::to_dotnet_ticks(Instant::at_epoch()), Ok(621_355_968_000_000_000));
+    }
+
+    #[test]
+    fn new_year() {
+        assert_eq!(epoch::to_dotnet_ticks(new_year_2024()), Ok(638_396_640_000_000_000));
+    }
+
+    #[test]
+    fn min_and_max() {
+        assert_eq!(epoch::from_dotnet_ticks(0), Ok(Instant::at(-62_135_596_800)));
+        assert_eq!(epoch::from_dotnet_ticks(3_155_378_975_999_999_999), Ok(Instant::at_ms(253_402_300_799, 999)));
+        assert_eq!(epoch::from_dotnet_ticks(3_155_378_976_000_000_000), Err(Error::OutOfRange));
+        assert_eq!(epoch::from_dotnet_ticks(-1), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(epoch::to_dotnet_ticks(Instant::at(253_402_300_800)), Err(Error::OutOfRange));
+        assert_eq!(epoch::to_dotnet_ticks(Instant::at(i64::min_value())), Err(Error::OutOfRange));
+    }
+}
+
+
+mod serial_dates {
+    use super::*;
+    use datetime::epoch::SerialDateSystem::{Excel1900, Excel1904};
+
+    fn midnight(year: i64, month: Month, day: i8) -> Instant {
+        LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::midnight()).to_instant()
+    }
+
+    #[test]
+    fn new_year() {
+        assert_eq!(epoch::from_serial_date(45_292.0, Excel1900), Ok(new_year_2024()));
+        assert_eq!(epoch::from_serial_date(43_830.0, Excel1904), Ok(new_year_2024()));
+        assert_eq!(epoch::to_serial_date(new_year_2024(), Excel1900), Ok(45_292.0));
+        assert_eq!(epoch::to_serial_date(new_year_2024(), Excel1904), Ok(43_830.0));
+    }
+
+    #[test]
+    fn lotus_leap_day() {
+        assert_eq!(epoch::from_serial_date(1.0, Excel1900), Ok(midnight(1900, Month::January, 1)));
+        assert_eq!(epoch::from_serial_date(59.0, Excel1900), Ok(midnight(1900, Month::February, 28)));
+        assert_eq!(epoch::from_serial_date(60.0, Excel1900), Err(Error::InvalidValue));
+        assert_eq!(epoch::from_serial_date(61.0, Excel1900), Ok(midnight(1900, Month::March, 1)));
+
+        assert_eq!(epoch::to_serial_date(midnight(1900, Month::February, 28), Excel1900), Ok(59.0));
+        assert_eq!(epoch::to_serial_date(midnight(1900, Month::March, 1), Excel1900), Ok(61.0));
+    }
+
+    #[test]
+    fn time_of_day() {
+        assert_eq!(epoch::from_serial_date(45_292.75, Excel1900), Ok(Instant::at(1_704_067_200 + 18 * 60 * 60)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(epoch::from_serial_date(-1.0, Excel1900), Err(Error::OutOfRange));
+        assert_eq!(epoch::from_serial_date(2_958_466.0, Excel1900), Err(Error::OutOfRange));
+        assert_eq!(epoch::to_serial_date(midnight(1899, Month::December, 30), Excel1900), Err(Error::OutOfRange));
+        assert_eq!(epoch::to_serial_date(midnight(1903, Month::December, 31), Excel1904), Err(Error::OutOfRange));
+    }
+}
+
+
+mod dos {
+    use super::*;
+
+    #[test]
+    fn new_year() {
+        let date_time = LocalDateTime::new(LocalDate::ymd(2024, Month::January, 1).unwrap(),
+                                           LocalTime::hms(12, 34, 56).unwrap());
+        assert_eq!(epoch::to_dos(date_time), Ok((0x5821, 0x645C)));
+        assert_eq!(epoch::from_dos(0x5821, 0x645C), Ok(date_time));
+    }
+
+    #[test]
+    fn odd_seconds() {
+        let date_time = LocalDateTime::new(LocalDate::ymd(1980, Month::January, 1).unwrap(),
+                                           LocalTime::hms(0, 0, 59).unwrap());
+        assert_eq!(epoch::to_dos(date_time), Ok((0x0021, 0x001D)));
+    }
+
+    #[test]
+    fn invalid_fields() {
+        assert_eq!(epoch::from_dos(0x0000, 0x0000), Err(Error::InvalidValue));
+        assert_eq!(epoch::from_dos(0x5821, 0xC000), Err(Error::InvalidValue));
+        assert_eq!(epoch::from_dos(0x59A1, 0x0000), Err(Error::InvalidValue));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let date_time = LocalDateTime::new(LocalDate::ymd(1979, Month::December, 31).unwrap(),
+                                           LocalTime::midnight());
+        assert_eq!(epoch::to_dos(date_time), Err(Error::OutOfRange));
+    }
+}
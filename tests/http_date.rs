@@ -0,0 +1,123 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Instant};
+use datetime::http_date::{self, Error};
+
+
+fn instant(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8) -> Instant {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hms(hour, minute, second).unwrap()).to_instant()
+}
+
+fn now() -> Instant {
+    instant(2024, Month::June, 1, 0, 0, 0)
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn imf_fixdate() {
+        assert_eq!(http_date::parse("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(instant(1994, Month::November, 6, 8, 49, 37)));
+    }
+
+    #[test]
+    fn rfc850() {
+        assert_eq!(http_date::parse_at("Sunday, 06-Nov-94 08:49:37 GMT", now()), Ok(instant(1994, Month::November, 6, 8, 49, 37)));
+        assert_eq!(http_date::parse_at("Wednesday, 09-Jun-21 10:18:14 GMT", now()), Ok(instant(2021, Month::June, 9, 10, 18, 14)));
+    }
+
+    #[test]
+    fn rfc850_two_digit_years() {
+        // 2074 is exactly 50 years after 2024, so it’s allowed, but 2075 is
+        // too far into the future and becomes 1975.
+        assert_eq!(http_date::parse_at("Monday, 01-Jan-74 00:00:00 GMT", now()), Ok(instant(2074, Month::January, 1, 0, 0, 0)));
+        assert_eq!(http_date::parse_at("Wednesday, 01-Jan-75 00:00:00 GMT", now()), Ok(instant(1975, Month::January, 1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn asctime() {
+        assert_eq!(http_date::parse("Sun Nov  6 08:49:37 1994"), Ok(instant(1994, Month::November, 6, 8, 49, 37)));
+        assert_eq!(http_date::parse("Sun Nov 27 08:49:37 1994"), Ok(instant(1994, Month::November, 27, 8, 49, 37)));
+    }
+
+    #[test]
+    fn leap_day() {
+        assert_eq!(http_date::parse("Thu, 29 Feb 2024 12:00:00 GMT"), Ok(instant(2024, Month::February, 29, 12, 0, 0)));
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn weekday_mismatch() {
+        assert_eq!(http_date::parse("Mon, 06 Nov 1994 08:49:37 GMT"),
+                   Err(Error::WeekdayMismatch { given: Weekday::Monday, actual: Weekday::Sunday }));
+    }
+
+    #[test]
+    fn case_sensitive() {
+        assert_eq!(http_date::parse("sun, 06 Nov 1994 08:49:37 GMT"), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sun, 06 NOV 1994 08:49:37 GMT"), Err(Error::Syntax));
+    }
+
+    #[test]
+    fn not_gmt() {
+        assert_eq!(http_date::parse("Sun, 06 Nov 1994 08:49:37 UTC"), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sun, 06 Nov 1994 08:49:37 +0000"), Err(Error::Syntax));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(http_date::parse(""), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sun, 6 Nov 1994 08:49:37 GMT"), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sun, 06 Nov 1994 08-49-37 GMT"), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sun, 06 Nov 1994 08:49:37 GMT "), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sun, 06-Nov-94 08:49:37 GMT"), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sun Nov 6 08:49:37 1994"), Err(Error::Syntax));
+        assert_eq!(http_date::parse("Sunday, 06 Nov 1994 08:49:37 GMT"), Err(Error::Syntax));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(http_date::parse("Thu, 31 Apr 2003 10:52:37 GMT").is_err());
+        assert!(http_date::parse("Sun, 06 Nov 1994 24:00:00 GMT").is_err());
+        assert!(http_date::parse("Sun, 06 Nov 1994 08:49:60 GMT").is_err());
+    }
+}
+
+
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn imf_fixdate() {
+        assert_eq!(http_date::format(instant(1994, Month::November, 6, 8, 49, 37)).to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn epoch() {
+        assert_eq!(http_date::format(Instant::at_epoch()).to_string(), "Thu, 01 Jan 1970 00:00:00 GMT");
+    }
+
+    #[test]
+    fn drops_milliseconds() {
+        assert_eq!(http_date::format(Instant::at_ms(999, 999)).to_string(), "Thu, 01 Jan 1970 00:16:39 GMT");
+    }
+
+    #[test]
+    fn round_trip() {
+        let instant = instant(2024, Month::February, 29, 23, 59, 59);
+        assert_eq!(http_date::parse(&http_date::format(instant).to_string()), Ok(instant));
+    }
+
+    #[test]
+    fn into_a_buffer() {
+        use std::io::Write;
+
+        let mut buffer = [0u8; 29];
+        write!(&mut buffer[..], "{}", http_date::format(Instant::at(784_111_777))).unwrap();
+        assert_eq!(&buffer[..], &b"Sun, 06 Nov 1994 08:49:37 GMT"[..]);
+    }
+}
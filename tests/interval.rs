@@ -0,0 +1,161 @@
+extern crate datetime;
+use datetime::{Instant, LocalDate, LocalTime, LocalDateTime, Month, Duration};
+use datetime::{Interval, IntervalSet};
+
+
+fn span(start: i64, end: i64) -> Interval<Instant> {
+    Interval::new(Instant::at(start), Instant::at(end)).unwrap()
+}
+
+
+mod intervals {
+    use super::*;
+
+    #[test]
+    fn backwards() {
+        assert!(Interval::new(Instant::at(10), Instant::at(5)).is_err());
+        assert!(Interval::new(Instant::at(5), Instant::at(5)).is_ok());
+    }
+
+    #[test]
+    fn half_open() {
+        let interval = span(10, 20);
+        assert!(interval.contains(Instant::at(10)));
+        assert!(interval.contains(Instant::at(19)));
+        assert!(!interval.contains(Instant::at(20)));
+        assert!(!interval.contains(Instant::at(9)));
+    }
+
+    #[test]
+    fn empty() {
+        let interval = span(10, 10);
+        assert!(interval.is_empty());
+        assert!(!interval.contains(Instant::at(10)));
+        assert!(!interval.overlaps(&span(0, 20)));
+    }
+
+    #[test]
+    fn overlaps() {
+        assert!(span(10, 20).overlaps(&span(15, 25)));
+        assert!(span(10, 20).overlaps(&span(12, 18)));
+        assert!(!span(10, 20).overlaps(&span(20, 30)));
+        assert!(!span(20, 30).overlaps(&span(10, 20)));
+    }
+
+    #[test]
+    fn intersection() {
+        assert_eq!(span(10, 20).intersection(&span(15, 25)), Some(span(15, 20)));
+        assert_eq!(span(10, 20).intersection(&span(12, 18)), Some(span(12, 18)));
+        assert_eq!(span(10, 20).intersection(&span(20, 30)), None);
+    }
+
+    #[test]
+    fn union() {
+        assert_eq!(span(10, 20).union(&span(15, 25)), Some(span(10, 25)));
+        assert_eq!(span(10, 20).union(&span(20, 30)), Some(span(10, 30)));
+        assert_eq!(span(10, 20).union(&span(21, 30)), None);
+    }
+
+    #[test]
+    fn gap() {
+        assert_eq!(span(10, 20).gap(&span(25, 30)), Some(span(20, 25)));
+        assert_eq!(span(25, 30).gap(&span(10, 20)), Some(span(20, 25)));
+        assert_eq!(span(10, 20).gap(&span(20, 30)), None);
+        assert_eq!(span(10, 20).gap(&span(15, 30)), None);
+    }
+
+    #[test]
+    fn instant_duration() {
+        let interval = Interval::new(Instant::at_ms(10, 750), Instant::at_ms(12, 250)).unwrap();
+        assert_eq!(interval.duration(), Duration::of_ms(1, 500));
+    }
+
+    #[test]
+    fn date_duration() {
+        let interval = Interval::new(LocalDate::ymd(2024, Month::February, 1).unwrap(),
+                                     LocalDate::ymd(2024, Month::March, 1).unwrap()).unwrap();
+        assert_eq!(interval.days(), 29);
+        assert_eq!(interval.duration(), Duration::of(29 * 86400));
+        assert!(interval.contains(LocalDate::ymd(2024, Month::February, 29).unwrap()));
+        assert!(!interval.contains(LocalDate::ymd(2024, Month::March, 1).unwrap()));
+    }
+
+    #[test]
+    fn datetime_duration() {
+        let date = LocalDate::ymd(2024, Month::January, 31).unwrap();
+        let interval = Interval::new(LocalDateTime::new(date, LocalTime::hm(22, 0).unwrap()),
+                                     LocalDateTime::new(date.add_days(1), LocalTime::hm(6, 30).unwrap())).unwrap();
+        assert_eq!(interval.duration(), Duration::of(8 * 3600 + 30 * 60));
+    }
+}
+
+
+mod interval_sets {
+    use super::*;
+
+    #[test]
+    fn merges_on_insert() {
+        let set: IntervalSet<_> = vec![ span(30, 40), span(10, 20), span(15, 25), span(50, 60) ].into_iter().collect();
+        assert_eq!(set.intervals(), &[ span(10, 25), span(30, 40), span(50, 60) ]);
+    }
+
+    #[test]
+    fn merges_touching() {
+        let set: IntervalSet<_> = vec![ span(10, 20), span(20, 30) ].into_iter().collect();
+        assert_eq!(set.intervals(), &[ span(10, 30) ]);
+    }
+
+    #[test]
+    fn bridges_several() {
+        let mut set: IntervalSet<_> = vec![ span(10, 20), span(30, 40), span(50, 60), span(70, 80) ].into_iter().collect();
+        set.insert(span(15, 55));
+        assert_eq!(set.intervals(), &[ span(10, 60), span(70, 80) ]);
+    }
+
+    #[test]
+    fn ignores_empty() {
+        let mut set = IntervalSet::new();
+        set.insert(span(10, 10));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut set = IntervalSet::from(span(10, 50));
+        set.remove(span(20, 30));
+        assert_eq!(set.intervals(), &[ span(10, 20), span(30, 50) ]);
+    }
+
+    #[test]
+    fn remove_across_several() {
+        let mut set: IntervalSet<_> = vec![ span(10, 20), span(30, 40), span(50, 60) ].into_iter().collect();
+        set.remove(span(15, 55));
+        assert_eq!(set.intervals(), &[ span(10, 15), span(55, 60) ]);
+    }
+
+    #[test]
+    fn difference() {
+        let opening_hours = IntervalSet::from(span(9, 17));
+        let bookings: IntervalSet<_> = vec![ span(8, 10), span(12, 13), span(16, 18) ].into_iter().collect();
+        let free = opening_hours.difference(&bookings);
+        assert_eq!(free.intervals(), &[ span(10, 12), span(13, 16) ]);
+        assert!(free.contains(Instant::at(10)));
+        assert!(!free.contains(Instant::at(12)));
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let a: IntervalSet<_> = vec![ span(0, 10), span(20, 30) ].into_iter().collect();
+        let b: IntervalSet<_> = vec![ span(5, 25) ].into_iter().collect();
+        assert_eq!(a.union(&b).intervals(), &[ span(0, 30) ]);
+        assert_eq!(a.intersection(&b).intervals(), &[ span(5, 10), span(20, 25) ]);
+    }
+
+    #[test]
+    fn dates() {
+        let date = |day| LocalDate::ymd(2024, Month::July, day).unwrap();
+        let stays: IntervalSet<_> = vec![ Interval::new(date(1), date(5)).unwrap(),
+                                          Interval::new(date(5), date(8)).unwrap() ].into_iter().collect();
+        assert_eq!(stays.intervals(), &[ Interval::new(date(1), date(8)).unwrap() ]);
+    }
+}
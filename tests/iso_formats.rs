@@ -30,6 +30,12 @@ mod datetimes {
         assert_eq!(debugged, "+10601-01-31");
     }
 
+    #[test]
+    fn last_four_digit_year() {
+        let date = LocalDate::ymd(9999, Month::December, 31).unwrap();
+        assert_eq!(date.iso().to_string(), "9999-12-31");
+    }
+
     #[test]
     fn midday() {
         let time = LocalTime::hms(12, 0, 0).unwrap();
@@ -96,3 +102,90 @@ mod offsets {
         assert_eq!(debugged, "2009-02-13T23:31:30.000+00:25:21");
     }
 }
+
+mod options {
+    use super::*;
+    use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset, IsoInterval, Period};
+    use datetime::iso::{IsoFormat, Precision, DateForm};
+
+    fn datetime() -> LocalDateTime {
+        LocalDateTime::new(LocalDate::ymd(2024, Month::January, 31).unwrap(),
+                           LocalTime::hms_ms(10, 30, 15, 250).unwrap())
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(datetime().iso_with(IsoFormat::new()).to_string(), datetime().iso().to_string());
+    }
+
+    #[test]
+    fn basic() {
+        let format = IsoFormat::new().basic();
+        assert_eq!(datetime().iso_with(format).to_string(), "20240131T103015.250");
+
+        let offset = Offset::of_hours_and_minutes(5, 30).unwrap();
+        assert_eq!(offset.transform_date(datetime()).iso_with(format).to_string(), "20240131T103015.250+0530");
+    }
+
+    #[test]
+    fn precisions() {
+        let time = datetime().time();
+        assert_eq!(time.iso_with(IsoFormat::new().precision(Precision::Minutes)).to_string(), "10:30");
+        assert_eq!(time.iso_with(IsoFormat::new().precision(Precision::Seconds)).to_string(), "10:30:15");
+        assert_eq!(time.iso_with(IsoFormat::new().precision(Precision::Microseconds)).to_string(), "10:30:15.250000");
+        assert_eq!(time.iso_with(IsoFormat::new().precision(Precision::Nanoseconds)).to_string(), "10:30:15.250000000");
+        assert_eq!(time.iso_with(IsoFormat::new().precision(Precision::Trimmed)).to_string(), "10:30:15.25");
+    }
+
+    #[test]
+    fn trimmed_whole_seconds() {
+        let time = LocalTime::hms(10, 30, 15).unwrap();
+        assert_eq!(time.iso_with(IsoFormat::new().precision(Precision::Trimmed)).to_string(), "10:30:15");
+    }
+
+    #[test]
+    fn week_dates() {
+        let format = IsoFormat::new().date_form(DateForm::Week);
+        assert_eq!(datetime().date().iso_with(format).to_string(), "2024-W05-3");
+        assert_eq!(datetime().date().iso_with(format.basic()).to_string(), "2024W053");
+
+        let sunday = LocalDate::ymd(2021, Month::January, 3).unwrap();
+        assert_eq!(sunday.iso_with(format).to_string(), "2020-W53-7");
+    }
+
+    #[test]
+    fn ordinal_dates() {
+        let format = IsoFormat::new().date_form(DateForm::Ordinal);
+        assert_eq!(datetime().date().iso_with(format).to_string(), "2024-031");
+        assert_eq!(datetime().date().iso_with(format.basic()).to_string(), "2024031");
+    }
+
+    #[test]
+    fn utc() {
+        let utc = Offset::utc().transform_date(datetime());
+        let format = IsoFormat::new().precision(Precision::Seconds);
+        assert_eq!(utc.iso_with(format).to_string(), "2024-01-31T10:30:15Z");
+        assert_eq!(utc.iso_with(format.utc_as_offset()).to_string(), "2024-01-31T10:30:15+00:00");
+        assert_eq!(utc.iso_with(format.utc_as_offset().basic()).to_string(), "20240131T103015+0000");
+    }
+
+    #[test]
+    fn year_sign() {
+        let format = IsoFormat::new().year_sign();
+        assert_eq!(datetime().date().iso_with(format).to_string(), "+2024-01-31");
+    }
+
+    #[test]
+    fn round_trips() {
+        let format = IsoFormat::new().basic().date_form(DateForm::Week).precision(Precision::Trimmed);
+        let string = datetime().iso_with(format).to_string();
+        assert_eq!(string.parse(), Ok(datetime()));
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = IsoInterval::StartPeriod(datetime().date(), Period { days: 3, .. Period::zero() });
+        let format = IsoFormat::new().date_form(DateForm::Ordinal);
+        assert_eq!(interval.iso_with(format).to_string(), "2024-031/P3D");
+    }
+}
@@ -0,0 +1,211 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset, OffsetDateTime, ISO};
+use datetime::{IsoInterval, RepeatingInterval, Period, PeriodArithmetic};
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+
+mod periods {
+    use super::*;
+
+    #[test]
+    fn full() {
+        let period: Period = "P1Y2M3DT4H5M6.5S".parse().unwrap();
+        assert_eq!(period, Period { years: 1, months: 2, days: 3, hours: 4, minutes: 5, seconds: 6, milliseconds: 500 });
+    }
+
+    #[test]
+    fn weeks() {
+        let period: Period = "P2W".parse().unwrap();
+        assert_eq!(period, Period::of_days(14));
+    }
+
+    #[test]
+    fn comma_fraction() {
+        let period: Period = "PT0,25S".parse().unwrap();
+        assert_eq!(period.milliseconds, 250);
+    }
+
+    #[test]
+    fn months_and_minutes() {
+        let period: Period = "P1MT1M".parse().unwrap();
+        assert_eq!(period.months, 1);
+        assert_eq!(period.minutes, 1);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("P".parse::<Period>().is_err());
+        assert!("PT".parse::<Period>().is_err());
+        assert!("1D".parse::<Period>().is_err());
+        assert!("P1H".parse::<Period>().is_err());
+        assert!("PT1D".parse::<Period>().is_err());
+        assert!("P1D1Y".parse::<Period>().is_err());
+        assert!("P1.5D".parse::<Period>().is_err());
+        assert!("PT1.5S1M".parse::<Period>().is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(Period::zero().iso().to_string(), "PT0S");
+        assert_eq!(Period::of_days(3).iso().to_string(), "P3D");
+        assert_eq!("P1Y2M3DT4H5M6.5S".parse::<Period>().unwrap().iso().to_string(), "P1Y2M3DT4H5M6.5S");
+        assert_eq!("PT0.05S".parse::<Period>().unwrap().iso().to_string(), "PT0.05S");
+    }
+
+    #[test]
+    fn add_months_clamps() {
+        let leap = date(2024, Month::January, 31).add_period(&Period::of_months(1));
+        assert_eq!(leap, date(2024, Month::February, 29));
+
+        let common = date(2023, Month::January, 31).add_period(&Period::of_months(1));
+        assert_eq!(common, date(2023, Month::February, 28));
+    }
+
+    #[test]
+    fn add_time_to_datetime() {
+        let start = LocalDateTime::new(date(2024, Month::February, 28), LocalTime::hm(23, 0).unwrap());
+        let period: Period = "PT2H30M".parse().unwrap();
+        assert_eq!(start.add_period(&period), LocalDateTime::new(date(2024, Month::February, 29), LocalTime::hm(1, 30).unwrap()));
+    }
+
+    #[test]
+    fn until() {
+        let start = date(2024, Month::January, 1);
+        assert_eq!(start.period_until(&date(2024, Month::March, 1)), Period::of_days(60));
+    }
+}
+
+
+mod intervals {
+    use super::*;
+
+    #[test]
+    fn start_end() {
+        let interval: IsoInterval<LocalDate> = "2024-01-01/2024-01-08".parse().unwrap();
+        assert_eq!(interval, IsoInterval::StartEnd(date(2024, Month::January, 1), date(2024, Month::January, 8)));
+    }
+
+    #[test]
+    fn abbreviated_end() {
+        let interval: IsoInterval<LocalDate> = "2024-02-01/15".parse().unwrap();
+        assert_eq!(interval.end(), date(2024, Month::February, 15));
+
+        let interval: IsoInterval<LocalDate> = "2024-02-01/03-01".parse().unwrap();
+        assert_eq!(interval.end(), date(2024, Month::March, 1));
+    }
+
+    #[test]
+    fn abbreviated_end_time() {
+        let interval: IsoInterval<LocalDateTime> = "2024-02-01T09:00:00/17:30:00".parse().unwrap();
+        assert_eq!(interval.end(), LocalDateTime::new(date(2024, Month::February, 1), LocalTime::hm(17, 30).unwrap()));
+    }
+
+    #[test]
+    fn abbreviated_end_keeps_offset() {
+        let interval: IsoInterval<OffsetDateTime> = "2024-02-01T09:00:00+01:00/17:00:00".parse().unwrap();
+        assert_eq!(interval.end().offset, Offset::of_hours_and_minutes(1, 0).unwrap());
+    }
+
+    #[test]
+    fn start_period() {
+        let interval: IsoInterval<LocalDate> = "2024-01-31/P1M".parse().unwrap();
+        assert_eq!(interval.start(), date(2024, Month::January, 31));
+        assert_eq!(interval.end(), date(2024, Month::February, 29));
+    }
+
+    #[test]
+    fn period_end() {
+        let interval: IsoInterval<LocalDate> = "P1W/2024-01-08".parse().unwrap();
+        assert_eq!(interval.start(), date(2024, Month::January, 1));
+        assert_eq!(interval.to_interval().unwrap().days(), 7);
+    }
+
+    #[test]
+    fn backwards() {
+        let interval: IsoInterval<LocalDate> = "2024-01-08/2024-01-01".parse().unwrap();
+        assert!(interval.to_interval().is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("2024-01-01".parse::<IsoInterval<LocalDate>>().is_err());
+        assert!("2024-01-01/P".parse::<IsoInterval<LocalDate>>().is_err());
+        assert!("P1D/P1D".parse::<IsoInterval<LocalDate>>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in &[ "2024-01-01/2024-01-08", "2024-01-01/P1Y2M", "P3D/2024-01-08" ] {
+            let interval: IsoInterval<LocalDate> = input.parse().unwrap();
+            assert_eq!(interval.iso().to_string(), *input);
+        }
+    }
+}
+
+
+mod repeating {
+    use super::*;
+
+    #[test]
+    fn daily() {
+        let series: RepeatingInterval<OffsetDateTime> = "R5/2024-01-01T00:00:00Z/P1D".parse().unwrap();
+        assert_eq!(series.repetitions, Some(5));
+
+        let starts: Vec<_> = series.occurrences().map(|i| i.start().local.date().to_rata_die()).collect();
+        let first = date(2024, Month::January, 1).to_rata_die();
+        assert_eq!(starts, vec![ first, first + 1, first + 2, first + 3, first + 4 ]);
+    }
+
+    #[test]
+    fn forever() {
+        let series: RepeatingInterval<LocalDate> = "R/2024-01-01/P1W".parse().unwrap();
+        assert_eq!(series.repetitions, None);
+
+        let hundredth = series.occurrences().nth(99).unwrap();
+        assert_eq!(hundredth.start(), date(2024, Month::January, 1).add_days(99 * 7));
+    }
+
+    #[test]
+    fn start_end() {
+        let series: RepeatingInterval<LocalDate> = "R3/2024-01-01/2024-01-03".parse().unwrap();
+        let starts: Vec<_> = series.occurrences().map(|i| i.start()).collect();
+        assert_eq!(starts, vec![ date(2024, Month::January, 1), date(2024, Month::January, 3), date(2024, Month::January, 5) ]);
+    }
+
+    #[test]
+    fn period_end_runs_backwards() {
+        let series: RepeatingInterval<LocalDate> = "R2/P1D/2024-01-10".parse().unwrap();
+        let intervals: Vec<_> = series.occurrences().map(|i| (i.start(), i.end())).collect();
+        assert_eq!(intervals, vec![
+            (date(2024, Month::January, 9), date(2024, Month::January, 10)),
+            (date(2024, Month::January, 8), date(2024, Month::January, 9)),
+        ]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("2024-01-01/P1D".parse::<RepeatingInterval<LocalDate>>().is_err());
+        assert!("Rx/2024-01-01/P1D".parse::<RepeatingInterval<LocalDate>>().is_err());
+        assert!("R5".parse::<RepeatingInterval<LocalDate>>().is_err());
+    }
+
+    #[test]
+    fn intervals_without_length() {
+        assert!("R5/2024-01-01/P0D".parse::<RepeatingInterval<LocalDate>>().is_err());
+        assert!("R5/2024-01-01/PT1H".parse::<RepeatingInterval<LocalDate>>().is_err());
+        assert!("R/2024-01-01/2024-01-01".parse::<RepeatingInterval<LocalDate>>().is_err());
+        assert!("R/2024-01-08/2024-01-01".parse::<RepeatingInterval<LocalDate>>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in &[ "R5/2024-01-01/P1D", "R/P1M/2024-12-31" ] {
+            let series: RepeatingInterval<LocalDate> = input.parse().unwrap();
+            assert_eq!(series.iso().to_string(), *input);
+        }
+    }
+}
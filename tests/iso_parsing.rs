@@ -0,0 +1,208 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset, OffsetDateTime, Period};
+use datetime::{IsoInterval, RepeatingInterval};
+use datetime::parse::Error;
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+fn time(hour: i8, minute: i8, second: i8, millisecond: i16) -> LocalTime {
+    LocalTime::hms_ms(hour, minute, second, millisecond).unwrap()
+}
+
+
+mod dates {
+    use super::*;
+
+    #[test]
+    fn calendar() {
+        assert_eq!("2024-01-31".parse(), Ok(date(2024, Month::January, 31)));
+        assert_eq!("20240131".parse(), Ok(date(2024, Month::January, 31)));
+    }
+
+    #[test]
+    fn week() {
+        assert_eq!("2024-W05-3".parse(), Ok(date(2024, Month::January, 31)));
+        assert_eq!("2024W053".parse(), Ok(date(2024, Month::January, 31)));
+        assert_eq!("2020-W53-7".parse(), Ok(date(2021, Month::January, 3)));
+    }
+
+    #[test]
+    fn ordinal() {
+        assert_eq!("2024-031".parse(), Ok(date(2024, Month::January, 31)));
+        assert_eq!("2024031".parse(), Ok(date(2024, Month::January, 31)));
+        assert_eq!("2024-366".parse(), Ok(date(2024, Month::December, 31)));
+    }
+
+    #[test]
+    fn reduced_precision() {
+        assert_eq!("2024-02".parse(), Ok(date(2024, Month::February, 1)));
+        assert_eq!("2024-W05".parse(), Ok(date(2024, Month::January, 29)));
+        assert_eq!("2024W05".parse(), Ok(date(2024, Month::January, 29)));
+        assert_eq!("2024".parse(), Ok(date(2024, Month::January, 1)));
+    }
+
+    #[test]
+    fn expanded_years() {
+        assert_eq!("+012024-01-31".parse(), Ok(date(12024, Month::January, 31)));
+        assert_eq!("+0120240131".parse(), Ok(date(12024, Month::January, 31)));
+        assert_eq!("-0044-03-15".parse(), Ok(date(-44, Month::March, 15)));
+        assert_eq!("+002024-W05-3".parse(), Ok(date(2024, Month::January, 31)));
+    }
+
+    #[test]
+    fn huge_years() {
+        assert_eq!("+999999999-12-31".parse(), Ok(date(999_999_999, Month::December, 31)));
+        assert_eq!("-999999999-01-01".parse(), Ok(date(-999_999_999, Month::January, 1)));
+        assert!("+999999999-12-31T23:59:59-23:59".parse::<OffsetDateTime>().is_ok());
+        assert!("-999999999-01-01T00:00:00+23:59".parse::<OffsetDateTime>().is_ok());
+        assert_eq!("+1000000000-01-01".parse::<LocalDate>(), Err(Error::Parse { position: 1, expected: "a year with at most nine digits" }));
+        assert_eq!("+100000000000000000-01-01".parse::<LocalDate>(), Err(Error::Parse { position: 1, expected: "a year with at most nine digits" }));
+        assert_eq!("+99999999999999999999-01-01".parse::<LocalDate>(), Err(Error::Parse { position: 1, expected: "a year with at most nine digits" }));
+        assert_eq!("+99999999999999999999-01-01T00:00Z".parse::<OffsetDateTime>(), Err(Error::Parse { position: 1, expected: "a year with at most nine digits" }));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(match "2023-02-29".parse::<LocalDate>() { Err(Error::Date(_)) => true, _ => false });
+        assert!(match "2023-366".parse::<LocalDate>() { Err(Error::Date(_)) => true, _ => false });
+        assert!(match "2024-W53-1".parse::<LocalDate>() { Err(Error::Date(_)) => true, _ => false });
+        assert!(match "2024-W00-1".parse::<LocalDate>() { Err(Error::Date(_)) => true, _ => false });
+        assert!(match "2024-13".parse::<LocalDate>() { Err(Error::Date(_)) => true, _ => false });
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!("24-01-31".parse::<LocalDate>(), Err(Error::Parse { position: 0, expected: "a year" }));
+        assert_eq!("202401".parse::<LocalDate>(), Err(Error::Parse { position: 4, expected: "'-', a week, or a day of the year" }));
+        assert_eq!("2024-1-31".parse::<LocalDate>(), Err(Error::Parse { position: 5, expected: "a month, week, or day of the year" }));
+        assert_eq!("2024-01-3".parse::<LocalDate>(), Err(Error::Parse { position: 8, expected: "a day" }));
+        assert_eq!("2024-W5".parse::<LocalDate>(), Err(Error::Parse { position: 6, expected: "a week number" }));
+        assert_eq!("2024-01-31T".parse::<LocalDate>(), Err(Error::Parse { position: 10, expected: "the end of the input" }));
+    }
+}
+
+
+mod times {
+    use super::*;
+
+    #[test]
+    fn extended_and_basic() {
+        assert_eq!("23:59:59".parse(), Ok(time(23, 59, 59, 0)));
+        assert_eq!("235959".parse(), Ok(time(23, 59, 59, 0)));
+        assert_eq!("T23:59".parse(), Ok(time(23, 59, 0, 0)));
+        assert_eq!("2359".parse(), Ok(time(23, 59, 0, 0)));
+        assert_eq!("23".parse(), Ok(time(23, 0, 0, 0)));
+    }
+
+    #[test]
+    fn decimal_fractions() {
+        assert_eq!("T10.5".parse(), Ok(time(10, 30, 0, 0)));
+        assert_eq!("10:30.25".parse(), Ok(time(10, 30, 15, 0)));
+        assert_eq!("10:30:15,5".parse(), Ok(time(10, 30, 15, 500)));
+        assert_eq!("103015.123456789".parse(), Ok(time(10, 30, 15, 123)));
+        assert_eq!("10.0000001".parse(), Ok(time(10, 0, 0, 0)));
+    }
+
+    #[test]
+    fn end_of_day() {
+        assert_eq!("24:00".parse::<LocalTime>().ok(), LocalTime::hms(24, 0, 0).ok());
+        assert_eq!("24:00:00".parse::<LocalTime>().ok(), LocalTime::hms(24, 0, 0).ok());
+        assert!(match "24:00:01".parse::<LocalTime>() { Err(Error::Date(_)) => true, _ => false });
+        assert!(match "24.5".parse::<LocalTime>() { Err(Error::Date(_)) => true, _ => false });
+    }
+
+    #[test]
+    fn offsets_are_ignored() {
+        assert_eq!("10:30Z".parse(), Ok(time(10, 30, 0, 0)));
+        assert_eq!("10:30+05:30".parse(), Ok(time(10, 30, 0, 0)));
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!("1:30".parse::<LocalTime>(), Err(Error::Parse { position: 0, expected: "an hour" }));
+        assert_eq!("10:3".parse::<LocalTime>(), Err(Error::Parse { position: 3, expected: "a minute" }));
+        assert_eq!("10:30:".parse::<LocalTime>(), Err(Error::Parse { position: 6, expected: "a second" }));
+        assert_eq!("10:30.".parse::<LocalTime>(), Err(Error::Parse { position: 6, expected: "a digit" }));
+        assert_eq!("10:3015".parse::<LocalTime>(), Err(Error::Parse { position: 5, expected: "the end of the input" }));
+    }
+}
+
+
+mod datetimes {
+    use super::*;
+
+    #[test]
+    fn basic_format() {
+        assert_eq!("20240131T235959".parse(), Ok(LocalDateTime::new(date(2024, Month::January, 31), time(23, 59, 59, 0))));
+    }
+
+    #[test]
+    fn end_of_day() {
+        assert_eq!("2024-01-31T24:00".parse(), Ok(LocalDateTime::new(date(2024, Month::February, 1), LocalTime::midnight())));
+        assert_eq!("2024-12-31T24:00:00Z".parse::<OffsetDateTime>().map(|d| d.local),
+                   Ok(LocalDateTime::new(date(2025, Month::January, 1), LocalTime::midnight())));
+    }
+
+    #[test]
+    fn week_and_ordinal() {
+        let expected = LocalDateTime::new(date(2024, Month::January, 31), time(10, 30, 0, 0));
+        assert_eq!("2024-W05-3T10:30".parse(), Ok(expected));
+        assert_eq!("2024-031T10.5".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn missing_time() {
+        assert_eq!("2024-01-31".parse::<LocalDateTime>(), Err(Error::Parse { position: 10, expected: "'T'" }));
+    }
+
+    #[test]
+    fn offsets() {
+        let local = LocalDateTime::new(date(2024, Month::January, 31), time(23, 59, 59, 0));
+        assert_eq!("20240131T235959Z".parse(), Ok(Offset::utc().transform_date(local)));
+        assert_eq!("2024-01-31T23:59:59+05:30".parse(), Ok(Offset::of_hours_and_minutes(5, 30).unwrap().transform_date(local)));
+        assert_eq!("20240131T235959-0800".parse(), Ok(Offset::of_hours_and_minutes(-8, 0).unwrap().transform_date(local)));
+        assert_eq!("2024-01-31T23:59:59-08".parse(), Ok(Offset::of_hours_and_minutes(-8, 0).unwrap().transform_date(local)));
+    }
+
+    #[test]
+    fn offset_required() {
+        assert_eq!("2024-01-31T23:59:59".parse::<OffsetDateTime>(), Err(Error::Parse { position: 19, expected: "an offset" }));
+        assert_eq!("2024-01-31T23:59:59+5".parse::<OffsetDateTime>(), Err(Error::Parse { position: 20, expected: "offset hours" }));
+    }
+
+    #[test]
+    fn display() {
+        let error = "2024-01-31T23:59:59".parse::<OffsetDateTime>().unwrap_err();
+        assert_eq!(error.to_string(), "parse error at byte 19: expected an offset");
+    }
+}
+
+
+mod positions {
+    use super::*;
+
+    #[test]
+    fn duration() {
+        assert_eq!("P1Y2X".parse::<Period>(), Err(Error::Parse { position: 3, expected: "a duration" }));
+    }
+
+    #[test]
+    fn interval_end() {
+        assert_eq!("2024-01-01/2024-13-01X".parse::<IsoInterval<LocalDate>>(), Err(Error::Parse { position: 21, expected: "the end of the input" }));
+        assert_eq!("2024-01-01/P1X".parse::<IsoInterval<LocalDate>>(), Err(Error::Parse { position: 12, expected: "a duration" }));
+    }
+
+    #[test]
+    fn completed_interval_end() {
+        assert_eq!("2024-01-01/1X".parse::<IsoInterval<LocalDate>>(), Err(Error::Parse { position: 11, expected: "a day" }));
+    }
+
+    #[test]
+    fn repeating_interval() {
+        assert_eq!("R5/2024-01-01/P".parse::<RepeatingInterval<LocalDate>>(), Err(Error::Parse { position: 15, expected: "a duration" }));
+        assert_eq!("RX/2024-01-01/P1D".parse::<RepeatingInterval<LocalDate>>(), Err(Error::Parse { position: 1, expected: "a number of repetitions" }));
+    }
+}
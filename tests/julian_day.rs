@@ -0,0 +1,114 @@
+extern crate datetime;
+pub use datetime::{Instant, LocalDate, LocalTime, LocalDateTime, Month};
+
+
+mod day_numbers {
+    use super::*;
+
+    #[test]
+    fn rata_die_epoch() {
+        let date = LocalDate::ymd(1, Month::January, 1).unwrap();
+        assert_eq!(date.to_rata_die(), 1);
+        assert_eq!(LocalDate::from_rata_die(1), date);
+    }
+
+    #[test]
+    fn rata_die_1945() {
+        let date = LocalDate::ymd(1945, Month::November, 12).unwrap();
+        assert_eq!(date.to_rata_die(), 710_347);
+        assert_eq!(LocalDate::from_rata_die(710_347), date);
+    }
+
+    #[test]
+    fn julian_day_number_2000() {
+        let date = LocalDate::ymd(2000, Month::January, 1).unwrap();
+        assert_eq!(date.to_julian_day_number(), 2_451_545);
+        assert_eq!(LocalDate::from_julian_day_number(2_451_545), date);
+    }
+
+    #[test]
+    fn julian_day_number_unix_epoch() {
+        let date = LocalDate::ymd(1970, Month::January, 1).unwrap();
+        assert_eq!(date.to_julian_day_number(), 2_440_588);
+    }
+
+    #[test]
+    fn julian_day_number_zero() {
+        // The 1st of January, 4713 BC in the Julian calendar is the 24th of
+        // November, -4713, in the proleptic Gregorian calendar.
+        let date = LocalDate::ymd(-4713, Month::November, 24).unwrap();
+        assert_eq!(date.to_julian_day_number(), 0);
+        assert_eq!(LocalDate::from_julian_day_number(0), date);
+    }
+
+    #[test]
+    fn modified_julian_day_zero() {
+        let date = LocalDate::ymd(1858, Month::November, 17).unwrap();
+        assert_eq!(date.to_modified_julian_day(), 0);
+        assert_eq!(LocalDate::from_modified_julian_day(0), date);
+    }
+
+    #[test]
+    fn modified_julian_day_2000() {
+        let date = LocalDate::ymd(2000, Month::January, 1).unwrap();
+        assert_eq!(date.to_modified_julian_day(), 51_544);
+    }
+
+    #[test]
+    fn round_trips() {
+        for days in -1_000_000 .. 1_000_000 {
+            if days % 997 == 0 {
+                let date = LocalDate::from_rata_die(days);
+                assert_eq!(date.to_rata_die(), days);
+                assert_eq!(LocalDate::from_julian_day_number(date.to_julian_day_number()), date);
+                assert_eq!(LocalDate::from_modified_julian_day(date.to_modified_julian_day()), date);
+            }
+        }
+    }
+}
+
+
+mod julian_dates {
+    use super::*;
+
+    #[test]
+    fn unix_epoch() {
+        assert_eq!(Instant::at_epoch().to_julian_date(), 2_440_587.5);
+        assert_eq!(Instant::at_epoch().to_modified_julian_date(), 40_587.0);
+    }
+
+    #[test]
+    fn j2000() {
+        let j2000 = LocalDateTime::new(LocalDate::ymd(2000, Month::January, 1).unwrap(),
+                                       LocalTime::hms(12, 0, 0).unwrap());
+        assert_eq!(j2000.to_julian_date(), 2_451_545.0);
+        assert_eq!(LocalDateTime::from_julian_date(2_451_545.0), j2000);
+    }
+
+    #[test]
+    fn modified_julian_date_zero() {
+        let midnight = LocalDateTime::new(LocalDate::ymd(1858, Month::November, 17).unwrap(),
+                                          LocalTime::midnight());
+        assert_eq!(midnight.to_modified_julian_date(), 0.0);
+        assert_eq!(LocalDateTime::from_modified_julian_date(0.0), midnight);
+    }
+
+    #[test]
+    fn quarter_day() {
+        let instant = Instant::from_modified_julian_date(51_544.25);
+        assert_eq!(instant, Instant::at(946_684_800 + 6 * 60 * 60));
+    }
+
+    #[test]
+    fn before_the_epoch() {
+        let instant = Instant::at_ms(-1, 500);
+        assert_eq!(Instant::from_julian_date(instant.to_julian_date()), instant);
+    }
+
+    #[test]
+    fn rounds_to_milliseconds() {
+        let instant = Instant::at_ms(1_700_000_000, 123);
+        assert_eq!(Instant::from_julian_date(instant.to_julian_date()), instant);
+        assert_eq!(Instant::from_modified_julian_date(instant.to_modified_julian_date()), instant);
+    }
+}
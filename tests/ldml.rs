@@ -0,0 +1,138 @@
+extern crate datetime;
+extern crate locale;
+
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset};
+use datetime::fmt::{DateFormat, Field, FormatError};
+
+
+fn datetime() -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2024, Month::January, 7).unwrap(), LocalTime::hms_ms(9, 4, 5, 60).unwrap())
+}
+
+fn format(pattern: &str) -> String {
+    DateFormat::from_ldml(pattern).unwrap().format(&datetime(), &locale::Time::english())
+}
+
+
+mod fields {
+    use super::*;
+
+    #[test]
+    fn iso_like() {
+        assert_eq!(format("yyyy-MM-dd'T'HH:mm:ss.SSS"), "2024-01-07T09:04:05.060");
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(format("EEEE, d MMMM y"), "Sunday, 7 January 2024");
+        assert_eq!(format("EEE d MMM"), "Sun 7 Jan");
+        assert_eq!(format("cccc LLLL"), "Sunday January");
+    }
+
+    #[test]
+    fn twelve_hour_clock() {
+        assert_eq!(format("h:mm a"), "9:04 AM");
+        assert_eq!(format("hh:mm a"), "09:04 AM");
+    }
+
+    #[test]
+    fn repeated_letter_widths() {
+        assert_eq!(format("d dd"), "7 07");
+        assert_eq!(format("M MM"), "1 01");
+        assert_eq!(format("D DD DDD"), "7 07 007");
+        assert_eq!(format("yy yyyyy"), "24 02024");
+    }
+
+    #[test]
+    fn weeks() {
+        // The 7th of January 2024 is the Sunday at the end of the first ISO
+        // week, and the 1st of January 2023 is in the last week of 2022.
+        assert_eq!(format("Y-'W'ww-e"), "2024-W01-7");
+
+        let new_year = LocalDateTime::new(LocalDate::ymd(2023, Month::January, 1).unwrap(), LocalTime::midnight());
+        let format = DateFormat::from_ldml("Y-'W'w y").unwrap();
+        assert_eq!(format.format(&new_year, &locale::Time::english()), "2022-W52 2023");
+    }
+
+    #[test]
+    fn quarters_and_eras() {
+        assert_eq!(format("Q QQ QQQ G GGGG"), "1 01 Q1 AD Anno Domini");
+    }
+
+    #[test]
+    fn offsets() {
+        let datetime = Offset::of_hours_and_minutes(5, 30).unwrap().transform_date(datetime());
+        let format = DateFormat::from_ldml("Z xx xxx ZZZZZ").unwrap();
+        assert_eq!(format.format(&datetime, &locale::Time::english()), "+0530 +0530 +05:30 +05:30");
+    }
+
+    #[test]
+    fn standalone_names() {
+        assert_eq!(DateFormat::from_ldml("LLLL").unwrap().fields, DateFormat::parse("{_:L}").unwrap().fields);
+        assert_eq!(DateFormat::from_ldml("ccc").unwrap().fields, DateFormat::parse("{:c}").unwrap().fields);
+    }
+}
+
+
+mod quoting {
+    use super::*;
+
+    #[test]
+    fn quoted_text() {
+        assert_eq!(format("'Day' d"), "Day 7");
+        assert_eq!(format("h 'o''clock' a"), "9 o'clock AM");
+    }
+
+    #[test]
+    fn doubled_quotes() {
+        assert_eq!(format("''yy"), "'24");
+        assert_eq!(format("''''"), "''");
+        assert_eq!(format("'''quoted'''"), "'quoted'");
+    }
+
+    #[test]
+    fn empty_quotes() {
+        assert_eq!(DateFormat::from_ldml("d''").unwrap().fields.len(), 2);
+    }
+
+    #[test]
+    fn literals_are_slices() {
+        assert_eq!(DateFormat::from_ldml("'at' d").unwrap().fields[0], Field::Literal("at"));
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn unclosed_quote() {
+        assert_eq!(DateFormat::from_ldml("d 'of MMMM"), Err(FormatError::UnclosedQuote { open_pos: 2 }));
+    }
+
+    #[test]
+    fn reserved_letters() {
+        assert_eq!(DateFormat::from_ldml("yyyy-MM-dd T"), Err(FormatError::UnsupportedDirective { c: 'T', pos: 11 }));
+        assert_eq!(DateFormat::from_ldml("h:mm b"), Err(FormatError::UnsupportedDirective { c: 'b', pos: 5 }));
+    }
+
+    #[test]
+    fn unsupported_lengths() {
+        assert_eq!(DateFormat::from_ldml("d MMMMM"), Err(FormatError::UnsupportedLength { c: 'M', length: 5, pos: 2 }));
+        assert_eq!(DateFormat::from_ldml("YY"), Err(FormatError::UnsupportedLength { c: 'Y', length: 2, pos: 0 }));
+        assert_eq!(DateFormat::from_ldml("HHH"), Err(FormatError::UnsupportedLength { c: 'H', length: 3, pos: 0 }));
+    }
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let locale = locale::Time::english();
+        let format = DateFormat::from_ldml("EEEE, d MMMM y, HH:mm:ss.SSS").unwrap();
+        let string = format.format(&datetime(), &locale);
+        assert_eq!(format.parse_value(&string, &locale).unwrap().to_local_date_time(), Ok(datetime()));
+    }
+}
@@ -0,0 +1,380 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, DatePiece, TimePiece};
+use datetime::{RecurrenceRule, RecurrenceSet};
+use datetime::recurrence::{ByDay, Error, Frequency, Until};
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+
+fn at(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hm(hour, minute).unwrap())
+}
+
+fn dates(rule: &str, start: LocalDateTime, limit: usize) -> Vec<(i64, i8, i8)> {
+    let rule: RecurrenceRule = rule.parse().unwrap();
+    rule.occurrences(start).take(limit).map(|e| (e.year(), e.month().months_from_january() as i8 + 1, e.day())).collect()
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let rule: RecurrenceRule = "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,-1FR,+2TU;WKST=SU;COUNT=5".parse().unwrap();
+        assert_eq!(rule.frequency, Frequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.count, Some(5));
+        assert_eq!(rule.week_start, Weekday::Sunday);
+        assert_eq!(rule.by_day, vec![
+            ByDay { ordinal: None,     weekday: Weekday::Monday },
+            ByDay { ordinal: Some(-1), weekday: Weekday::Friday },
+            ByDay { ordinal: Some(2),  weekday: Weekday::Tuesday },
+        ]);
+    }
+
+    #[test]
+    fn until() {
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=19971224T000000Z".parse().unwrap();
+        assert_eq!(rule.until, Some(Until::Utc(at(1997, Month::December, 24, 0, 0).to_instant())));
+
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=19971224".parse().unwrap();
+        assert_eq!(rule.until, Some(Until::Local(at(1997, Month::December, 24, 0, 0))));
+    }
+
+    #[test]
+    fn lowercase() {
+        let rule: RecurrenceRule = "freq=monthly;bymonth=1,7".parse().unwrap();
+        assert_eq!(rule.by_month, vec![ Month::January, Month::July ]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("COUNT=3".parse::<RecurrenceRule>(), Err(Error::MissingFrequency));
+        assert_eq!("FREQ=DAILY;COUNT=3;UNTIL=20240101".parse::<RecurrenceRule>(), Err(Error::CountAndUntil));
+        assert_eq!("FREQ=DAILY;BYFORTNIGHT=1".parse::<RecurrenceRule>(), Err(Error::UnknownPart("BYFORTNIGHT".to_owned())));
+        assert_eq!("FREQ=DAILY;BYMONTH=13".parse::<RecurrenceRule>(), Err(Error::InvalidValue("BYMONTH=13".to_owned())));
+        assert!("FREQ=FORTNIGHTLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;INTERVAL=0".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=MONTHLY;BYDAY=0MO".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=MONTHLY;BYMONTHDAY=0".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;UNTIL=1997-12-24".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;BYSECOND=61".parse::<RecurrenceRule>().is_err());
+    }
+}
+
+
+// Most of these are the examples from section 3.8.5.3 of RFC 5545.
+mod expansion {
+    use super::*;
+
+    #[test]
+    fn daily() {
+        let events = dates("FREQ=DAILY;COUNT=10", at(1997, Month::September, 2, 9, 0), 20);
+        assert_eq!(events.len(), 10);
+        assert_eq!(events[9], (1997, 9, 11));
+    }
+
+    #[test]
+    fn every_ten_days() {
+        let events = dates("FREQ=DAILY;INTERVAL=10;COUNT=5", at(1997, Month::September, 2, 9, 0), 20);
+        assert_eq!(events, vec![ (1997, 9, 2), (1997, 9, 12), (1997, 9, 22), (1997, 10, 2), (1997, 10, 12) ]);
+    }
+
+    #[test]
+    fn weekly_until() {
+        let events = dates("FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH", at(1997, Month::September, 2, 9, 0), 20);
+        assert_eq!(events, vec![
+            (1997, 9, 2), (1997, 9, 4), (1997, 9, 9), (1997, 9, 11), (1997, 9, 16),
+            (1997, 9, 18), (1997, 9, 23), (1997, 9, 25), (1997, 9, 30), (1997, 10, 2),
+        ]);
+    }
+
+    #[test]
+    fn week_start() {
+        let start = at(1997, Month::August, 5, 9, 0);
+        assert_eq!(dates("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", start, 10),
+                   vec![ (1997, 8, 5), (1997, 8, 10), (1997, 8, 19), (1997, 8, 24) ]);
+        assert_eq!(dates("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", start, 10),
+                   vec![ (1997, 8, 5), (1997, 8, 17), (1997, 8, 19), (1997, 8, 31) ]);
+    }
+
+    #[test]
+    fn first_friday() {
+        let events = dates("FREQ=MONTHLY;COUNT=10;BYDAY=1FR", at(1997, Month::September, 5, 9, 0), 20);
+        assert_eq!(events, vec![
+            (1997, 9, 5), (1997, 10, 3), (1997, 11, 7), (1997, 12, 5), (1998, 1, 2),
+            (1998, 2, 6), (1998, 3, 6), (1998, 4, 3), (1998, 5, 1), (1998, 6, 5),
+        ]);
+    }
+
+    #[test]
+    fn second_to_last_monday() {
+        let events = dates("FREQ=MONTHLY;COUNT=6;BYDAY=-2MO", at(1997, Month::September, 22, 9, 0), 20);
+        assert_eq!(events, vec![ (1997, 9, 22), (1997, 10, 20), (1997, 11, 17), (1997, 12, 22), (1998, 1, 19), (1998, 2, 16) ]);
+    }
+
+    #[test]
+    fn third_to_last_day() {
+        let events = dates("FREQ=MONTHLY;BYMONTHDAY=-3", at(1997, Month::September, 28, 9, 0), 6);
+        assert_eq!(events, vec![ (1997, 9, 28), (1997, 10, 29), (1997, 11, 28), (1997, 12, 29), (1998, 1, 29), (1998, 2, 26) ]);
+    }
+
+    #[test]
+    fn monthly_on_the_thirty_first() {
+        let events = dates("FREQ=MONTHLY;COUNT=4", at(2024, Month::January, 31, 9, 0), 10);
+        assert_eq!(events, vec![ (2024, 1, 31), (2024, 3, 31), (2024, 5, 31), (2024, 7, 31) ]);
+    }
+
+    #[test]
+    fn june_and_july() {
+        let events = dates("FREQ=YEARLY;COUNT=10;BYMONTH=6,7", at(1997, Month::June, 10, 9, 0), 20);
+        assert_eq!(events.len(), 10);
+        assert_eq!(events[..3], [ (1997, 6, 10), (1997, 7, 10), (1998, 6, 10) ]);
+        assert_eq!(events[9], (2001, 7, 10));
+    }
+
+    #[test]
+    fn twentieth_monday() {
+        let events = dates("FREQ=YEARLY;BYDAY=20MO", at(1997, Month::May, 19, 9, 0), 3);
+        assert_eq!(events, vec![ (1997, 5, 19), (1998, 5, 18), (1999, 5, 17) ]);
+    }
+
+    #[test]
+    fn week_number() {
+        let events = dates("FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", at(1997, Month::May, 12, 9, 0), 3);
+        assert_eq!(events, vec![ (1997, 5, 12), (1998, 5, 11), (1999, 5, 17) ]);
+    }
+
+    #[test]
+    fn year_days() {
+        let events = dates("FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200", at(1997, Month::January, 1, 9, 0), 20);
+        assert_eq!(events, vec![
+            (1997, 1, 1), (1997, 4, 10), (1997, 7, 19), (2000, 1, 1), (2000, 4, 9),
+            (2000, 7, 18), (2003, 1, 1), (2003, 4, 10), (2003, 7, 19), (2006, 1, 1),
+        ]);
+    }
+
+    #[test]
+    fn friday_the_thirteenth() {
+        let events = dates("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13", at(1997, Month::September, 2, 9, 0), 5);
+        assert_eq!(events, vec![ (1998, 2, 13), (1998, 3, 13), (1998, 11, 13), (1999, 8, 13), (2000, 10, 13) ]);
+    }
+
+    #[test]
+    fn last_work_day() {
+        let events = dates("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", at(1997, Month::September, 29, 9, 0), 7);
+        assert_eq!(events, vec![ (1997, 9, 30), (1997, 10, 31), (1997, 11, 28), (1997, 12, 31), (1998, 1, 30), (1998, 2, 27), (1998, 3, 31) ]);
+    }
+
+    #[test]
+    fn third_of_several_weekdays() {
+        let events = dates("FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3", at(1997, Month::September, 4, 9, 0), 10);
+        assert_eq!(events, vec![ (1997, 9, 4), (1997, 10, 7), (1997, 11, 6) ]);
+    }
+
+    #[test]
+    fn leap_day() {
+        let events = dates("FREQ=YEARLY;COUNT=3", at(2024, Month::February, 29, 9, 0), 10);
+        assert_eq!(events, vec![ (2024, 2, 29), (2028, 2, 29), (2032, 2, 29) ]);
+    }
+
+    #[test]
+    fn never_matches() {
+        let rule: RecurrenceRule = "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30".parse().unwrap();
+        assert_eq!(rule.occurrences(at(2024, Month::January, 1, 9, 0)).next(), None);
+    }
+
+    #[test]
+    fn every_three_hours() {
+        let rule: RecurrenceRule = "FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z".parse().unwrap();
+        let times: Vec<_> = rule.occurrences(at(1997, Month::September, 2, 9, 0)).map(|e| e.hour()).collect();
+        assert_eq!(times, vec![ 9, 12, 15 ]);
+    }
+
+    #[test]
+    fn every_fifteen_minutes() {
+        let rule: RecurrenceRule = "FREQ=MINUTELY;INTERVAL=15;COUNT=6".parse().unwrap();
+        let events: Vec<_> = rule.occurrences(at(1997, Month::September, 2, 9, 0)).collect();
+        assert_eq!(events.len(), 6);
+        assert_eq!(events[5], at(1997, Month::September, 2, 10, 15));
+    }
+
+    #[test]
+    fn working_hours() {
+        let daily: RecurrenceRule = "FREQ=DAILY;BYHOUR=9,10,11,12,13,14,15,16;BYMINUTE=0,20,40".parse().unwrap();
+        let minutely: RecurrenceRule = "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16".parse().unwrap();
+        let start = at(1997, Month::September, 2, 9, 0);
+
+        let daily: Vec<_> = daily.occurrences(start).take(50).collect();
+        let minutely: Vec<_> = minutely.occurrences(start).take(50).collect();
+        assert_eq!(daily, minutely);
+        assert_eq!(daily[23], at(1997, Month::September, 2, 16, 40));
+        assert_eq!(daily[24], at(1997, Month::September, 3, 9, 0));
+    }
+
+    #[test]
+    fn leap_seconds_are_skipped() {
+        let rule: RecurrenceRule = "FREQ=MINUTELY;BYSECOND=0,60;COUNT=3".parse().unwrap();
+        assert_eq!(rule.by_second, vec![ 0, 60 ]);
+
+        let events: Vec<_> = rule.occurrences(at(2016, Month::December, 31, 23, 59)).collect();
+        assert_eq!(events, vec![ at(2016, Month::December, 31, 23, 59), at(2017, Month::January, 1, 0, 0), at(2017, Month::January, 1, 0, 1) ]);
+    }
+
+    #[test]
+    fn limited_by_month() {
+        let rule: RecurrenceRule = "FREQ=HOURLY;BYMONTH=3;BYHOUR=12".parse().unwrap();
+        let events: Vec<_> = rule.occurrences(at(2023, Month::April, 1, 0, 0)).take(2).collect();
+        assert_eq!(events, vec![ at(2024, Month::March, 1, 12, 0), at(2024, Month::March, 2, 12, 0) ]);
+    }
+}
+
+
+mod sets {
+    use super::*;
+
+    #[test]
+    fn dates_and_exclusions() {
+        let set: RecurrenceSet = "DTSTART:19970902T090000\n\
+                                  RRULE:FREQ=WEEKLY;COUNT=3\n\
+                                  RDATE:19970903T090000,19970909T090000\n\
+                                  EXDATE:19970916T090000".parse().unwrap();
+
+        let events: Vec<_> = set.occurrences().collect();
+        assert_eq!(events, vec![
+            at(1997, Month::September, 2, 9, 0),
+            at(1997, Month::September, 3, 9, 0),
+            at(1997, Month::September, 9, 9, 0),
+        ]);
+    }
+
+    #[test]
+    fn start_is_included() {
+        let set: RecurrenceSet = "DTSTART:19970902T090000\r\nRRULE:FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13;COUNT=2".parse().unwrap();
+        let events: Vec<_> = set.occurrences().collect();
+        assert_eq!(events, vec![ at(1997, Month::September, 2, 9, 0), at(1998, Month::February, 13, 9, 0) ]);
+    }
+
+    #[test]
+    fn start_counts_towards_the_count() {
+        let set: RecurrenceSet = "DTSTART:19970902T090000\nRRULE:FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13;COUNT=1".parse().unwrap();
+        assert_eq!(set.occurrences().collect::<Vec<_>>(), vec![ at(1997, Month::September, 2, 9, 0) ]);
+
+        let set: RecurrenceSet = "DTSTART:20240101T090000\nRRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=3".parse().unwrap();
+        let days: Vec<_> = set.occurrences().map(|e| e.day()).collect();
+        assert_eq!(days, vec![ 1, 2, 9 ]);
+    }
+
+    #[test]
+    fn matching_start_is_counted_once() {
+        let set: RecurrenceSet = "DTSTART:20240102T090000\nRRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=3".parse().unwrap();
+        let days: Vec<_> = set.occurrences().map(|e| e.day()).collect();
+        assert_eq!(days, vec![ 2, 9, 16 ]);
+    }
+
+    #[test]
+    fn several_rules() {
+        let mut set = RecurrenceSet::new(at(2024, Month::January, 1, 9, 0));
+        set.rules.push("FREQ=DAILY;INTERVAL=2;COUNT=3".parse().unwrap());
+        set.rules.push("FREQ=DAILY;INTERVAL=3;COUNT=3".parse().unwrap());
+
+        let days: Vec<_> = set.occurrences().map(|e| e.day()).collect();
+        assert_eq!(days, vec![ 1, 3, 4, 5, 7 ]);
+    }
+
+    #[test]
+    fn parameters_are_ignored() {
+        let set: RecurrenceSet = "DTSTART;TZID=America/New_York:20240101T090000\nEXDATE;TZID=America/New_York:20240101T090000".parse().unwrap();
+        assert_eq!(set.occurrences().next(), None);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("RRULE:FREQ=DAILY".parse::<RecurrenceSet>(), Err(Error::MissingStart));
+        assert_eq!("DTSTART:20240101T090000\nDTEND:20240101T100000".parse::<RecurrenceSet>(), Err(Error::UnknownPart("DTEND".to_owned())));
+        assert!("DTSTART:2024-01-01".parse::<RecurrenceSet>().is_err());
+    }
+}
+
+
+mod zoned {
+    use super::*;
+
+    // America/New_York in 2024.
+    const EASTERN: &'static StaticTimeZone<'static> = &StaticTimeZone {
+        name: "Eastern",
+        fixed_timespans: FixedTimespanSet {
+            first: FixedTimespan {
+                offset: -5 * 3600,
+                is_dst: false,
+                name: Cow::Borrowed("EST"),
+            },
+            rest: &[
+                (1_710_054_000, FixedTimespan {
+                    offset: -4 * 3600,
+                    is_dst: true,
+                    name: Cow::Borrowed("EDT"),
+                }),
+                (1_730_613_600, FixedTimespan {
+                    offset: -5 * 3600,
+                    is_dst: false,
+                    name: Cow::Borrowed("EST"),
+                }),
+            ],
+        },
+    };
+
+    #[test]
+    fn same_wall_clock_time() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=3".parse().unwrap();
+
+        let events: Vec<_> = rule.zoned_occurrences(at(2024, Month::March, 9, 9, 0), &zone).collect();
+        assert!(events.iter().all(|e| e.hour() == 9));
+
+        let instants: Vec<_> = events.iter().map(|e| e.to_instant().seconds()).collect();
+        assert_eq!(instants[1] - instants[0], 23 * 3600);
+        assert_eq!(instants[2] - instants[1], 24 * 3600);
+    }
+
+    #[test]
+    fn gap() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=2".parse().unwrap();
+
+        let events: Vec<_> = rule.zoned_occurrences(at(2024, Month::March, 9, 2, 30), &zone).collect();
+        assert_eq!(events[1].hour(), 3);
+        assert_eq!(events[1].minute(), 30);
+        assert_eq!(events[1].to_instant().seconds(), 1_710_054_000 + 1800);
+    }
+
+    #[test]
+    fn overlap() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=2".parse().unwrap();
+
+        let events: Vec<_> = rule.zoned_occurrences(at(2024, Month::November, 2, 1, 30), &zone).collect();
+        assert_eq!(events[1].hour(), 1);
+        assert_eq!(events[1].to_instant().seconds(), 1_730_613_600 - 1800);
+    }
+
+    #[test]
+    fn utc_until() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=20240311T123000Z".parse().unwrap();
+        let start = at(2024, Month::March, 9, 9, 0);
+
+        assert_eq!(rule.zoned_occurrences(start, &zone).count(), 2);
+        assert_eq!(rule.occurrences(start).count(), 3);
+    }
+
+    #[test]
+    fn set() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let set: RecurrenceSet = "DTSTART:20240309T023000\nRDATE:20240310T023000".parse().unwrap();
+
+        let hours: Vec<_> = set.zoned_occurrences(&zone).map(|e| e.hour()).collect();
+        assert_eq!(hours, vec![ 2, 3 ]);
+    }
+}
@@ -0,0 +1,154 @@
+extern crate datetime;
+
+use datetime::{Duration, Instant};
+use datetime::cldr::{Locale, Plural};
+use datetime::relative::{RelativeFormat, RelativeUnit, RelativeStyle};
+
+
+fn format(tag: &str) -> RelativeFormat<'static> {
+    RelativeFormat::new(Locale::from_tag(tag).unwrap())
+}
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+
+mod numeric {
+    use super::*;
+
+    #[test]
+    fn past_and_future() {
+        assert_eq!(format("en").format(Duration::of(-3 * HOUR)), "3 hours ago");
+        assert_eq!(format("en").format(Duration::of(2 * DAY)), "in 2 days");
+    }
+
+    #[test]
+    fn singular() {
+        assert_eq!(format("en").format(Duration::of(-DAY)), "1 day ago");
+        assert_eq!(format("en").format(Duration::of(MINUTE)), "in 1 minute");
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(format("en").format(Duration::zero()), "in 0 seconds");
+        assert_eq!(format("fr").format(Duration::zero()), "dans 0 seconde");
+    }
+
+    #[test]
+    fn other_languages() {
+        assert_eq!(format("de").format(Duration::of(-DAY)), "vor 1 Tag");
+        assert_eq!(format("de").format(Duration::of(-3 * DAY)), "vor 3 Tagen");
+        assert_eq!(format("es").format(Duration::of(5 * MINUTE)), "dentro de 5 minutos");
+        assert_eq!(format("ja").format(Duration::of(-2 * HOUR)), "2 時間前");
+    }
+
+    #[test]
+    fn between_instants() {
+        let now = Instant::at(1_700_000_000);
+        assert_eq!(format("en").format_between(Instant::at(1_700_000_000 - 10), now), "10 seconds ago");
+        assert_eq!(format("en").format_between(Instant::at(1_700_000_000 + 40 * DAY), now), "in 1 month");
+    }
+}
+
+
+mod idiomatic {
+    use super::*;
+
+    #[test]
+    fn days() {
+        assert_eq!(format("en").idiomatic().format(Duration::of(-DAY)), "yesterday");
+        assert_eq!(format("en").idiomatic().format(Duration::of(DAY)), "tomorrow");
+        assert_eq!(format("en").idiomatic().format(Duration::of(2 * DAY)), "in 2 days");
+        assert_eq!(format("de").idiomatic().format(Duration::of(2 * DAY)), "übermorgen");
+    }
+
+    #[test]
+    fn now() {
+        assert_eq!(format("en").idiomatic().format(Duration::of(-3)), "3 seconds ago");
+        assert_eq!(format("en").idiomatic().format(Duration::zero()), "now");
+        assert_eq!(format("es").style(RelativeStyle::Idiomatic).format(Duration::of_ms(0, 400)), "ahora");
+    }
+
+    #[test]
+    fn months() {
+        assert_eq!(format("fr").idiomatic().format(Duration::of(-30 * DAY)), "le mois dernier");
+    }
+}
+
+
+mod thresholds {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        assert_eq!(format("en").format(Duration::of(44)), "in 44 seconds");
+        assert_eq!(format("en").format(Duration::of(45)), "in 1 minute");
+        assert_eq!(format("en").format(Duration::of(44 * MINUTE)), "in 44 minutes");
+        assert_eq!(format("en").format(Duration::of(45 * MINUTE)), "in 1 hour");
+        assert_eq!(format("en").format(Duration::of(22 * HOUR)), "in 1 day");
+        assert_eq!(format("en").format(Duration::of(25 * DAY)), "in 25 days");
+        assert_eq!(format("en").format(Duration::of(26 * DAY)), "in 1 month");
+        assert_eq!(format("en").format(Duration::of(300 * DAY)), "in 10 months");
+        assert_eq!(format("en").format(Duration::of(320 * DAY)), "in 1 year");
+        assert_eq!(format("en").format(Duration::of(-400 * DAY)), "1 year ago");
+    }
+
+    #[test]
+    fn huge_durations() {
+        assert_eq!(format("en").format(Duration::of(i64::max_value())), "in 292277025 years");
+        assert_eq!(format("en").format(Duration::of(i64::min_value())), "292277025 years ago");
+        assert_eq!(format("en").format_between(Instant::at(i64::max_value()), Instant::at(i64::min_value())), "in 292277025 years");
+    }
+
+    #[test]
+    fn custom() {
+        let format = format("en").threshold(RelativeUnit::Second, 60).threshold(RelativeUnit::Day, 7).threshold(RelativeUnit::Week, 4);
+        assert_eq!(format.format(Duration::of(50)), "in 50 seconds");
+        assert_eq!(format.format(Duration::of(-20 * DAY)), "3 weeks ago");
+        assert_eq!(format.format(Duration::of(-27 * DAY)), "1 month ago");
+    }
+
+    #[test]
+    fn skipping_units() {
+        let format = format("en").threshold(RelativeUnit::Hour, 0);
+        assert_eq!(format.format(Duration::of(-3 * HOUR)), "0 days ago");
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(format("en").unit_and_count(89_499), (RelativeUnit::Minute, 1));
+        assert_eq!(format("en").unit_and_count(90_000), (RelativeUnit::Minute, 2));
+        assert_eq!(format("en").unit_and_count(-90_000), (RelativeUnit::Minute, -2));
+        assert_eq!(format("en").format(Duration::of(-90)), "2 minutes ago");
+    }
+}
+
+
+mod plurals {
+    use super::*;
+
+    fn plural(tag: &str, number: u64) -> Plural {
+        Locale::from_tag(tag).unwrap().plural(number)
+    }
+
+    #[test]
+    fn english() {
+        assert_eq!(plural("en", 1), Plural::One);
+        assert_eq!(plural("en", 0), Plural::Other);
+        assert_eq!(plural("en", 21), Plural::Other);
+    }
+
+    #[test]
+    fn french() {
+        assert_eq!(plural("fr", 0), Plural::One);
+        assert_eq!(plural("fr", 1), Plural::One);
+        assert_eq!(plural("fr", 2), Plural::Other);
+        assert_eq!(plural("fr", 1_000_000), Plural::Many);
+    }
+
+    #[test]
+    fn japanese() {
+        assert_eq!(plural("ja", 1), Plural::Other);
+    }
+}
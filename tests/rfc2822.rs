@@ -0,0 +1,162 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Offset, OffsetDateTime};
+use datetime::rfc2822::{self, Error};
+
+
+fn datetime(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8, offset: Offset) -> OffsetDateTime {
+    let local = LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hms(hour, minute, second).unwrap());
+    offset.transform_date(local)
+}
+
+fn hours(hours: i8) -> Offset {
+    Offset::of_hours_and_minutes(hours, 0).unwrap()
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn full() {
+        assert_eq!(rfc2822::parse("Tue, 1 Jul 2003 10:52:37 +0200"),
+                   Ok(datetime(2003, Month::July, 1, 10, 52, 37, hours(2))));
+    }
+
+    #[test]
+    fn negative_offset() {
+        assert_eq!(rfc2822::parse("Fri, 21 Nov 1997 09:55:06 -0630"),
+                   Ok(datetime(1997, Month::November, 21, 9, 55, 6, Offset::of_hours_and_minutes(-6, -30).unwrap())));
+    }
+
+    #[test]
+    fn without_weekday() {
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 +0200"),
+                   Ok(datetime(2003, Month::July, 1, 10, 52, 37, hours(2))));
+    }
+
+    #[test]
+    fn without_seconds() {
+        assert_eq!(rfc2822::parse("Tue, 01 Jul 2003 10:52 +0200"),
+                   Ok(datetime(2003, Month::July, 1, 10, 52, 0, hours(2))));
+    }
+
+    #[test]
+    fn any_case() {
+        assert_eq!(rfc2822::parse("tue, 1 JUL 2003 10:52:37 gmt"),
+                   Ok(datetime(2003, Month::July, 1, 10, 52, 37, Offset::utc())));
+    }
+
+    #[test]
+    fn obsolete_zones() {
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 GMT"), Ok(datetime(2003, Month::July, 1, 10, 52, 37, Offset::utc())));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 UT"),  Ok(datetime(2003, Month::July, 1, 10, 52, 37, Offset::utc())));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 EST"), Ok(datetime(2003, Month::July, 1, 10, 52, 37, hours(-5))));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 PDT"), Ok(datetime(2003, Month::July, 1, 10, 52, 37, hours(-7))));
+    }
+
+    #[test]
+    fn military_zones_are_unknown() {
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 Z"), Ok(datetime(2003, Month::July, 1, 10, 52, 37, Offset::utc())));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 A"), Ok(datetime(2003, Month::July, 1, 10, 52, 37, Offset::unknown())));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 J"), Err(Error::UnknownZone("J".to_owned())));
+    }
+
+    #[test]
+    fn unknown_offset() {
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 -0000"), Ok(datetime(2003, Month::July, 1, 10, 52, 37, Offset::unknown())));
+    }
+
+    #[test]
+    fn short_years() {
+        assert_eq!(rfc2822::parse("1 Jul 03 10:52:37 +0000").map(|d| d.local.date()), Ok(LocalDate::ymd(2003, Month::July, 1).unwrap()));
+        assert_eq!(rfc2822::parse("1 Jul 97 10:52:37 +0000").map(|d| d.local.date()), Ok(LocalDate::ymd(1997, Month::July, 1).unwrap()));
+        assert_eq!(rfc2822::parse("1 Jul 103 10:52:37 +0000").map(|d| d.local.date()), Ok(LocalDate::ymd(2003, Month::July, 1).unwrap()));
+    }
+
+    #[test]
+    fn comments_and_folding() {
+        assert_eq!(rfc2822::parse("Thu,\r\n      13\r\n        Feb\r\n          1969\r\n      23:32\r\n               -0330 (Newfoundland Time)"),
+                   Ok(datetime(1969, Month::February, 13, 23, 32, 0, Offset::of_hours_and_minutes(-3, -30).unwrap())));
+
+        assert_eq!(rfc2822::parse("(Sent) Tue, 1 Jul (a (nested) comment \\) here) 2003 10 : 52 : 37 +0200"),
+                   Ok(datetime(2003, Month::July, 1, 10, 52, 37, hours(2))));
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn weekday_mismatch() {
+        assert_eq!(rfc2822::parse("Wed, 1 Jul 2003 10:52:37 +0200"),
+                   Err(Error::WeekdayMismatch { given: Weekday::Wednesday, actual: Weekday::Tuesday }));
+    }
+
+    #[test]
+    fn unknown_names() {
+        assert_eq!(rfc2822::parse("Tues, 1 Jul 2003 10:52:37 +0200"), Err(Error::UnknownWeekday("Tues".to_owned())));
+        assert_eq!(rfc2822::parse("1 July 2003 10:52:37 +0200"), Err(Error::UnknownMonth("July".to_owned())));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 CEST"), Err(Error::UnknownZone("CEST".to_owned())));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 +020"), Err(Error::UnknownZone("+020".to_owned())));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(rfc2822::parse("31 Apr 2003 10:52:37 +0200").is_err());
+        assert!(rfc2822::parse("1 Jul 2003 24:00:00 +0200").is_err());
+        assert!(rfc2822::parse("1 Jul 2003 10:60:00 +0200").is_err());
+        assert!(rfc2822::parse("1 Jul 2003 10:52:37 +0260").is_err());
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!(rfc2822::parse(""), Err(Error::Syntax));
+        assert_eq!(rfc2822::parse("Tue 1 Jul 2003 10:52:37 +0200"), Err(Error::Syntax));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37"), Err(Error::Syntax));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 +0200 extra"), Err(Error::Syntax));
+        assert_eq!(rfc2822::parse("1 Jul 2003 10:52:37 +0200 (unclosed"), Err(Error::Syntax));
+        assert_eq!(rfc2822::parse("1 Jul 2003 1052 +0200"), Err(Error::Syntax));
+    }
+
+    #[test]
+    fn display() {
+        let error = rfc2822::parse("Wed, 1 Jul 2003 10:52:37 +0200").unwrap_err();
+        assert_eq!(error.to_string(), "day of the week does not match the date: given Wednesday, but the date is a Tuesday");
+    }
+}
+
+
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn positive() {
+        let datetime = datetime(2003, Month::July, 1, 10, 52, 37, hours(2));
+        assert_eq!(rfc2822::format(&datetime).to_string(), "Tue, 1 Jul 2003 10:52:37 +0200");
+    }
+
+    #[test]
+    fn negative() {
+        let datetime = datetime(1997, Month::November, 21, 9, 5, 6, Offset::of_hours_and_minutes(-6, -30).unwrap());
+        assert_eq!(rfc2822::format(&datetime).to_string(), "Fri, 21 Nov 1997 09:05:06 -0630");
+    }
+
+    #[test]
+    fn utc() {
+        let datetime = datetime(2000, Month::January, 1, 0, 0, 0, Offset::utc());
+        assert_eq!(rfc2822::format(&datetime).to_string(), "Sat, 1 Jan 2000 00:00:00 +0000");
+    }
+
+    #[test]
+    fn unknown_offset() {
+        let datetime = datetime(2000, Month::January, 1, 0, 0, 0, Offset::unknown());
+        assert_eq!(rfc2822::format(&datetime).to_string(), "Sat, 1 Jan 2000 00:00:00 -0000");
+    }
+
+    #[test]
+    fn round_trip() {
+        let datetime = datetime(2024, Month::February, 29, 23, 59, 59, Offset::of_hours_and_minutes(5, 45).unwrap());
+        assert_eq!(rfc2822::parse(&rfc2822::format(&datetime).to_string()), Ok(datetime));
+    }
+}
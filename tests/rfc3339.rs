@@ -0,0 +1,133 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset, OffsetDateTime};
+use datetime::rfc3339::{self, Error, FractionDigits};
+
+
+fn datetime(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8, millisecond: i16, offset: Offset) -> OffsetDateTime {
+    let local = LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hms_ms(hour, minute, second, millisecond).unwrap());
+    offset.transform_date(local)
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn utc() {
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50.52Z"),
+                   Ok(datetime(1985, Month::April, 12, 23, 20, 50, 520, Offset::utc())));
+    }
+
+    #[test]
+    fn offset() {
+        assert_eq!(rfc3339::parse("1996-12-19T16:39:57-08:00"),
+                   Ok(datetime(1996, Month::December, 19, 16, 39, 57, 0, Offset::of_hours_and_minutes(-8, 0).unwrap())));
+        assert_eq!(rfc3339::parse("1937-01-01T12:00:27.87+00:20"),
+                   Ok(datetime(1937, Month::January, 1, 12, 0, 27, 870, Offset::of_hours_and_minutes(0, 20).unwrap())));
+    }
+
+    #[test]
+    fn lowercase_and_space() {
+        assert_eq!(rfc3339::parse("1985-04-12t23:20:50z"), rfc3339::parse("1985-04-12T23:20:50Z"));
+        assert_eq!(rfc3339::parse("1985-04-12 23:20:50Z"), rfc3339::parse("1985-04-12T23:20:50Z"));
+    }
+
+    #[test]
+    fn unknown_offset() {
+        let unknown = rfc3339::parse("1985-04-12T23:20:50-00:00").unwrap();
+        assert_eq!(unknown.offset, Offset::unknown());
+        assert!(unknown.offset.is_unknown());
+
+        assert!(unknown != rfc3339::parse("1985-04-12T23:20:50+00:00").unwrap());
+        assert!(unknown != rfc3339::parse("1985-04-12T23:20:50Z").unwrap());
+    }
+
+    #[test]
+    fn long_fractions_are_truncated() {
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50.123999999Z"),
+                   Ok(datetime(1985, Month::April, 12, 23, 20, 50, 123, Offset::utc())));
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn missing_offset() {
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50"), Err(Error::MissingOffset));
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50.52"), Err(Error::MissingOffset));
+    }
+
+    #[test]
+    fn not_strict() {
+        assert_eq!(rfc3339::parse("19850412T232050Z"), Err(Error::Syntax(4)));
+        assert_eq!(rfc3339::parse("1985-04-12_23:20:50Z"), Err(Error::Syntax(10)));
+        assert_eq!(rfc3339::parse("1985-04-12T23:20Z"), Err(Error::Syntax(16)));
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50.Z"), Err(Error::Syntax(20)));
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50+0800"), Err(Error::Syntax(22)));
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50+08"), Err(Error::Syntax(22)));
+        assert_eq!(rfc3339::parse("1985-04-12T23:20:50Z "), Err(Error::Syntax(20)));
+        assert_eq!(rfc3339::parse("1985-4-12T23:20:50Z"), Err(Error::Syntax(6)));
+        assert_eq!(rfc3339::parse("+1985-04-12T23:20:50Z"), Err(Error::Syntax(0)));
+        assert_eq!(rfc3339::parse("1985-04-12"), Err(Error::Syntax(10)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(rfc3339::parse("1985-13-12T23:20:50Z").is_err());
+        assert!(rfc3339::parse("1985-02-29T23:20:50Z").is_err());
+        assert!(rfc3339::parse("1985-04-12T24:00:00Z").is_err());
+        assert!(rfc3339::parse("1990-12-31T23:59:60Z").is_err());
+        assert!(rfc3339::parse("1985-04-12T23:20:50+08:60").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(rfc3339::parse("1985-04-12").unwrap_err().to_string(), "invalid RFC 3339 datetime at byte 10");
+    }
+}
+
+
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn fraction_digits() {
+        let datetime = datetime(1985, Month::April, 12, 23, 20, 50, 520, Offset::utc());
+        assert_eq!(rfc3339::format(&datetime, FractionDigits::Zero).to_string(),  "1985-04-12T23:20:50Z");
+        assert_eq!(rfc3339::format(&datetime, FractionDigits::Three).to_string(), "1985-04-12T23:20:50.520Z");
+        assert_eq!(rfc3339::format(&datetime, FractionDigits::Six).to_string(),   "1985-04-12T23:20:50.520000Z");
+        assert_eq!(rfc3339::format(&datetime, FractionDigits::Nine).to_string(),  "1985-04-12T23:20:50.520000000Z");
+        assert_eq!(rfc3339::format(&datetime, FractionDigits::Auto).to_string(),  "1985-04-12T23:20:50.520Z");
+    }
+
+    #[test]
+    fn auto_whole_seconds() {
+        let datetime = datetime(1985, Month::April, 12, 23, 20, 50, 0, Offset::utc());
+        assert_eq!(rfc3339::format(&datetime, FractionDigits::Auto).to_string(), "1985-04-12T23:20:50Z");
+    }
+
+    #[test]
+    fn offsets() {
+        let negative = datetime(1996, Month::December, 19, 16, 39, 57, 0, Offset::of_hours_and_minutes(-8, 0).unwrap());
+        assert_eq!(rfc3339::format(&negative, FractionDigits::Zero).to_string(), "1996-12-19T16:39:57-08:00");
+
+        let positive = datetime(1996, Month::December, 19, 16, 39, 57, 0, Offset::of_hours_and_minutes(5, 45).unwrap());
+        assert_eq!(rfc3339::format(&positive, FractionDigits::Zero).to_string(), "1996-12-19T16:39:57+05:45");
+
+        let zero = datetime(1996, Month::December, 19, 16, 39, 57, 0, Offset::of_seconds(0).unwrap());
+        assert_eq!(rfc3339::format(&zero, FractionDigits::Zero).to_string(), "1996-12-19T16:39:57+00:00");
+
+        let unknown = datetime(1996, Month::December, 19, 16, 39, 57, 0, Offset::unknown());
+        assert_eq!(rfc3339::format(&unknown, FractionDigits::Zero).to_string(), "1996-12-19T16:39:57-00:00");
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in &[ "2024-02-29T23:59:59.999+05:45", "1985-04-12T23:20:50Z", "1985-04-12T23:20:50-00:00" ] {
+            let datetime = rfc3339::parse(input).unwrap();
+            assert_eq!(rfc3339::format(&datetime, FractionDigits::Auto).to_string(), *input);
+        }
+    }
+}
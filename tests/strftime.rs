@@ -0,0 +1,172 @@
+extern crate datetime;
+extern crate locale;
+
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset};
+use datetime::fmt::{DateFormat, FormatError, ParseError};
+use datetime::strftime::strptime;
+
+
+fn datetime() -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2024, Month::February, 3).unwrap(), LocalTime::hms(15, 4, 5).unwrap())
+}
+
+fn format(format: &str, datetime: LocalDateTime) -> String {
+    DateFormat::from_strftime(format).unwrap().format(&datetime, &locale::Time::english())
+}
+
+fn sunday() -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2021, Month::January, 3).unwrap(), LocalTime::midnight())
+}
+
+
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn common() {
+        assert_eq!(format("%Y-%m-%d %H:%M:%S", datetime()), "2024-02-03 15:04:05");
+        assert_eq!(format("%A, %B %d", datetime()), "Saturday, February 03");
+        assert_eq!(format("%a %b %I:%M %p", datetime()), "Sat Feb 03:04 PM");
+    }
+
+    #[test]
+    fn padding_flags() {
+        assert_eq!(format("[%e] [%-d] [%_m] [%k] [%l]", datetime()), "[ 3] [3] [ 2] [15] [ 3]");
+        assert_eq!(format("[%j] [%-j] [%_5j] [%10Y] [%06Y]", datetime()), "[034] [34] [   34] [0000002024] [002024]");
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(format("%G-W%V-%u", sunday()), "2020-W53-7");
+        assert_eq!(format("%U %W %w", sunday()), "01 00 0");
+    }
+
+    #[test]
+    fn timestamp() {
+        let then = LocalDateTime::new(LocalDate::ymd(2009, Month::February, 13).unwrap(), LocalTime::hms(23, 31, 30).unwrap());
+        assert_eq!(format("%s", then), "1234567890");
+    }
+
+    #[test]
+    fn offsets() {
+        let datetime = Offset::of_hours_and_minutes(-8, 0).unwrap().transform_date(datetime());
+        let format = DateFormat::from_strftime("%z %:z").unwrap();
+        assert_eq!(format.format(&datetime, &locale::Time::english()), "-0800 -08:00");
+    }
+
+    #[test]
+    fn shorthands() {
+        assert_eq!(format("%c", datetime()), "Sat Feb  3 15:04:05 2024");
+        assert_eq!(format("%F", LocalDateTime::new(LocalDate::ymd(44, Month::March, 15).unwrap(), LocalTime::midnight())), "0044-03-15");
+        assert_eq!(format("%F", LocalDateTime::new(LocalDate::ymd(-44, Month::March, 15).unwrap(), LocalTime::midnight())), "-044-03-15");
+        assert_eq!(format("%D %F %T %R", datetime()), "02/03/24 2024-02-03 15:04:05 15:04");
+        assert_eq!(format("%r", datetime()), "03:04:05 PM");
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(format("100%% %Y%n%t{}", datetime()), "100% 2024\n\t{}");
+    }
+}
+
+
+mod unsupported {
+    use super::*;
+
+    #[test]
+    fn directives() {
+        assert_eq!(DateFormat::from_strftime("%C"), Err(FormatError::UnsupportedDirective { c: 'C', pos: 0 }));
+        assert_eq!(DateFormat::from_strftime("at %P"), Err(FormatError::UnsupportedDirective { c: 'P', pos: 3 }));
+        assert_eq!(DateFormat::from_strftime("%Ey"), Err(FormatError::UnsupportedDirective { c: 'E', pos: 0 }));
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(DateFormat::from_strftime("%^a"), Err(FormatError::UnsupportedDirective { c: '^', pos: 0 }));
+        assert_eq!(DateFormat::from_strftime("%:d"), Err(FormatError::UnsupportedDirective { c: ':', pos: 0 }));
+    }
+
+    #[test]
+    fn unfinished() {
+        assert_eq!(DateFormat::from_strftime("%Y-%"), Err(FormatError::UnfinishedDirective { pos: 3 }));
+        assert_eq!(DateFormat::from_strftime("%-"), Err(FormatError::UnfinishedDirective { pos: 0 }));
+    }
+
+    #[test]
+    fn strptime_reports_them() {
+        let locale = locale::Time::english();
+        assert_eq!(strptime("2024", "%C", &locale), Err(ParseError::Format(FormatError::UnsupportedDirective { c: 'C', pos: 0 })));
+    }
+}
+
+
+mod parsing {
+    use super::*;
+
+    fn parse(input: &str, format: &str) -> Result<LocalDateTime, ParseError> {
+        strptime(input, format, &locale::Time::english()).and_then(|parsed| parsed.to_local_date_time())
+    }
+
+    fn parse_date(input: &str, format: &str) -> Result<LocalDate, ParseError> {
+        strptime(input, format, &locale::Time::english()).and_then(|parsed| parsed.to_local_date())
+    }
+
+    #[test]
+    fn common() {
+        assert_eq!(parse("2024-02-03 15:04:05", "%Y-%m-%d %H:%M:%S"), Ok(datetime()));
+        assert_eq!(parse("Saturday, 3 February 2024 3:04:05 pm", "%A, %d %B %Y %I:%M:%S %p"), Ok(datetime()));
+    }
+
+    #[test]
+    fn padding_is_optional() {
+        assert_eq!(parse("2024-2-3 15:4:5", "%Y-%m-%d %H:%M:%S"), Ok(datetime()));
+        assert_eq!(parse("Feb  3 15:04:05 2024", "%b %e %T %Y"), Ok(datetime()));
+        assert_eq!(parse("20240203150405", "%Y%m%d%H%M%S"), Ok(datetime()));
+    }
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(parse("2024-02-03T15:04:05", "%Y-%m-%dT%H:%M:%S"), Ok(datetime()));
+        assert_eq!(parse("2024-02-03   15:04:05", "%F %T"), Ok(datetime()));
+        assert_eq!(parse("2024-02-0315:04:05", "%F %T"), Ok(datetime()));
+        assert_eq!(parse("2024-02-03\t15:04:05", "%F%n%T"), Ok(datetime()));
+    }
+
+    #[test]
+    fn log_line() {
+        let parsed = strptime("03/Feb/2024:15:04:05 -0800", "%d/%b/%Y:%H:%M:%S %z", &locale::Time::english()).unwrap();
+        let offset = Offset::of_hours_and_minutes(-8, 0).unwrap();
+        assert_eq!(parsed.to_offset_date_time(Offset::utc()), Ok(offset.transform_date(datetime())));
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(parse_date("2020-W53-7", "%G-W%V-%u"), Ok(sunday().date()));
+        assert_eq!(parse_date("2021 01 0", "%Y %U %w"), Ok(sunday().date()));
+        assert_eq!(parse_date("2021 00 0", "%Y %W %w"), Ok(sunday().date()));
+        assert!(match parse_date("2021 00", "%Y %U") { Err(ParseError::Date(_)) => true, _ => false });
+    }
+
+    #[test]
+    fn day_of_year() {
+        assert_eq!(parse_date("2024 034", "%Y %j"), Ok(datetime().date()));
+        assert_eq!(parse_date("24 34", "%y %j"), Ok(datetime().date()));
+    }
+
+    #[test]
+    fn timestamps() {
+        let then = LocalDateTime::new(LocalDate::ymd(2009, Month::February, 13).unwrap(), LocalTime::hms(23, 31, 30).unwrap());
+        assert_eq!(parse("1234567890", "%s"), Ok(then));
+
+        let parsed = strptime("1234567890 +0100", "%s %z", &locale::Time::english()).unwrap();
+        assert_eq!(parsed.to_local_date_time(), Ok(LocalDateTime::new(then.date().add_days(1), LocalTime::hms(0, 31, 30).unwrap())));
+    }
+
+    #[test]
+    fn mismatches() {
+        let locale = locale::Time::english();
+        assert_eq!(strptime("2024-02-03 extra", "%F", &locale), Err(ParseError::TrailingInput { pos: 10 }));
+        assert_eq!(strptime("2024/02/03", "%F", &locale), Err(ParseError::InvalidField { field: 1, pos: 4 }));
+        assert_eq!(parse_date("Fri 2024-02-03", "%a %F"), Err(ParseError::Inconsistent("weekday")));
+    }
+}
@@ -0,0 +1,156 @@
+extern crate datetime;
+
+use std::borrow::Cow;
+
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset, DateStyle, TimeStyle};
+use datetime::cldr::Locale;
+use datetime::fmt::DateFormat;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+
+
+fn locale(tag: &str) -> &'static Locale {
+    Locale::from_tag(tag).unwrap()
+}
+
+fn date() -> LocalDate {
+    LocalDate::ymd(2024, Month::January, 31).unwrap()
+}
+
+fn time() -> LocalTime {
+    LocalTime::hms(15, 4, 5).unwrap()
+}
+
+fn datetime() -> LocalDateTime {
+    LocalDateTime::new(date(), time())
+}
+
+static DATE_STYLES: [DateStyle; 4] = [ DateStyle::Short, DateStyle::Medium, DateStyle::Long, DateStyle::Full ];
+static TIME_STYLES: [TimeStyle; 4] = [ TimeStyle::Short, TimeStyle::Medium, TimeStyle::Long, TimeStyle::Full ];
+
+
+mod dates {
+    use super::*;
+
+    #[test]
+    fn short() {
+        assert_eq!(DateStyle::Short.format(&date(), locale("en-US")), "1/31/24");
+        assert_eq!(DateStyle::Short.format(&date(), locale("de-DE")), "31.01.24");
+        assert_eq!(DateStyle::Short.format(&date(), locale("en-GB")), "31/01/2024");
+        assert_eq!(DateStyle::Short.format(&date(), locale("ja")), "2024/01/31");
+    }
+
+    #[test]
+    fn english() {
+        assert_eq!(DateStyle::Medium.format(&date(), locale("en")), "Jan 31, 2024");
+        assert_eq!(DateStyle::Long.format(&date(), locale("en")), "January 31, 2024");
+        assert_eq!(DateStyle::Full.format(&date(), locale("en")), "Wednesday, January 31, 2024");
+    }
+
+    #[test]
+    fn other_languages() {
+        assert_eq!(DateStyle::Medium.format(&date(), locale("fr")), "31 janv. 2024");
+        assert_eq!(DateStyle::Full.format(&date(), locale("de")), "Mittwoch, 31. Januar 2024");
+        assert_eq!(DateStyle::Full.format(&date(), locale("es")), "miércoles, 31 de enero de 2024");
+        assert_eq!(DateStyle::Full.format(&date(), locale("ja")), "2024年1月31日水曜日");
+    }
+
+    #[test]
+    fn date_of_a_datetime() {
+        assert_eq!(DateStyle::Short.format(&datetime(), locale("en")), "1/31/24");
+    }
+
+    #[test]
+    fn patterns() {
+        assert_eq!(DateStyle::Short.pattern(locale("en")), "M/d/yy");
+        assert_eq!(DateStyle::Full.pattern(locale("de")), "EEEE, d. MMMM y");
+    }
+}
+
+
+mod times {
+    use super::*;
+
+    #[test]
+    fn twelve_hour() {
+        assert_eq!(TimeStyle::Short.format(&time(), locale("en")), "3:04\u{202f}PM");
+        assert_eq!(TimeStyle::Medium.format(&time(), locale("en")), "3:04:05\u{202f}PM");
+    }
+
+    #[test]
+    fn twenty_four_hour() {
+        assert_eq!(TimeStyle::Short.format(&time(), locale("de")), "15:04");
+        assert_eq!(TimeStyle::Medium.format(&time(), locale("es")), "15:04:05");
+    }
+
+    #[test]
+    fn without_a_zone() {
+        assert_eq!(TimeStyle::Long.format(&time(), locale("en")), "3:04:05\u{202f}PM");
+        assert_eq!(TimeStyle::Full.format(&time(), locale("es")), "15:04:05");
+        assert_eq!(TimeStyle::Full.format(&datetime(), locale("ja")), "15時04分05秒");
+    }
+
+    #[test]
+    fn with_an_offset() {
+        let offset = Offset::of_hours_and_minutes(1, 0).unwrap().transform_date(datetime());
+        assert_eq!(TimeStyle::Long.format(&offset, locale("de")), format!("{} +01:00", TimeStyle::Medium.format(&offset, locale("de"))));
+        assert!(TimeStyle::Full.format(&offset, locale("es")).ends_with(" (+01:00)"));
+    }
+
+    #[test]
+    fn with_a_time_zone() {
+        static ZONE: StaticTimeZone<'static> = StaticTimeZone {
+            name: "Test",
+            fixed_timespans: FixedTimespanSet {
+                first: FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("CET") },
+                rest: &[],
+            },
+        };
+
+        let zone = TimeZone(TimeZoneSource::Static(&ZONE));
+        let zoned = zone.convert_local(datetime()).unwrap_precise();
+        assert_eq!(TimeStyle::Long.format(&zoned, locale("en-GB")), "15:04:05 CET");
+        assert_eq!(TimeStyle::Full.format(&zoned, locale("en-GB")), "15:04:05 CET");
+    }
+}
+
+
+mod date_times {
+    use super::*;
+
+    #[test]
+    fn combined() {
+        assert_eq!(DateStyle::Short.with_time(TimeStyle::Short).format(&datetime(), locale("en")), "1/31/24, 3:04\u{202f}PM");
+        assert_eq!(DateStyle::Medium.with_time(TimeStyle::Medium).format(&datetime(), locale("fr")), "31 janv. 2024 15:04:05");
+        assert_eq!(DateStyle::Long.with_time(TimeStyle::Short).format(&datetime(), locale("ja")), "2024年1月31日 15:04");
+    }
+
+    #[test]
+    fn with_an_offset() {
+        let offset = Offset::of_hours_and_minutes(5, 30).unwrap().transform_date(LocalDateTime::new(date(), LocalTime::hms(10, 0, 0).unwrap()));
+        assert_eq!(DateStyle::Short.with_time(TimeStyle::Long).format(&offset, locale("de")), "31.01.24, 10:00:00 +05:30");
+        assert_eq!(DateStyle::Medium.with_time(TimeStyle::Short).format(&offset, locale("en")), "Jan 31, 2024, 10:00\u{202f}AM");
+    }
+
+    #[test]
+    fn patterns() {
+        assert_eq!(DateStyle::Long.with_time(TimeStyle::Short).pattern(locale("de")), "d. MMMM y, HH:mm");
+    }
+
+    #[test]
+    fn every_pattern_is_understood() {
+        for locale in Locale::all() {
+            let offset = Offset::utc().transform_date(datetime());
+
+            for &date in DATE_STYLES.iter() {
+                assert!(DateFormat::from_ldml(date.pattern(locale)).is_ok(), "{} {:?}", locale.tag(), date);
+
+                // The time zone in the time patterns is rewritten to suit
+                // the value before they’re used, so try both kinds.
+                for &time in TIME_STYLES.iter() {
+                    let _ = date.with_time(time).format(&datetime(), locale);
+                    let _ = date.with_time(time).format(&offset, locale);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+extern crate datetime;
+use datetime::{LocalTime, Duration, TimeRange};
+
+
+fn hm(hour: i8, minute: i8) -> LocalTime {
+    LocalTime::hm(hour, minute).unwrap()
+}
+
+
+#[test]
+fn addition() {
+    assert_eq!(hm(10, 0) + Duration::of(90 * 60), hm(11, 30));
+}
+
+#[test]
+fn addition_wraps() {
+    assert_eq!(hm(23, 0) + Duration::of(2 * 60 * 60), hm(1, 0));
+}
+
+#[test]
+fn subtraction_wraps() {
+    assert_eq!(hm(1, 0) - Duration::of(2 * 60 * 60), hm(23, 0));
+}
+
+#[test]
+fn milliseconds_carry() {
+    let time = LocalTime::hms_ms(23, 59, 59, 900).unwrap();
+    assert_eq!(time.overflowing_add(Duration::of_ms(0, 200)), (LocalTime::hms_ms(0, 0, 0, 100).unwrap(), 1));
+}
+
+#[test]
+fn overflowing_days() {
+    assert_eq!(hm(12, 0).overflowing_add(Duration::of(3 * 86400 + 13 * 3600)), (hm(1, 0), 4));
+    assert_eq!(hm(12, 0).overflowing_add(Duration::zero() - Duration::of(13 * 3600)), (hm(23, 0), -1));
+    assert_eq!(hm(12, 0).overflowing_add(Duration::of(3600)), (hm(13, 0), 0));
+}
+
+#[test]
+fn huge_duration() {
+    // The leftover 15:30:07 takes midday past midnight one more time.
+    let (time, days) = hm(12, 0).overflowing_add(Duration::of(i64::max_value()));
+    assert_eq!(time, LocalTime::hms(3, 30, 7).unwrap());
+    assert_eq!(days, i64::max_value() / 86400 + 1);
+}
+
+#[test]
+fn difference() {
+    assert_eq!(hm(18, 30) - hm(9, 0), Duration::of(9 * 3600 + 30 * 60));
+    assert_eq!(hm(9, 0) - hm(18, 30), Duration::zero() - Duration::of(9 * 3600 + 30 * 60));
+}
+
+#[test]
+fn difference_with_milliseconds() {
+    let a = LocalTime::hms_ms(0, 0, 1, 0).unwrap();
+    let b = LocalTime::hms_ms(0, 0, 0, 250).unwrap();
+    assert_eq!(a - b, Duration::of_ms(0, 750));
+    assert_eq!(b - a, Duration::of_ms(-1, 250));
+}
+
+#[test]
+fn until() {
+    assert_eq!(hm(22, 0).until(hm(6, 0)), Duration::of(8 * 3600));
+    assert_eq!(hm(6, 0).until(hm(22, 0)), Duration::of(16 * 3600));
+    assert_eq!(hm(6, 0).until(hm(6, 0)), Duration::zero());
+}
+
+
+#[test]
+fn range_during_the_day() {
+    let range = TimeRange::new(hm(9, 0), hm(17, 30));
+    assert!(!range.crosses_midnight());
+    assert!(range.contains(hm(9, 0)));
+    assert!(range.contains(hm(17, 29)));
+    assert!(!range.contains(hm(17, 30)));
+    assert!(!range.contains(hm(8, 59)));
+    assert_eq!(range.duration(), Duration::of(8 * 3600 + 30 * 60));
+}
+
+#[test]
+fn range_across_midnight() {
+    let range = TimeRange::new(hm(22, 0), hm(6, 0));
+    assert!(range.crosses_midnight());
+    assert!(range.contains(hm(22, 0)));
+    assert!(range.contains(hm(0, 0)));
+    assert!(range.contains(hm(5, 59)));
+    assert!(!range.contains(hm(6, 0)));
+    assert!(!range.contains(hm(12, 0)));
+    assert_eq!(range.duration(), Duration::of(8 * 3600));
+}
+
+#[test]
+fn empty_range() {
+    let range = TimeRange::new(hm(9, 0), hm(9, 0));
+    assert!(range.is_empty());
+    assert!(!range.contains(hm(9, 0)));
+    assert_eq!(range.duration(), Duration::zero());
+}
+
+#[test]
+fn whole_day() {
+    let range = TimeRange::new(LocalTime::midnight(), hm(24, 0));
+    assert!(range.contains(LocalTime::midnight()));
+    assert!(range.contains(LocalTime::hms_ms(23, 59, 59, 999).unwrap()));
+    assert_eq!(range.duration(), Duration::of(86400));
+}
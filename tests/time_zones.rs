@@ -94,3 +94,33 @@ fn impossible() {
     assert!(converted.is_impossible(),
         "Local time {:?} should be impossible", converted);
 }
+
+const NEGATIVE_ZONESET: &'static StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Negative Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: -4 * 3600,
+            is_dst: true,
+            name: Cow::Borrowed("ZONE_C"),
+        },
+        rest: &[
+            (1730613600, FixedTimespan {
+                offset: -5 * 3600,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_D"),
+            }),
+        ]
+    }
+};
+
+#[test]
+fn after_a_transition_behind_utc() {
+    let test_date = LocalDateTime::new(
+        LocalDate::ymd(2024, Month::November, 3).unwrap(),
+        LocalTime::hms(2, 30, 0).unwrap(),
+    );
+
+    let zone = TimeZone(TimeZoneSource::Static(NEGATIVE_ZONESET));
+    let zoned_date = zone.convert_local(test_date).unwrap_precise();
+    assert_eq!(zoned_date.to_instant().seconds(), 1730613600 + 3600 + 1800);
+}
@@ -0,0 +1,259 @@
+extern crate datetime;
+use datetime::{Instant, LocalDate, LocalTime, LocalDateTime, Month, Weekday, Offset, TimeUnit};
+use datetime::{DatePiece, TimePiece};
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone, LocalTimes};
+use std::borrow::Cow;
+
+
+fn datetime(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8, millisecond: i16) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(),
+                       LocalTime::hms_ms(hour, minute, second, millisecond).unwrap())
+}
+
+
+mod local_date_time {
+    use super::*;
+
+    #[test]
+    fn truncate_small_units() {
+        let then = datetime(2024, Month::January, 31, 14, 37, 12, 345);
+        assert_eq!(then.truncate_to(TimeUnit::Milliseconds(100)), datetime(2024, Month::January, 31, 14, 37, 12, 300));
+        assert_eq!(then.truncate_to(TimeUnit::Seconds(1)),        datetime(2024, Month::January, 31, 14, 37, 12,   0));
+        assert_eq!(then.truncate_to(TimeUnit::Minutes(1)),        datetime(2024, Month::January, 31, 14, 37,  0,   0));
+        assert_eq!(then.truncate_to(TimeUnit::Minutes(5)),        datetime(2024, Month::January, 31, 14, 35,  0,   0));
+        assert_eq!(then.truncate_to(TimeUnit::Hours(1)),          datetime(2024, Month::January, 31, 14,  0,  0,   0));
+        assert_eq!(then.truncate_to(TimeUnit::Hours(6)),          datetime(2024, Month::January, 31, 12,  0,  0,   0));
+        assert_eq!(then.truncate_to(TimeUnit::Day),               datetime(2024, Month::January, 31,  0,  0,  0,   0));
+    }
+
+    #[test]
+    fn truncate_large_units() {
+        // The 31st of January, 2024 was a Wednesday.
+        let then = datetime(2024, Month::January, 31, 14, 37, 12, 345);
+        assert_eq!(then.truncate_to(TimeUnit::Week(Weekday::Monday)),    datetime(2024, Month::January, 29, 0, 0, 0, 0));
+        assert_eq!(then.truncate_to(TimeUnit::Week(Weekday::Sunday)),    datetime(2024, Month::January, 28, 0, 0, 0, 0));
+        assert_eq!(then.truncate_to(TimeUnit::Week(Weekday::Wednesday)), datetime(2024, Month::January, 31, 0, 0, 0, 0));
+        assert_eq!(then.truncate_to(TimeUnit::Week(Weekday::Thursday)),  datetime(2024, Month::January, 25, 0, 0, 0, 0));
+        assert_eq!(then.truncate_to(TimeUnit::Month),                    datetime(2024, Month::January,  1, 0, 0, 0, 0));
+        assert_eq!(then.truncate_to(TimeUnit::Year),                     datetime(2024, Month::January,  1, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn ceil() {
+        let then = datetime(2024, Month::February, 14, 23, 58, 1, 0);
+        assert_eq!(then.ceil_to(TimeUnit::Minutes(1)),               datetime(2024, Month::February, 14, 23, 59, 0, 0));
+        assert_eq!(then.ceil_to(TimeUnit::Minutes(5)),               datetime(2024, Month::February, 15,  0,  0, 0, 0));
+        assert_eq!(then.ceil_to(TimeUnit::Week(Weekday::Monday)),    datetime(2024, Month::February, 19,  0,  0, 0, 0));
+        assert_eq!(then.ceil_to(TimeUnit::Month),                    datetime(2024, Month::March,     1,  0,  0, 0, 0));
+        assert_eq!(then.ceil_to(TimeUnit::Year),                     datetime(2025, Month::January,   1,  0,  0, 0, 0));
+    }
+
+    #[test]
+    fn ceil_when_already_aligned() {
+        let then = datetime(2024, Month::March, 1, 0, 0, 0, 0);
+        assert_eq!(then.ceil_to(TimeUnit::Seconds(1)), then);
+        assert_eq!(then.ceil_to(TimeUnit::Month), then);
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(datetime(2024, Month::June, 1, 10, 7, 29, 999).round_to(TimeUnit::Minutes(15)), datetime(2024, Month::June, 1, 10,  0, 0, 0));
+        assert_eq!(datetime(2024, Month::June, 1, 10, 7, 30,   0).round_to(TimeUnit::Minutes(15)), datetime(2024, Month::June, 1, 10, 15, 0, 0));
+        assert_eq!(datetime(2024, Month::June, 1, 11, 59,  0,  0).round_to(TimeUnit::Day),         datetime(2024, Month::June, 1,  0,  0, 0, 0));
+        assert_eq!(datetime(2024, Month::June, 1, 12,  0,  0,  0).round_to(TimeUnit::Day),         datetime(2024, Month::June, 2,  0,  0, 0, 0));
+        assert_eq!(datetime(2024, Month::June, 16, 0,  0,  0,  0).round_to(TimeUnit::Month),       datetime(2024, Month::July, 1,  0,  0, 0, 0));
+    }
+
+    #[test]
+    fn uneven_buckets() {
+        // Seven-hour buckets leave a three-hour bucket at the end of the day.
+        let then = datetime(2024, Month::June, 1, 22, 0, 0, 0);
+        assert_eq!(then.truncate_to(TimeUnit::Hours(7)), datetime(2024, Month::June, 1, 21, 0, 0, 0));
+        assert_eq!(then.ceil_to(TimeUnit::Hours(7)),     datetime(2024, Month::June, 2,  0, 0, 0, 0));
+    }
+
+    #[test]
+    fn leap_year_february() {
+        let then = datetime(2024, Month::February, 29, 12, 0, 0, 0);
+        assert_eq!(then.ceil_to(TimeUnit::Month), datetime(2024, Month::March, 1, 0, 0, 0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_count() {
+        let _ = datetime(2024, Month::June, 1, 0, 0, 0, 0).truncate_to(TimeUnit::Minutes(0));
+    }
+}
+
+
+mod instant {
+    use super::*;
+
+    #[test]
+    fn truncate() {
+        let instant = Instant::at_ms(1_706_711_832, 500);
+        assert_eq!(instant.truncate_to(TimeUnit::Seconds(1)), Instant::at(1_706_711_832));
+        assert_eq!(instant.truncate_to(TimeUnit::Minutes(5)), Instant::at(1_706_711_700));
+        assert_eq!(instant.truncate_to(TimeUnit::Day),        Instant::at(1_706_659_200));
+    }
+
+    #[test]
+    fn before_the_epoch() {
+        let instant = Instant::at_ms(-1, 500);
+        assert_eq!(instant.truncate_to(TimeUnit::Seconds(1)), Instant::at(-1));
+        assert_eq!(instant.truncate_to(TimeUnit::Day),        Instant::at(-86400));
+        assert_eq!(instant.ceil_to(TimeUnit::Day),            Instant::at(0));
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(Instant::at_ms(59, 500).round_to(TimeUnit::Seconds(1)), Instant::at(60));
+        assert_eq!(Instant::at(89).round_to(TimeUnit::Minutes(1)), Instant::at(60));
+        assert_eq!(Instant::at(90).round_to(TimeUnit::Minutes(1)), Instant::at(120));
+    }
+}
+
+
+mod local_time {
+    use super::*;
+
+    #[test]
+    fn truncate() {
+        let time = LocalTime::hms_ms(14, 37, 12, 345).unwrap();
+        assert_eq!(time.truncate_to(TimeUnit::Minutes(15)), LocalTime::hm(14, 30).unwrap());
+        assert_eq!(time.truncate_to(TimeUnit::Year),        LocalTime::midnight());
+    }
+
+    #[test]
+    fn ceil_wraps_at_midnight() {
+        let time = LocalTime::hm(23, 50).unwrap();
+        assert_eq!(time.ceil_to(TimeUnit::Hours(1)), LocalTime::midnight());
+        assert_eq!(time.round_to(TimeUnit::Minutes(30)), LocalTime::midnight());
+        assert_eq!(time.round_to(TimeUnit::Minutes(1)), time);
+    }
+}
+
+
+mod offset_date_time {
+    use super::*;
+
+    #[test]
+    fn truncate_in_own_offset() {
+        let offset = Offset::of_hours_and_minutes(-5, 0).unwrap();
+        let then = offset.transform_date(datetime(2024, Month::January, 31, 22, 15, 0, 0));
+
+        let day = then.truncate_to(TimeUnit::Day);
+        assert_eq!(day.local, datetime(2024, Month::January, 31, 0, 0, 0, 0));
+        assert_eq!(day.offset, offset);
+
+        let hour = then.round_to(TimeUnit::Hours(1));
+        assert_eq!(hour.local, datetime(2024, Month::January, 31, 22, 0, 0, 0));
+
+        let month = then.ceil_to(TimeUnit::Month);
+        assert_eq!(month.local, datetime(2024, Month::February, 1, 0, 0, 0, 0));
+    }
+}
+
+
+mod zoned_date_time {
+    use super::*;
+
+    /// A zone in the style of America/Santiago, where the clocks go forward
+    /// at midnight, from UTC-4 to UTC-3, on the 3rd of September, 2023.
+    const MIDNIGHT_DST: &'static StaticTimeZone<'static> = &StaticTimeZone {
+        name: "Test/Midnight",
+        fixed_timespans: FixedTimespanSet {
+            first: FixedTimespan {
+                offset: -4 * 3600,
+                is_dst: false,
+                name: Cow::Borrowed("-04"),
+            },
+            rest: &[
+                (1_693_713_600, FixedTimespan {
+                    offset: -3 * 3600,
+                    is_dst: true,
+                    name: Cow::Borrowed("-03"),
+                }),
+            ],
+        },
+    };
+
+    #[test]
+    fn ordinary_day() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_DST));
+        let then = zone.convert_local(datetime(2023, Month::September, 10, 15, 0, 0, 0)).unwrap_precise();
+
+        let day = then.truncate_to(TimeUnit::Day);
+        assert_eq!((day.day(), day.hour()), (10, 0));
+        assert_eq!(day.to_instant(), Instant::at(1_694_314_800));
+    }
+
+    #[test]
+    fn day_starting_at_one_am() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_DST));
+        let then = zone.convert_local(datetime(2023, Month::September, 3, 15, 0, 0, 0)).unwrap_precise();
+
+        let day = then.truncate_to(TimeUnit::Day);
+        assert_eq!((day.day(), day.hour(), day.minute()), (3, 1, 0));
+        assert_eq!(day.to_instant(), Instant::at(1_693_713_600));
+    }
+
+    /// A zone in the style of America/New_York, where the clocks go back
+    /// at 02:00, from UTC-4 to UTC-5, on the 5th of November, 2023.
+    const FALL_BACK: &'static StaticTimeZone<'static> = &StaticTimeZone {
+        name: "Test/FallBack",
+        fixed_timespans: FixedTimespanSet {
+            first: FixedTimespan {
+                offset: -4 * 3600,
+                is_dst: true,
+                name: Cow::Borrowed("EDT"),
+            },
+            rest: &[
+                (1_699_164_000, FixedTimespan {
+                    offset: -5 * 3600,
+                    is_dst: false,
+                    name: Cow::Borrowed("EST"),
+                }),
+            ],
+        },
+    };
+
+    #[test]
+    fn first_of_a_repeated_hour() {
+        let zone = TimeZone(TimeZoneSource::Static(FALL_BACK));
+        let then = match zone.convert_local(datetime(2023, Month::November, 5, 1, 32, 0, 0)) {
+            LocalTimes::Ambiguous { earlier, .. } => earlier,
+            _ => panic!("01:32 should happen twice"),
+        };
+
+        assert_eq!(then.truncate_to(TimeUnit::Minutes(5)).to_instant(), Instant::at(1_699_162_200));
+        assert_eq!(then.truncate_to(TimeUnit::Hours(1)).to_instant(),   Instant::at(1_699_160_400));
+    }
+
+    #[test]
+    fn second_of_a_repeated_hour() {
+        let zone = TimeZone(TimeZoneSource::Static(FALL_BACK));
+        let then = match zone.convert_local(datetime(2023, Month::November, 5, 1, 32, 0, 0)) {
+            LocalTimes::Ambiguous { later, .. } => later,
+            _ => panic!("01:32 should happen twice"),
+        };
+        assert_eq!(then.to_instant(), Instant::at(1_699_165_920));
+
+        let minutes = then.truncate_to(TimeUnit::Minutes(5));
+        assert_eq!((minutes.hour(), minutes.minute()), (1, 30));
+        assert_eq!(minutes.to_instant(), Instant::at(1_699_165_800));
+
+        assert_eq!(then.truncate_to(TimeUnit::Hours(1)).to_instant(), Instant::at(1_699_164_000));
+        assert_eq!(then.truncate_to(TimeUnit::Day).to_instant(),      Instant::at(1_699_156_800));
+    }
+
+    #[test]
+    fn day_before_the_change() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_DST));
+        let then = zone.convert_local(datetime(2023, Month::September, 2, 23, 30, 0, 0)).unwrap_precise();
+
+        let day = then.truncate_to(TimeUnit::Day);
+        assert_eq!((day.day(), day.hour()), (2, 0));
+        assert_eq!(day.to_instant(), Instant::at(1_693_627_200));
+    }
+}
@@ -0,0 +1,301 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+use datetime::VTimeZone;
+use datetime::vtimezone::Error;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+
+const NEW_YORK: &'static str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+LAST-MODIFIED:20050809T050000Z\r
+BEGIN:STANDARD\r
+DTSTART:20071104T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+TZNAME:EST\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:20070311T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r
+TZOFFSETFROM:-0500\r
+TZOFFSETTO:-0400\r
+TZNAME:EDT\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+END:VCALENDAR\r
+";
+
+// America/New_York in 2024.
+const EASTERN: &'static StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Eastern",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: -5 * 3600,
+            is_dst: false,
+            name: Cow::Borrowed("EST"),
+        },
+        rest: &[
+            (1_710_054_000, FixedTimespan {
+                offset: -4 * 3600,
+                is_dst: true,
+                name: Cow::Borrowed("EDT"),
+            }),
+            (1_730_613_600, FixedTimespan {
+                offset: -5 * 3600,
+                is_dst: false,
+                name: Cow::Borrowed("EST"),
+            }),
+        ],
+    },
+};
+
+
+fn at(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hm(hour, minute).unwrap())
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn observances() {
+        let vtimezone: VTimeZone = NEW_YORK.parse().unwrap();
+        assert_eq!(vtimezone.tzid, "America/New_York");
+        assert_eq!(vtimezone.observances.len(), 2);
+
+        let standard = &vtimezone.observances[0];
+        assert_eq!(standard.is_dst, false);
+        assert_eq!(standard.offset_from, -4 * 3600);
+        assert_eq!(standard.offset_to, -5 * 3600);
+        assert_eq!(standard.name, Some("EST".to_owned()));
+        assert_eq!(standard.onsets.start, at(2007, Month::November, 4, 2, 0));
+        assert_eq!(standard.onsets.rules.len(), 1);
+    }
+
+    #[test]
+    fn folded_lines() {
+        let input = NEW_YORK.replace("TZID:America/New_York", "TZID:America/\r\n  New_York");
+        let vtimezone: VTimeZone = input.parse().unwrap();
+        assert_eq!(vtimezone.tzid, "America/ New_York");
+    }
+
+    #[test]
+    fn offsets_with_seconds() {
+        let input = NEW_YORK.replace("TZOFFSETTO:-0500", "TZOFFSETTO:-045602");
+        let vtimezone: VTimeZone = input.parse().unwrap();
+        assert_eq!(vtimezone.observances[0].offset_to, -(4 * 3600 + 56 * 60 + 2));
+    }
+
+    #[test]
+    fn missing_tzid() {
+        let input = NEW_YORK.replace("TZID:America/New_York\r\n", "");
+        assert_eq!(input.parse::<VTimeZone>(), Err(Error::MissingProperty("TZID")));
+    }
+
+    #[test]
+    fn missing_offset() {
+        let input = NEW_YORK.replace("TZOFFSETFROM:-0400\r\n", "");
+        assert_eq!(input.parse::<VTimeZone>(), Err(Error::MissingProperty("TZOFFSETFROM")));
+    }
+
+    #[test]
+    fn invalid_offset() {
+        let input = NEW_YORK.replace("TZOFFSETTO:-0500", "TZOFFSETTO:-5");
+        assert_eq!(input.parse::<VTimeZone>(), Err(Error::InvalidValue("TZOFFSETTO:-5".to_owned())));
+    }
+
+    #[test]
+    fn invalid_rule() {
+        let input = NEW_YORK.replace("RRULE:FREQ=YEARLY;BYMONTH=11", "RRULE:BYMONTH=11");
+        assert!(match input.parse::<VTimeZone>() { Err(Error::Recurrence(_)) => true, _ => false });
+    }
+
+    #[test]
+    fn no_vtimezone() {
+        assert_eq!("BEGIN:VCALENDAR\r\nEND:VCALENDAR".parse::<VTimeZone>(), Err(Error::MissingProperty("BEGIN:VTIMEZONE")));
+    }
+
+    #[test]
+    fn unterminated() {
+        let input = NEW_YORK.replace("END:VTIMEZONE\r\n", "");
+        assert_eq!(input.parse::<VTimeZone>(), Err(Error::MissingProperty("END:VTIMEZONE")));
+    }
+}
+
+
+mod importing {
+    use super::*;
+
+    #[test]
+    fn offsets() {
+        let zone = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2030);
+        assert_eq!(zone.zone_name(), Some("America/New_York"));
+
+        assert_eq!(zone.offset(at(2024, Month::January, 1, 12, 0)), -5 * 3600);
+        assert_eq!(zone.offset(at(2024, Month::July, 1, 12, 0)), -4 * 3600);
+        assert_eq!(zone.offset(at(2024, Month::December, 1, 12, 0)), -5 * 3600);
+        assert_eq!(zone.name(at(2024, Month::July, 1, 12, 0)), "EDT");
+    }
+
+    #[test]
+    fn transitions() {
+        let zone = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2030);
+
+        let spring = zone.convert_local(at(2024, Month::March, 10, 1, 59)).unwrap_precise();
+        assert_eq!(spring.to_instant().seconds() + 60, 1_710_054_000);
+        assert!(zone.convert_local(at(2024, Month::March, 10, 2, 30)).is_impossible());
+
+        let autumn = zone.convert_local(at(2024, Month::November, 3, 1, 30));
+        assert!(autumn.is_ambiguous());
+        let after = zone.convert_local(at(2024, Month::November, 3, 2, 0)).unwrap_precise();
+        assert_eq!(after.to_instant().seconds(), 1_730_613_600 + 3600);
+    }
+
+    #[test]
+    fn before_the_first_onset() {
+        let zone = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2030);
+        assert_eq!(zone.offset(at(2000, Month::July, 1, 12, 0)), -5 * 3600);
+        assert_eq!(zone.name(at(2000, Month::July, 1, 12, 0)), "EST");
+    }
+
+    #[test]
+    fn until_year() {
+        let zone = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2010);
+        assert_eq!(zone.offset(at(2010, Month::July, 1, 12, 0)), -4 * 3600);
+        assert_eq!(zone.offset(at(2011, Month::July, 1, 12, 0)), -4 * 3600);
+        assert_eq!(zone.offset(at(2050, Month::January, 1, 12, 0)), -5 * 3600);
+        assert_eq!(zone.name(at(2050, Month::July, 1, 12, 0)), "EDT");
+        assert!(!zone.is_fixed());
+    }
+
+    #[test]
+    fn transitions_after_until_year() {
+        let zone = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2010);
+        let eastern = TimeZone(TimeZoneSource::Static(EASTERN));
+
+        let spring = zone.convert_local(at(2024, Month::March, 10, 1, 59)).unwrap_precise();
+        assert_eq!(spring.to_instant().seconds() + 60, 1_710_054_000);
+        assert!(zone.convert_local(at(2024, Month::March, 10, 2, 30)).is_impossible());
+        assert!(zone.convert_local(at(2024, Month::November, 3, 1, 30)).is_ambiguous());
+
+        let mut time = at(2024, Month::January, 1, 12, 0);
+        while time < at(2024, Month::December, 31, 0, 0) {
+            assert_eq!(zone.offset(time), eastern.offset(time));
+            time = LocalDateTime::new(time.date().add_days(1), time.time());
+        }
+    }
+
+    #[test]
+    fn lookups_in_any_order() {
+        let zone = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2010);
+
+        for &year in &[2080, 2015, 2100, 2011, 2080] {
+            assert_eq!(zone.offset(at(year, Month::January, 1, 12, 0)), -5 * 3600);
+            assert_eq!(zone.offset(at(year, Month::July, 1, 12, 0)), -4 * 3600);
+            assert_eq!(zone.name(at(year, Month::July, 1, 12, 0)), "EDT");
+        }
+    }
+}
+
+
+mod exporting {
+    use super::*;
+
+    #[test]
+    fn observances() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let vtimezone = VTimeZone::from_time_zone("Eastern", &zone, 2024, 2024);
+
+        assert_eq!(vtimezone.observances.len(), 3);
+        assert_eq!(vtimezone.observances[0].onsets.start, at(2024, Month::January, 1, 0, 0));
+        assert_eq!(vtimezone.observances[1].onsets.start, at(2024, Month::March, 10, 2, 0));
+        assert_eq!(vtimezone.observances[1].is_dst, true);
+        assert_eq!(vtimezone.observances[2].onsets.start, at(2024, Month::November, 3, 2, 0));
+        assert_eq!(vtimezone.observances[2].offset_from, -4 * 3600);
+    }
+
+    #[test]
+    fn no_transitions() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let vtimezone = VTimeZone::from_time_zone("Eastern", &zone, 2025, 2026);
+
+        assert_eq!(vtimezone.observances.len(), 1);
+        assert_eq!(vtimezone.observances[0].offset_from, -5 * 3600);
+        assert_eq!(vtimezone.observances[0].offset_to, -5 * 3600);
+        assert_eq!(vtimezone.observances[0].name, Some("EST".to_owned()));
+    }
+
+    #[test]
+    fn repeated_changes_are_grouped() {
+        let zone = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2030);
+        let vtimezone = VTimeZone::from_time_zone("America/New_York", &zone, 2020, 2024);
+
+        assert_eq!(vtimezone.observances.len(), 3);
+        assert_eq!(vtimezone.observances[1].onsets.dates.len(), 4);
+        assert_eq!(vtimezone.observances[2].onsets.dates.len(), 4);
+    }
+
+    #[test]
+    fn display() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let vtimezone = VTimeZone::from_time_zone("Eastern", &zone, 2024, 2024);
+
+        assert_eq!(vtimezone.to_string(), "BEGIN:VTIMEZONE\r
+TZID:Eastern\r
+BEGIN:STANDARD\r
+DTSTART:20240101T000000\r
+TZOFFSETFROM:-0500\r
+TZOFFSETTO:-0500\r
+TZNAME:EST\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:20240310T020000\r
+TZOFFSETFROM:-0500\r
+TZOFFSETTO:-0400\r
+TZNAME:EDT\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+DTSTART:20241103T020000\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+TZNAME:EST\r
+END:STANDARD\r
+END:VTIMEZONE\r
+");
+    }
+
+    #[test]
+    fn long_lines_are_folded() {
+        let zone = TimeZone(TimeZoneSource::Static(EASTERN));
+        let tzid: String = ::std::iter::repeat("x").take(100).collect();
+        let text = VTimeZone::from_time_zone(&tzid, &zone, 2024, 2024).to_string();
+
+        assert!(text.lines().all(|line| line.trim_right_matches('\r').len() <= 75));
+        assert_eq!(text.parse::<VTimeZone>().unwrap().tzid, tzid);
+    }
+
+    #[test]
+    fn round_trip() {
+        let original = NEW_YORK.parse::<VTimeZone>().unwrap().to_time_zone(2030);
+        let text = VTimeZone::from_time_zone("America/New_York", &original, 2020, 2030).to_string();
+        let zone = text.parse::<VTimeZone>().unwrap().to_time_zone(2030);
+
+        let mut time = at(2020, Month::January, 1, 12, 0);
+        while time < at(2030, Month::December, 31, 0, 0) {
+            assert_eq!(zone.offset(time), original.offset(time));
+            assert_eq!(zone.name(time), original.name(time));
+            time = LocalDateTime::new(time.date().add_days(7), time.time());
+        }
+    }
+
+    #[test]
+    fn rules_round_trip() {
+        let vtimezone: VTimeZone = NEW_YORK.parse().unwrap();
+        assert_eq!(vtimezone.to_string().parse::<VTimeZone>(), Ok(vtimezone));
+    }
+}
@@ -0,0 +1,94 @@
+extern crate datetime;
+extern crate locale;
+
+use std::fmt::Write;
+
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Offset};
+use datetime::cldr::Locale;
+use datetime::fmt::DateFormat;
+
+
+fn datetime() -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2024, Month::February, 3).unwrap(), LocalTime::hms_ms(15, 4, 5, 250).unwrap())
+}
+
+fn format() -> DateFormat<'static> {
+    DateFormat::parse("{:Y}-{>02:N}-{>02:D} {>02:h}:{>02:m}:{>02:s}.{:F} {_:z}").unwrap()
+}
+
+
+mod fmt_write {
+    use super::*;
+
+    #[test]
+    fn same_as_format() {
+        let offset = Offset::of_hours_and_minutes(-5, -30).unwrap().transform_date(datetime());
+        let mut string = String::new();
+        format().write_to(&mut string, &offset, &locale::Time::english()).unwrap();
+        assert_eq!(string, format().format(&offset, &locale::Time::english()));
+        assert_eq!(string, "2024-02-03 15:04:05.25 -05:30");
+    }
+
+    #[test]
+    fn appends() {
+        let mut string = String::from("at ");
+        DateFormat::parse("{:D} {:M}").unwrap().write_to(&mut string, &datetime(), &locale::Time::english()).unwrap();
+        write!(string, "!").unwrap();
+        assert_eq!(string, "at 3 Feb!");
+    }
+
+    #[test]
+    fn padding() {
+        let format = DateFormat::parse("[{<6:E}] [{^7:E}] [{>05:Y}] [{>05:y}]").unwrap();
+        assert_eq!(format.format(&datetime(), &locale::Time::english()), "[Sat   ] [  Sat  ] [02024] [00024]");
+    }
+
+    #[test]
+    fn wide_characters_count_twice() {
+        let format = DateFormat::parse("[{>8_:E}]").unwrap();
+        assert_eq!(format.format(&datetime(), Locale::from_tag("ja").unwrap()), "[  土曜日]");
+    }
+
+    #[test]
+    fn negative_numbers_with_zeros() {
+        let date = LocalDateTime::new(LocalDate::ymd(-33, Month::March, 1).unwrap(), LocalTime::midnight());
+        assert_eq!(DateFormat::parse("{>05:Y}").unwrap().format(&date, &locale::Time::english()), "-0033");
+    }
+}
+
+
+mod io_write {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        let mut bytes = Vec::new();
+        format().write_io(&mut bytes, &datetime(), &locale::Time::english()).unwrap();
+        assert_eq!(bytes, b"2024-02-03 15:04:05.25 ".to_vec());
+    }
+
+    #[test]
+    fn non_ascii() {
+        let mut bytes = Vec::new();
+        DateFormat::parse("{_:E}").unwrap().write_io(&mut bytes, &datetime(), Locale::from_tag("de").unwrap()).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), "Samstag");
+    }
+}
+
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn format_macro() {
+        let english = locale::Time::english();
+        let format = format();
+        assert_eq!(format!("<{}>", format.display(&datetime(), &english)), "<2024-02-03 15:04:05.25 >");
+    }
+
+    #[test]
+    fn cldr_locale() {
+        let format = DateFormat::parse("{_:M}").unwrap();
+        assert_eq!(format.display(&datetime(), Locale::from_tag("fr").unwrap()).to_string(), "février");
+    }
+}
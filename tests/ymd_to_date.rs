@@ -31,3 +31,23 @@ fn the_distant_future() {
     assert_eq!(date.month(), Month::October);
     assert_eq!(date.day(), 13);
 }
+
+
+#[test]
+fn leap_day() {
+    let date = LocalDate::ymd(2024, Month::February, 29).unwrap();
+
+    assert_eq!(date.year(),  2024);
+    assert_eq!(date.month(), Month::February);
+    assert_eq!(date.day(),   29);
+}
+
+
+#[test]
+fn leap_day_at_the_end_of_a_400_year_cycle() {
+    let date = LocalDate::ymd(2400, Month::February, 29).unwrap();
+
+    assert_eq!(date.year(),  2400);
+    assert_eq!(date.month(), Month::February);
+    assert_eq!(date.day(),   29);
+}